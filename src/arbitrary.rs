@@ -0,0 +1,157 @@
+//! Random `Owo`, `Currency` and `RoundingMode` values for fuzzing and
+//! property tests.
+//!
+//! The types implement [`arbitrary::Arbitrary`](::arbitrary::Arbitrary) for
+//! `cargo fuzz`, and the functions in this module are `proptest` strategies.
+//! Generated currencies always have a three-letter uppercase code, a symbol
+//! from [`SYMBOLS`] and a precision no greater than
+//! [`Currency::MAX_PRECISION`], so the custom currencies a fuzzer interns
+//! stay bounded.
+//!
+//! #Example
+//! ```
+//! use cowry::prelude::*;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(owo in cowry::arbitrary::owo(), ratios in prop::collection::vec(1u64..100, 1..8))| {
+//!     let parts = owo.allocate(&ratios).unwrap();
+//!     prop_assert_eq!(parts.iter().map(|part| part.amount).sum::<i64>(), owo.amount);
+//! });
+//! ```
+
+use crate::{Currency, Owo, RoundingMode, registry};
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use proptest::prelude::*;
+
+/// The symbols generated custom currencies use.
+pub const SYMBOLS: &[&str] = &["¤", "★", "X", "$", "₦", "€", "CHF", "ք"];
+
+impl<'a> Arbitrary<'a> for Currency {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut code = String::with_capacity(3);
+        for _ in 0..3 {
+            code.push(char::from(u.int_in_range(b'A'..=b'Z')?));
+        }
+        let symbol = *u.choose(SYMBOLS)?;
+        let precision = u.int_in_range(0..=Currency::MAX_PRECISION)?;
+        Ok(Currency::new(&code, symbol, precision))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Owo {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Owo::new(i64::arbitrary(u)?, Currency::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for RoundingMode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            RoundingMode::Nearest,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+        ])?)
+    }
+}
+
+/// A strategy producing ISO 4217 currencies from the registry.
+///
+/// #Example
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let currency = cowry::arbitrary::iso_currency()
+///     .new_tree(&mut TestRunner::default())
+///     .unwrap()
+///     .current();
+/// assert!(cowry::registry::find(&currency.code).is_some());
+/// ```
+pub fn iso_currency() -> impl Strategy<Value = Currency> {
+    prop::sample::select(registry::ISO_CURRENCIES).prop_map(|iso| iso.to_currency())
+}
+
+/// A strategy producing custom currencies with any supported precision.
+///
+/// #Example
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let currency = cowry::arbitrary::currency()
+///     .new_tree(&mut TestRunner::default())
+///     .unwrap()
+///     .current();
+/// assert!(currency.precision <= cowry::Currency::MAX_PRECISION);
+/// ```
+pub fn currency() -> impl Strategy<Value = Currency> {
+    (
+        "[A-Z]{3}",
+        prop::sample::select(SYMBOLS),
+        0..=Currency::MAX_PRECISION,
+    )
+        .prop_map(|(code, symbol, precision)| Currency::new(&code, symbol, precision))
+}
+
+/// A strategy producing amounts of any size in registry currencies.
+///
+/// #Example
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let owo = cowry::arbitrary::owo()
+///     .new_tree(&mut TestRunner::default())
+///     .unwrap()
+///     .current();
+/// assert!(cowry::registry::find(&owo.currency.code).is_some());
+/// ```
+pub fn owo() -> impl Strategy<Value = Owo> {
+    (any::<i64>(), iso_currency()).prop_map(|(amount, currency)| Owo::new(amount, currency))
+}
+
+/// A strategy producing amounts in `currency` with minor units drawn from
+/// `amounts`, for billing logic that assumes one currency.
+///
+/// #Example
+/// ```
+/// use cowry::prelude::*;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let owo = cowry::arbitrary::owo_in(ngn.clone(), 0i64..10_000)
+///     .new_tree(&mut TestRunner::default())
+///     .unwrap()
+///     .current();
+/// assert_eq!(owo.currency, ngn);
+/// assert!((0..10_000).contains(&owo.amount));
+/// ```
+pub fn owo_in(
+    currency: Currency,
+    amounts: impl Strategy<Value = i64>,
+) -> impl Strategy<Value = Owo> {
+    amounts.prop_map(move |amount| Owo::new(amount, currency))
+}
+
+/// A strategy producing every rounding mode.
+///
+/// #Example
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let mode = cowry::arbitrary::rounding_mode()
+///     .new_tree(&mut TestRunner::default())
+///     .unwrap()
+///     .current();
+/// let owo = cowry::Owo::new(100, cowry::Currency::new("USD", "$", 2));
+/// assert!(owo.multiply_with_mode(1.5, mode).is_ok());
+/// ```
+pub fn rounding_mode() -> impl Strategy<Value = RoundingMode> {
+    prop_oneof![
+        Just(RoundingMode::Nearest),
+        Just(RoundingMode::Floor),
+        Just(RoundingMode::Ceil),
+    ]
+}
@@ -0,0 +1,138 @@
+//! A lock-free money accumulator for multi-threaded processing.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo};
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// What [`AtomicOwo`] does when an update would overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Leave the amount unchanged and return an error.
+    #[default]
+    Error,
+    /// Clamp the amount to `i64::MIN` or `i64::MAX`.
+    Saturate,
+}
+
+/// An amount that many threads can add to at once, without a `Mutex`.
+///
+/// The currency is fixed at construction and every update is checked
+/// against it.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::atomic::AtomicOwo;
+/// use std::sync::Arc;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let revenue = Arc::new(AtomicOwo::new(Owo::new(0,usd.clone())));
+///
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let revenue = Arc::clone(&revenue);
+///         let usd = usd.clone();
+///         std::thread::spawn(move || {
+///             for _ in 0..1000 {
+///                 revenue.fetch_add(&Owo::new(199,usd.clone())).unwrap();
+///             }
+///         })
+///     })
+///     .collect();
+/// for worker in workers {
+///     worker.join().unwrap();
+/// }
+///
+/// assert_eq!(revenue.snapshot(), Owo::new(796000,usd.clone()));
+/// ```
+#[derive(Debug)]
+pub struct AtomicOwo {
+    amount: AtomicI64,
+    currency: Currency,
+    policy: OverflowPolicy,
+}
+
+impl AtomicOwo {
+    /// Creates an accumulator starting at `initial` that errors on overflow.
+    pub fn new(initial: Owo) -> Self {
+        Self::with_policy(initial, OverflowPolicy::Error)
+    }
+
+    /// Creates an accumulator starting at `initial` with an overflow policy.
+    pub fn with_policy(initial: Owo, policy: OverflowPolicy) -> Self {
+        Self {
+            amount: AtomicI64::new(initial.amount),
+            currency: initial.currency,
+            policy,
+        }
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Adds `owo`, returning the amount before the update
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::atomic::{AtomicOwo, OverflowPolicy};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let strict = AtomicOwo::new(Owo::new(i64::MAX - 1,ngn.clone()));
+    /// assert!(strict.fetch_add(&Owo::new(5,ngn.clone())).is_err());
+    /// assert_eq!(strict.snapshot().get_amount(), i64::MAX - 1);
+    ///
+    /// let clamped = AtomicOwo::with_policy(Owo::new(i64::MAX - 1,ngn.clone()), OverflowPolicy::Saturate);
+    /// clamped.fetch_add(&Owo::new(5,ngn.clone())).unwrap();
+    /// assert_eq!(clamped.snapshot().get_amount(), i64::MAX);
+    ///
+    /// assert!(clamped.fetch_add(&Owo::new(5,Currency::new("USD", "$", 2))).is_err());
+    /// ```
+    pub fn fetch_add(&self, owo: &Owo) -> Result<Owo, OwoError> {
+        self.update(owo, "add", i64::checked_add, i64::saturating_add)
+    }
+
+    /// Subtracts `owo`, returning the amount before the update.
+    pub fn fetch_sub(&self, owo: &Owo) -> Result<Owo, OwoError> {
+        self.update(owo, "subtract", i64::checked_sub, i64::saturating_sub)
+    }
+
+    /// Returns the current amount.
+    pub fn snapshot(&self) -> Owo {
+        Owo::new(self.amount.load(Ordering::Acquire), self.currency)
+    }
+
+    /// Resets the amount to zero, returning what it was, e.g. to flush a
+    /// running total at the end of a reporting interval.
+    pub fn take(&self) -> Owo {
+        Owo::new(self.amount.swap(0, Ordering::AcqRel), self.currency)
+    }
+
+    /// Consumes the accumulator, returning the final amount.
+    pub fn into_inner(self) -> Owo {
+        Owo::new(self.amount.into_inner(), self.currency)
+    }
+
+    // Helper applying `checked` (or `saturating`, per the policy) in a
+    // compare-and-swap loop
+    fn update(
+        &self,
+        owo: &Owo,
+        op: &'static str,
+        checked: fn(i64, i64) -> Option<i64>,
+        saturating: fn(i64, i64) -> i64,
+    ) -> Result<Owo, OwoError> {
+        self.snapshot().check_currency(owo)?;
+        let previous = self
+            .amount
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                match self.policy {
+                    OverflowPolicy::Error => checked(current, owo.amount),
+                    OverflowPolicy::Saturate => Some(saturating(current, owo.amount)),
+                }
+            })
+            .map_err(|current| Owo::new(current, self.currency).overflow(op, owo.amount))?;
+        Ok(Owo::new(previous, self.currency))
+    }
+}
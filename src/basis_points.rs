@@ -0,0 +1,119 @@
+//! Basis points, hundredths of a percent, for fee schedules and FX spreads.
+//!
+//! A basis point is an exact integer, so `Owo::apply_bps` computes
+//! `amount * bps / 10000` in integers and rounds once, without the float
+//! error a percentage like `0.25` can pick up.
+
+use crate::error::OwoError;
+use crate::rounding::round_div;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A whole number of basis points; `100` bps is 1%.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+#[serde(transparent)]
+pub struct BasisPoints(pub i64);
+
+impl BasisPoints {
+    /// The number of basis points in 100%.
+    pub const WHOLE: BasisPoints = BasisPoints(10_000);
+
+    pub const fn new(bps: i64) -> Self {
+        Self(bps)
+    }
+
+    pub const fn get(self) -> i64 {
+        self.0
+    }
+
+    /// Converts a percentage, rejecting any that isn't a whole number of
+    /// basis points
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::basis_points::BasisPoints;
+    ///
+    /// assert_eq!(BasisPoints::from_percent(0.25).unwrap(), BasisPoints(25));
+    /// assert_eq!(BasisPoints::from_percent(2.9).unwrap(), BasisPoints(290));
+    /// assert_eq!(BasisPoints(25).to_percent(), 0.25);
+    ///
+    /// assert!(BasisPoints::from_percent(0.255).is_err());
+    /// assert!(BasisPoints::from_percent(f64::NAN).is_err());
+    /// ```
+    pub fn from_percent(percent: f64) -> Result<Self, OwoError> {
+        let bps = (percent * 100.0).round();
+        // Allow for the representation error of decimal percentages
+        if !bps.is_finite()
+            || (percent * 100.0 - bps).abs() > 1e-6
+            || bps < i64::MIN as f64
+            || bps >= i64::MAX as f64
+        {
+            return Err(OwoError::InvalidScalar(percent));
+        }
+        Ok(Self(bps as i64))
+    }
+
+    /// Returns the equivalent percentage, e.g. `0.25` for 25 bps.
+    pub fn to_percent(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+}
+
+impl From<i64> for BasisPoints {
+    fn from(bps: i64) -> Self {
+        Self(bps)
+    }
+}
+
+impl fmt::Display for BasisPoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bps", self.0)
+    }
+}
+
+impl Owo {
+    /// Returns `bps` basis points of the amount, rounded to the nearest
+    /// minor unit
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// // 25 bps of $1,234.56 is $3.0864
+    /// assert_eq!(Owo::new(123456,usd.clone()).apply_bps(25).unwrap(), Owo::new(309,usd.clone()));
+    /// assert!(Owo::new(i64::MAX,usd).apply_bps(20_000).is_err());
+    /// ```
+    pub fn apply_bps(&self, bps: impl Into<BasisPoints>) -> Result<Owo, OwoError> {
+        self.apply_bps_with_mode(bps, RoundingMode::Nearest)
+    }
+
+    /// Returns `bps` basis points of the amount, computed exactly in
+    /// integers and rounded once with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let fee = Owo::new(1010,usd.clone());
+    ///
+    /// // 50 bps of $10.10 is exactly 5.05¢
+    /// assert_eq!(fee.apply_bps_with_mode(50, RoundingMode::Floor).unwrap().get_amount(), 5);
+    /// assert_eq!(fee.apply_bps_with_mode(50, RoundingMode::Ceil).unwrap().get_amount(), 6);
+    /// assert_eq!(fee.apply_bps_with_mode(-50, RoundingMode::Nearest).unwrap().get_amount(), -5);
+    /// ```
+    pub fn apply_bps_with_mode(
+        &self,
+        bps: impl Into<BasisPoints>,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let bps = bps.into();
+        let exact = i128::from(self.amount) * i128::from(bps.0);
+        let amount = round_div(exact, i128::from(BasisPoints::WHOLE.0), mode);
+        let amount = i64::try_from(amount).map_err(|_| self.overflow("apply_bps", bps))?;
+        Ok(Owo::new(amount, self.currency))
+    }
+}
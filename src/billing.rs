@@ -0,0 +1,26 @@
+//! Subscription and usage billing built on the `finance` date arithmetic.
+
+pub mod installment;
+pub mod plan_change;
+pub mod schedule;
+pub mod usage;
+
+use crate::finance::day_count::CalendarDate;
+use serde::{Deserialize, Serialize};
+
+/// A billing period from `start` up to, but not including, `end`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BillingPeriod {
+    pub start: CalendarDate,
+    pub end: CalendarDate,
+}
+
+impl BillingPeriod {
+    /// Creates the period `start..end`.
+    pub fn new(start: impl Into<CalendarDate>, end: impl Into<CalendarDate>) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Splitting a total into a plan of installments.
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// Which installment absorbs the minor units that don't divide evenly.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Adjustment {
+    First,
+    #[default]
+    Last,
+}
+
+/// One payment in an installment plan.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Installment {
+    /// The installment number, starting at 1.
+    pub number: u32,
+    pub principal: Owo,
+    pub interest: Owo,
+    /// `principal + interest`.
+    pub amount: Owo,
+}
+
+/// A total split into installments.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InstallmentPlan {
+    pub installments: Vec<Installment>,
+    /// Always the total that was split.
+    pub principal: Owo,
+    pub interest: Owo,
+    pub total: Owo,
+}
+
+/// Splits `total` into `count` equal installments, the `adjustment` one
+/// taking the leftover minor units, and adds `interest_percent` percent of
+/// each installment's principal as interest, rounded with `mode`
+///
+/// The principals always sum to `total`. Returns an error if `count` is zero.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::billing::installment::{Adjustment, installments};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let plan = installments(&Owo::new(10000,usd.clone()), 3, Adjustment::First, 0.0, RoundingMode::Nearest).unwrap();
+///
+/// let amounts: Vec<i64> = plan.installments.iter().map(|i| i.amount.get_amount()).collect();
+/// assert_eq!(amounts, vec![3334, 3333, 3333]);
+///
+/// // With 1.5% interest on each installment
+/// let plan = installments(&Owo::new(10000,usd.clone()), 4, Adjustment::Last, 1.5, RoundingMode::Nearest).unwrap();
+/// assert_eq!(plan.installments[0].interest, Owo::new(38,usd.clone()));
+/// assert_eq!(plan.principal, Owo::new(10000,usd.clone()));
+/// assert_eq!(plan.total, Owo::new(10152,usd.clone()));
+/// ```
+pub fn installments(
+    total: &Owo,
+    count: u32,
+    adjustment: Adjustment,
+    interest_percent: f64,
+    mode: RoundingMode,
+) -> Result<InstallmentPlan, OwoError> {
+    if count == 0 {
+        return Err(OwoError::DivisionByZero);
+    }
+    let share = total.amount / i64::from(count);
+    let leftover = total.amount % i64::from(count);
+    let adjusted = match adjustment {
+        Adjustment::First => 1,
+        Adjustment::Last => count,
+    };
+
+    let mut interest = Owo::new(0, total.currency);
+    let mut installments = Vec::with_capacity(count as usize);
+    for number in 1..=count {
+        let principal = Owo::new(
+            if number == adjusted {
+                share + leftover
+            } else {
+                share
+            },
+            total.currency,
+        );
+        let charge = principal.percentage_with_mode(interest_percent, mode)?;
+        interest = interest.checked_add(&charge)?;
+        installments.push(Installment {
+            number,
+            amount: principal.checked_add(&charge)?,
+            principal,
+            interest: charge,
+        });
+    }
+    Ok(InstallmentPlan {
+        installments,
+        principal: *total,
+        total: total.checked_add(&interest)?,
+        interest,
+    })
+}
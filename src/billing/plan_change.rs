@@ -0,0 +1,83 @@
+//! Mid-period subscription plan changes, prorated by actual days.
+
+use crate::billing::BillingPeriod;
+use crate::error::OwoError;
+use crate::finance::day_count::{CalendarDate, DayCount};
+use crate::finance::prorate;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// The proration lines for switching plans part-way through a period.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanChange {
+    /// The refund for unused time on the old plan, as a negative amount.
+    pub credit: Owo,
+    /// The charge for the remaining time on the new plan.
+    pub charge: Owo,
+    /// `charge + credit`, always the prorated difference between the plans.
+    pub net: Owo,
+}
+
+/// Prorates a switch from `old` to `new`, both priced per `period`, taking
+/// effect on `change_date`
+///
+/// The credit is rounded with `mode`, and the charge is derived from it so
+/// that `charge + credit` equals the plan difference prorated with `mode`,
+/// exactly.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::billing::BillingPeriod;
+/// use cowry::billing::plan_change::plan_change;
+/// use cowry::finance::day_count::CalendarDate;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let period = BillingPeriod::new(CalendarDate::new(2024, 4, 1), CalendarDate::new(2024, 5, 1));
+///
+/// // Upgrading from $10 to $25 with 20 of April's 30 days left
+/// let change = plan_change(
+///     &Owo::new(1000,usd.clone()),
+///     &Owo::new(2500,usd.clone()),
+///     period,
+///     CalendarDate::new(2024, 4, 11),
+///     RoundingMode::Nearest,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(change.credit, Owo::new(-667,usd.clone()));
+/// assert_eq!(change.charge, Owo::new(1667,usd.clone()));
+/// assert_eq!(change.net, Owo::new(1000,usd.clone()));
+/// ```
+pub fn plan_change(
+    old: &Owo,
+    new: &Owo,
+    period: BillingPeriod,
+    change_date: impl Into<CalendarDate>,
+    mode: RoundingMode,
+) -> Result<PlanChange, OwoError> {
+    let change_date = change_date.into();
+    let remaining = |amount: &Owo| {
+        prorate(
+            amount,
+            period.start,
+            period.end,
+            change_date,
+            period.end,
+            DayCount::Act365Fixed,
+            mode,
+        )
+    };
+    let unused = remaining(old)?;
+    let net = remaining(&new.checked_sub(old)?)?;
+    let charge = unused.checked_add(&net)?;
+    let credit_amount = unused
+        .amount
+        .checked_neg()
+        .ok_or_else(|| unused.overflow("credit", 0))?;
+    Ok(PlanChange {
+        credit: Owo::new(credit_amount, unused.currency),
+        charge,
+        net,
+    })
+}
@@ -0,0 +1,156 @@
+//! Expanding a recurring charge into concrete dated charges.
+//!
+//! Every date is computed from the anchor rather than the previous charge,
+//! so a charge anchored on the 31st lands on the last day of shorter months
+//! and returns to the 31st afterwards: Jan 31, Feb 29, Mar 31, Apr 30.
+
+use crate::Owo;
+use crate::error::OwoError;
+use crate::finance::day_count::CalendarDate;
+use serde::{Deserialize, Serialize};
+
+/// The most charges [`RecurringCharge::charges`] expands a schedule into.
+pub const MAX_CHARGES: u32 = 10_000;
+
+/// How often a charge recurs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interval {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+    Years(u32),
+}
+
+impl Interval {
+    // Helper returning the date of the `n`th occurrence after `anchor`
+    fn nth_after(self, anchor: CalendarDate, n: u32) -> CalendarDate {
+        let n = i64::from(n);
+        match self {
+            Interval::Days(days) => anchor.add_days(i64::from(days) * n),
+            Interval::Weeks(weeks) => anchor.add_days(i64::from(weeks) * 7 * n),
+            Interval::Months(months) => anchor.add_months(i64::from(months) * n),
+            Interval::Years(years) => anchor.add_months(i64::from(years) * 12 * n),
+        }
+    }
+
+    // Helper reporting whether the interval never advances
+    fn is_zero(self) -> bool {
+        matches!(
+            self,
+            Interval::Days(0) | Interval::Weeks(0) | Interval::Months(0) | Interval::Years(0)
+        )
+    }
+}
+
+/// When a recurring charge stops.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndCondition {
+    /// After this many charges.
+    Count(u32),
+    /// After the last charge on or before this date.
+    Until(CalendarDate),
+}
+
+/// One charge on a billing schedule.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ScheduledCharge {
+    pub date: CalendarDate,
+    pub amount: Owo,
+}
+
+/// A charge repeated every `interval`, starting on `anchor`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecurringCharge {
+    pub amount: Owo,
+    pub interval: Interval,
+    pub anchor: CalendarDate,
+    pub end: EndCondition,
+}
+
+impl RecurringCharge {
+    /// Creates a recurring charge of `amount` every `interval` from `anchor`
+    /// until `end`.
+    pub fn new(
+        amount: Owo,
+        interval: Interval,
+        anchor: impl Into<CalendarDate>,
+        end: EndCondition,
+    ) -> Self {
+        Self {
+            amount,
+            interval,
+            anchor: anchor.into(),
+            end,
+        }
+    }
+
+    /// Expands the schedule into its dated charges, the first on the anchor
+    ///
+    /// A schedule that would run past [`MAX_CHARGES`] charges is rejected with
+    /// `InvalidRange` instead of being expanded.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::billing::schedule::{EndCondition, Interval, RecurringCharge};
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let plan = RecurringCharge::new(
+    ///     Owo::new(999,usd.clone()),
+    ///     Interval::Months(1),
+    ///     CalendarDate::new(2024, 1, 31),
+    ///     EndCondition::Count(4),
+    /// );
+    ///
+    /// let dates: Vec<CalendarDate> = plan.charges().unwrap().iter().map(|charge| charge.date).collect();
+    /// assert_eq!(dates, vec![
+    ///     CalendarDate::new(2024, 1, 31),
+    ///     CalendarDate::new(2024, 2, 29),
+    ///     CalendarDate::new(2024, 3, 31),
+    ///     CalendarDate::new(2024, 4, 30),
+    /// ]);
+    ///
+    /// let weekly = RecurringCharge::new(
+    ///     Owo::new(500,usd.clone()),
+    ///     Interval::Weeks(1),
+    ///     CalendarDate::new(2024, 1, 1),
+    ///     EndCondition::Until(CalendarDate::new(2024, 1, 29)),
+    /// );
+    /// assert_eq!(weekly.charges().unwrap().len(), 5);
+    ///
+    /// let forever = RecurringCharge::new(
+    ///     Owo::new(500,usd.clone()),
+    ///     Interval::Days(1),
+    ///     CalendarDate::new(2024, 1, 1),
+    ///     EndCondition::Count(u32::MAX),
+    /// );
+    /// assert!(forever.charges().is_err());
+    /// ```
+    pub fn charges(&self) -> Result<Vec<ScheduledCharge>, OwoError> {
+        if self.interval.is_zero() {
+            return Err(OwoError::InvalidInterval(format!("{:?}", self.interval)));
+        }
+        let mut charges = Vec::new();
+        for n in 0.. {
+            let date = self.interval.nth_after(self.anchor, n);
+            let done = match self.end {
+                EndCondition::Count(count) => n >= count,
+                EndCondition::Until(until) => date > until,
+            };
+            if done {
+                break;
+            }
+            if n == MAX_CHARGES {
+                return Err(OwoError::InvalidRange(format!(
+                    "schedule has more than {MAX_CHARGES} charges"
+                )));
+            }
+            charges.push(ScheduledCharge {
+                date,
+                amount: self.amount,
+            });
+        }
+        Ok(charges)
+    }
+}
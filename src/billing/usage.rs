@@ -0,0 +1,169 @@
+//! Metered usage billing: raw usage events aggregated into a period invoice.
+//!
+//! Quantities are summed per meter first and each meter is priced once, so
+//! rounding happens once per meter per period rather than once per event.
+
+use crate::billing::BillingPeriod;
+use crate::error::OwoError;
+use crate::finance::day_count::CalendarDate;
+use crate::pricing::TieredPricing;
+use crate::{Currency, Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// How a meter's usage is priced.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MeterPrice {
+    /// A flat price per unit; fractional quantities are allowed.
+    PerUnit(Owo),
+    /// A tier schedule; the period's quantity is rounded up to whole units.
+    Tiered(TieredPricing),
+}
+
+/// A quantity consumed on a meter on a given day.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsageEvent {
+    pub meter: String,
+    pub quantity: f64,
+    pub date: CalendarDate,
+}
+
+impl UsageEvent {
+    /// Creates a usage event.
+    pub fn new(meter: &str, quantity: f64, date: impl Into<CalendarDate>) -> Self {
+        Self {
+            meter: meter.to_string(),
+            quantity,
+            date: date.into(),
+        }
+    }
+}
+
+/// One meter's usage and charge for a period.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MeterSubtotal {
+    pub meter: String,
+    pub quantity: f64,
+    pub amount: Owo,
+}
+
+/// The usage charges for one billing period.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsageInvoice {
+    pub period: BillingPeriod,
+    /// One subtotal per meter, in the order the meters were added.
+    pub meters: Vec<MeterSubtotal>,
+    pub total: Owo,
+}
+
+/// Collects usage events against priced meters in one currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UsageAggregator {
+    currency: Currency,
+    meters: Vec<(String, MeterPrice)>,
+    events: Vec<UsageEvent>,
+}
+
+impl UsageAggregator {
+    /// Creates an aggregator billing in `currency`.
+    pub fn new(currency: Currency) -> Self {
+        Self {
+            currency,
+            meters: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Adds a meter, replacing the price of an existing meter with the same
+    /// name and rejecting prices in another currency.
+    pub fn add_meter(&mut self, name: &str, price: MeterPrice) -> Result<(), OwoError> {
+        let unit_price = match &price {
+            MeterPrice::PerUnit(unit_price) => Some(unit_price),
+            MeterPrice::Tiered(tiers) => tiers.tiers().first().map(|tier| &tier.unit_price),
+        };
+        if let Some(unit_price) = unit_price.filter(|price| price.currency != self.currency) {
+            return Err(OwoError::CurrencyMismatch(
+                self.currency.code.to_string(),
+                unit_price.currency.code.to_string(),
+            ));
+        }
+        match self.meters.iter_mut().find(|(meter, _)| meter == name) {
+            Some((_, existing)) => *existing = price,
+            None => self.meters.push((name.to_string(), price)),
+        }
+        Ok(())
+    }
+
+    /// Records a usage event, rejecting events for unknown meters or with
+    /// quantities that aren't finite.
+    pub fn record(&mut self, event: UsageEvent) -> Result<(), OwoError> {
+        if !self.meters.iter().any(|(meter, _)| *meter == event.meter) {
+            return Err(OwoError::UnknownMeter(event.meter));
+        }
+        if !event.quantity.is_finite() {
+            return Err(OwoError::InvalidScalar(event.quantity));
+        }
+        self.events.push(event);
+        Ok(())
+    }
+
+    /// Prices the events dated within `period`, rounding each per-unit
+    /// meter's charge once with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::billing::BillingPeriod;
+    /// use cowry::billing::usage::{MeterPrice, UsageAggregator, UsageEvent};
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut usage = UsageAggregator::new(usd.clone());
+    /// usage.add_meter("api_calls", MeterPrice::PerUnit(Owo::new(1,usd.clone()))).unwrap();
+    ///
+    /// // Three events of 0.4 units: 1.2¢ overall, not 3 × 0.4¢ rounded to 0¢
+    /// for day in [3, 10, 17] {
+    ///     usage.record(UsageEvent::new("api_calls", 0.4, CalendarDate::new(2024, 5, day))).unwrap();
+    /// }
+    /// usage.record(UsageEvent::new("api_calls", 100.0, CalendarDate::new(2024, 6, 1))).unwrap();
+    /// assert!(usage.record(UsageEvent::new("storage", 1.0, CalendarDate::new(2024, 5, 1))).is_err());
+    ///
+    /// let may = BillingPeriod::new(CalendarDate::new(2024, 5, 1), CalendarDate::new(2024, 6, 1));
+    /// let invoice = usage.invoice(may, RoundingMode::Nearest).unwrap();
+    ///
+    /// assert!((invoice.meters[0].quantity - 1.2).abs() < 1e-9);
+    /// assert_eq!(invoice.total, Owo::new(1,usd.clone()));
+    /// ```
+    pub fn invoice(
+        &self,
+        period: BillingPeriod,
+        mode: RoundingMode,
+    ) -> Result<UsageInvoice, OwoError> {
+        let mut meters = Vec::with_capacity(self.meters.len());
+        let mut total = Owo::new(0, self.currency);
+        for (name, price) in &self.meters {
+            let quantity: f64 = self
+                .events
+                .iter()
+                .filter(|event| event.meter == *name)
+                .filter(|event| period.start <= event.date && event.date < period.end)
+                .map(|event| event.quantity)
+                .sum();
+            let amount = match price {
+                MeterPrice::PerUnit(unit_price) => unit_price.multiply_with_mode(quantity, mode)?,
+                MeterPrice::Tiered(tiers) if quantity <= 0.0 => Owo::new(0, self.currency),
+                MeterPrice::Tiered(tiers) => tiers.price(quantity.ceil() as u64)?.total,
+            };
+            total = total.checked_add(&amount)?;
+            meters.push(MeterSubtotal {
+                meter: name.clone(),
+                quantity,
+                amount,
+            });
+        }
+        Ok(UsageInvoice {
+            period,
+            meters,
+            total,
+        })
+    }
+}
@@ -0,0 +1,184 @@
+//! The `cowry` command-line tool, built with the `cli` feature.
+//!
+//! Amounts are written as compact strings such as `"USD 12.34"`, with the
+//! currency resolved from the ISO 4217 registry.
+
+use cowry::error::OwoError;
+use cowry::prelude::*;
+use cowry::registry;
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage:
+  cowry format <CODE> <MINOR_UNITS>              Format minor units, e.g. `cowry format USD 1234`
+  cowry parse <AMOUNT>                           Print the minor units of an amount
+  cowry convert <AMOUNT> <CODE> --rates <FILE>   Convert using a rates file of `FROM TO RATE` lines
+  cowry sum [AMOUNT...]                          Sum amounts, read one per line from stdin if none are given
+  cowry allocate <AMOUNT> <RATIO>...             Split an amount by whole-number ratios
+
+Amounts are compact strings such as \"USD 12.34\".";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some((command, rest)) = args.split_first() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+    let result = match command.as_str() {
+        "format" => format(rest),
+        "parse" => parse(rest),
+        "convert" => convert(rest),
+        "sum" => sum(rest),
+        "allocate" => allocate(rest),
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        _ => Err(CliError::Usage(format!("unknown command `{}`", command))),
+    };
+    match result {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(CliError::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+        Err(CliError::Failed(message)) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Why a command failed: bad arguments, or an error doing the work
+enum CliError {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<OwoError> for CliError {
+    fn from(error: OwoError) -> Self {
+        CliError::Failed(error.to_string())
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(error: io::Error) -> Self {
+        CliError::Failed(error.to_string())
+    }
+}
+
+type CliResult = Result<Vec<String>, CliError>;
+
+fn format(args: &[String]) -> CliResult {
+    let [code, minor] = args else {
+        return Err(CliError::Usage(
+            "format takes <CODE> <MINOR_UNITS>".to_string(),
+        ));
+    };
+    let amount = minor
+        .parse()
+        .map_err(|_| CliError::Usage(format!("`{}` is not a whole number", minor)))?;
+    Ok(vec![Owo::new(amount, registry::currency(code)?).format()])
+}
+
+fn parse(args: &[String]) -> CliResult {
+    let [amount] = args else {
+        return Err(CliError::Usage("parse takes one <AMOUNT>".to_string()));
+    };
+    Ok(vec![amount.parse::<Owo>()?.get_amount().to_string()])
+}
+
+fn convert(args: &[String]) -> CliResult {
+    let [amount, code, flag, path] = args else {
+        return Err(CliError::Usage(
+            "convert takes <AMOUNT> <CODE> --rates <FILE>".to_string(),
+        ));
+    };
+    if flag != "--rates" {
+        return Err(CliError::Usage(format!("unexpected argument `{}`", flag)));
+    }
+    let rates = read_rates(&std::fs::read_to_string(path)?)?;
+    let owo: Owo = amount.parse()?;
+    let converted = owo.convert(&rates, &registry::currency(code)?, RoundingMode::Nearest)?;
+    Ok(vec![converted.to_compact_string()])
+}
+
+fn sum(args: &[String]) -> CliResult {
+    let inputs = if args.is_empty() {
+        let mut lines = Vec::new();
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                lines.push(line.trim().to_string());
+            }
+        }
+        lines
+    } else {
+        args.to_vec()
+    };
+    let items = inputs
+        .iter()
+        .map(|input| input.parse())
+        .collect::<Result<Vec<Owo>, _>>()?;
+    Ok(vec![items.total()?.to_compact_string()])
+}
+
+fn allocate(args: &[String]) -> CliResult {
+    let Some((amount, ratios)) = args.split_first() else {
+        return Err(CliError::Usage(
+            "allocate takes <AMOUNT> <RATIO>...".to_string(),
+        ));
+    };
+    let ratios = ratios
+        .iter()
+        .map(|ratio| {
+            ratio
+                .parse()
+                .map_err(|_| CliError::Usage(format!("`{}` is not a whole-number ratio", ratio)))
+        })
+        .collect::<Result<Vec<u64>, _>>()?;
+    let shares = amount.parse::<Owo>()?.allocate(&ratios)?;
+    Ok(shares.iter().map(Owo::to_compact_string).collect())
+}
+
+// Helper reading `FROM TO RATE` lines, ignoring blank lines and `#` comments
+fn read_rates(text: &str) -> Result<RateTable, CliError> {
+    let mut rates = RateTable::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line
+            .split([' ', '\t', ','])
+            .filter(|f| !f.is_empty())
+            .collect();
+        match fields[..] {
+            [from, to, rate] => match rate.parse() {
+                Ok(rate) => rates.set_rate(from, to, rate).map_err(|err| {
+                    CliError::Failed(format!("rates line {}: {}", index + 1, err))
+                })?,
+                Err(_) => {
+                    return Err(CliError::Failed(format!(
+                        "rates line {}: `{}` is not a number",
+                        index + 1,
+                        rate
+                    )));
+                }
+            },
+            _ => {
+                return Err(CliError::Failed(format!(
+                    "rates line {}: expected `FROM TO RATE`",
+                    index + 1
+                )));
+            }
+        }
+    }
+    Ok(rates)
+}
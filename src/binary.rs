@@ -0,0 +1,159 @@
+//! A compact, stable binary encoding for `Owo`.
+//!
+//! Layout, version 1:
+//!
+//! | Bytes | Meaning |
+//! |---|---|
+//! | 1 | Tag: `0` for an ISO 4217 currency, `1` for a custom currency |
+//! | 2 | Tag `0`: ISO numeric code, little endian |
+//! | n | Tag `1`: code and symbol (each a varint length then UTF-8 bytes), then the precision byte |
+//! | n | The amount in minor units, as a zigzag varint |
+//!
+//! An ISO currency takes 3 bytes, so small amounts encode in 4 to 6 bytes.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo, registry};
+
+const TAG_ISO: u8 = 0;
+const TAG_CUSTOM: u8 = 1;
+
+impl Owo {
+    /// Encodes the money into the compact binary format.
+    ///
+    /// Currencies that exactly match an ISO 4217 registry entry are stored as
+    /// their 2-byte numeric code; any other currency is stored in full.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let owo = Owo::new(599, usd);
+    ///
+    /// let bytes = owo.to_bytes();
+    /// assert_eq!(bytes.len(), 5);
+    /// assert_eq!(Owo::from_bytes(&bytes).unwrap(), owo);
+    ///
+    /// let btc = Owo::new(-200, Currency::new("BTC", "₿", 8));
+    /// assert_eq!(Owo::from_bytes(&btc.to_bytes()).unwrap(), btc);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        match registry::find(self.currency.code).filter(|iso| iso.to_currency() == self.currency) {
+            Some(iso) => {
+                bytes.push(TAG_ISO);
+                bytes.extend_from_slice(&iso.numeric.to_le_bytes());
+            }
+            None => {
+                bytes.push(TAG_CUSTOM);
+                write_str(&mut bytes, self.currency.code);
+                write_str(&mut bytes, self.currency.symbol);
+                bytes.push(self.currency.precision);
+            }
+        }
+        write_varint(&mut bytes, zigzag(self.amount));
+        bytes
+    }
+
+    /// Decodes money from the compact binary format produced by `to_bytes`.
+    ///
+    /// Custom currencies are resolved with [`Currency::resolve`], so they
+    /// must have been created with `Currency::new` first.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let owo = Owo::from_bytes(&[0, 0x36, 0x02, 0xc8, 0x01]).unwrap();
+    ///
+    /// assert_eq!(owo.format(), "₦1.00");
+    /// assert!(Owo::from_bytes(&[0, 0x36]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Owo, OwoError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let currency = match reader.byte()? {
+            TAG_ISO => {
+                let numeric = u16::from_le_bytes([reader.byte()?, reader.byte()?]);
+                registry::find_numeric(numeric)
+                    .map(|iso| iso.to_currency())
+                    .ok_or_else(|| OwoError::UnknownCurrency(numeric.to_string()))?
+            }
+            TAG_CUSTOM => {
+                let code = reader.string()?;
+                let symbol = reader.string()?;
+                Currency::resolve(&code, &symbol, reader.byte()?)?
+            }
+            tag => return Err(OwoError::DecodeError(format!("unknown tag {}", tag))),
+        };
+        let amount = unzigzag(reader.varint()?);
+        if reader.pos != bytes.len() {
+            return Err(OwoError::DecodeError("trailing bytes".to_string()));
+        }
+        Ok(Owo::new(amount, currency))
+    }
+}
+
+// Helper mapping signed values to unsigned so small negatives stay short
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+// Helper reversing `zigzag`
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+// Helper writing an unsigned LEB128 varint
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+// Helper writing a length-prefixed UTF-8 string
+fn write_str(bytes: &mut Vec<u8>, value: &str) {
+    write_varint(bytes, value.len() as u64);
+    bytes.extend_from_slice(value.as_bytes());
+}
+
+// Cursor over the encoded bytes
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, OwoError> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| OwoError::DecodeError("unexpected end of input".to_string()))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> Result<u64, OwoError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(OwoError::DecodeError("varint too long".to_string()))
+    }
+
+    fn string(&mut self) -> Result<String, OwoError> {
+        let len = self.varint()? as usize;
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| OwoError::DecodeError("unexpected end of input".to_string()))?;
+        let value = std::str::from_utf8(&self.bytes[self.pos..end])
+            .map_err(|_| OwoError::DecodeError("invalid UTF-8".to_string()))?;
+        self.pos = end;
+        Ok(value.to_string())
+    }
+}
@@ -0,0 +1,215 @@
+//! Envelope budgeting: an overall amount divided into named envelopes.
+
+use crate::error::OwoError;
+use crate::tax::apportion;
+use crate::{Currency, Owo};
+use serde::{Deserialize, Serialize};
+
+/// How much of the budget an envelope receives.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Allocation {
+    /// A fixed amount, taken off the budget first.
+    Fixed(Owo),
+    /// A percentage of what the fixed envelopes leave over.
+    Percentage(f64),
+}
+
+/// A named envelope with the amount allocated to it and spent from it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Envelope {
+    pub name: String,
+    pub allocated: Owo,
+    pub spent: Owo,
+}
+
+impl Envelope {
+    /// Returns what's left to spend, negative once the envelope is overspent.
+    pub fn remaining(&self) -> Result<Owo, OwoError> {
+        self.allocated.checked_sub(&self.spent)
+    }
+}
+
+/// An overall amount divided into envelopes, with whatever isn't allocated
+/// kept aside so the envelopes and the unallocated amount always sum to the
+/// total.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Budget {
+    total: Owo,
+    envelopes: Vec<Envelope>,
+    unallocated: Owo,
+}
+
+impl Budget {
+    /// Divides `total` into the named envelopes
+    ///
+    /// Fixed envelopes are funded first and the percentages apply to what
+    /// they leave over. Percentage envelopes are rounded by largest
+    /// remainder, so when the percentages add up to 100 nothing is left
+    /// unallocated. Returns an error for a negative total, duplicate names,
+    /// negative or oversized allocations, or percentages over 100 in total.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::budget::{Allocation, Budget};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let budget = Budget::new(Owo::new(100000,ngn.clone()), &[
+    ///     ("rent", Allocation::Fixed(Owo::new(40000,ngn.clone()))),
+    ///     ("food", Allocation::Percentage(100.0 / 3.0)),
+    ///     ("transport", Allocation::Percentage(100.0 / 3.0)),
+    ///     ("savings", Allocation::Percentage(100.0 / 3.0)),
+    /// ]).unwrap();
+    ///
+    /// let allocated: Vec<i64> = budget.envelopes().iter().map(|e| e.allocated.get_amount()).collect();
+    /// assert_eq!(allocated, vec![40000, 20000, 20000, 20000]);
+    /// assert_eq!(budget.unallocated().get_amount(), 0);
+    ///
+    /// let rent = [("rent", Allocation::Fixed(Owo::new(40000,ngn.clone())))];
+    /// assert!(Budget::new(Owo::new(i64::MIN + 1,ngn.clone()), &rent).is_err());
+    /// ```
+    pub fn new(total: Owo, envelopes: &[(&str, Allocation)]) -> Result<Self, OwoError> {
+        if total.amount < 0 {
+            return Err(OwoError::InvalidBudget(format!(
+                "total {} is negative",
+                total.to_compact_string()
+            )));
+        }
+        let mut fixed = 0i64;
+        let mut percent = 0.0;
+        for (index, (name, allocation)) in envelopes.iter().enumerate() {
+            if envelopes[..index].iter().any(|(other, _)| other == name) {
+                return Err(OwoError::InvalidBudget(format!(
+                    "envelope {name} appears twice"
+                )));
+            }
+            match allocation {
+                Allocation::Fixed(amount) => {
+                    total.check_currency(amount)?;
+                    if amount.amount < 0 {
+                        return Err(OwoError::InvalidBudget(format!(
+                            "envelope {name} is negative"
+                        )));
+                    }
+                    fixed = fixed
+                        .checked_add(amount.amount)
+                        .ok_or_else(|| total.overflow("add", amount.amount))?;
+                }
+                Allocation::Percentage(share) => {
+                    if !share.is_finite() || *share < 0.0 {
+                        return Err(OwoError::InvalidScalar(*share));
+                    }
+                    percent += share;
+                }
+            }
+        }
+        let left = total.amount - fixed;
+        if left < 0 {
+            return Err(OwoError::InvalidBudget(format!(
+                "fixed envelopes exceed the total by {}",
+                Owo::new(-left, total.currency).to_compact_string()
+            )));
+        }
+        if percent > 100.0 + 1e-9 {
+            return Err(OwoError::InvalidBudget(format!(
+                "percentages add up to {percent}"
+            )));
+        }
+
+        // Round the percentage envelopes together so they sum to their share
+        let raws: Vec<f64> = envelopes
+            .iter()
+            .filter_map(|(_, allocation)| match allocation {
+                Allocation::Percentage(share) => Some(left as f64 * share / 100.0),
+                Allocation::Fixed(_) => None,
+            })
+            .collect();
+        let target = if percent > 100.0 - 1e-9 {
+            left
+        } else {
+            raws.iter().sum::<f64>().round() as i64
+        };
+        let mut shares = apportion(target, &raws).into_iter();
+
+        let zero = Owo::new(0, total.currency);
+        let envelopes = envelopes
+            .iter()
+            .map(|(name, allocation)| Envelope {
+                name: name.to_string(),
+                allocated: match allocation {
+                    Allocation::Fixed(amount) => *amount,
+                    Allocation::Percentage(_) => {
+                        Owo::new(shares.next().unwrap_or(0), total.currency)
+                    }
+                },
+                spent: zero,
+            })
+            .collect();
+        Ok(Self {
+            unallocated: Owo::new(left - target, total.currency),
+            total,
+            envelopes,
+        })
+    }
+
+    /// Records `amount` spent from the envelope called `name`; envelopes may
+    /// be overspent
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::budget::{Allocation, Budget};
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut budget = Budget::new(Owo::new(50000,usd.clone()), &[
+    ///     ("groceries", Allocation::Percentage(60.0)),
+    ///     ("fun", Allocation::Percentage(40.0)),
+    /// ]).unwrap();
+    ///
+    /// budget.spend("fun", &Owo::new(25000,usd.clone())).unwrap();
+    /// let fun = budget.envelope("fun").unwrap();
+    /// assert_eq!(fun.remaining().unwrap(), Owo::new(-5000,usd.clone()));
+    ///
+    /// assert!(budget.spend("rent", &Owo::new(1,usd.clone())).is_err());
+    /// ```
+    pub fn spend(&mut self, name: &str, amount: &Owo) -> Result<(), OwoError> {
+        let envelope = self
+            .envelopes
+            .iter_mut()
+            .find(|envelope| envelope.name == name)
+            .ok_or_else(|| OwoError::UnknownEnvelope(name.to_string()))?;
+        envelope.spent = envelope.spent.checked_add(amount)?;
+        Ok(())
+    }
+
+    pub fn total(&self) -> &Owo {
+        &self.total
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.total.currency
+    }
+
+    pub fn envelopes(&self) -> &[Envelope] {
+        &self.envelopes
+    }
+
+    /// Looks up an envelope by name.
+    pub fn envelope(&self, name: &str) -> Option<&Envelope> {
+        self.envelopes.iter().find(|envelope| envelope.name == name)
+    }
+
+    /// Returns the part of the total no envelope received.
+    pub fn unallocated(&self) -> &Owo {
+        &self.unallocated
+    }
+
+    /// Returns what's left of the whole budget, unallocated amount included.
+    pub fn remaining(&self) -> Result<Owo, OwoError> {
+        let mut remaining = self.total;
+        for envelope in &self.envelopes {
+            remaining = remaining.checked_sub(&envelope.spent)?;
+        }
+        Ok(remaining)
+    }
+}
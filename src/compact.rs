@@ -0,0 +1,103 @@
+//! A compact `Owo` representation for columnar processing of large datasets.
+//!
+//! [`CompactOwo`] stores a small [`CurrencyId`] instead of the full
+//! [`Currency`], shrinking each value to 12 bytes. Ids come from a
+//! [`CurrencyTable`], which resolves them back to currencies.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo};
+use serde::{Deserialize, Serialize};
+
+/// An index into a [`CurrencyTable`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurrencyId(pub u16);
+
+/// An amount in minor units and the id of its currency in a
+/// [`CurrencyTable`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C, packed(4))]
+pub struct CompactOwo {
+    pub amount: i64,
+    pub currency: CurrencyId,
+}
+
+/// The currencies a set of [`CompactOwo`] values refer to, each stored once.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CurrencyTable {
+    currencies: Vec<Currency>,
+}
+
+impl CurrencyTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id for `currency`, adding it to the table if it's new
+    ///
+    /// Returns an error once the table holds `u16::MAX` currencies.
+    pub fn intern(&mut self, currency: &Currency) -> Result<CurrencyId, OwoError> {
+        if let Some(index) = self.currencies.iter().position(|c| c == currency) {
+            return Ok(CurrencyId(index as u16));
+        }
+        let id = u16::try_from(self.currencies.len()).map_err(|_| OwoError::Overflow {
+            op: "intern",
+            lhs: self.currencies.len().to_string(),
+            rhs: currency.code.to_string(),
+        })?;
+        self.currencies.push(*currency);
+        Ok(CurrencyId(id))
+    }
+
+    /// Returns the currency with `id`, if the table has one.
+    pub fn resolve(&self, id: CurrencyId) -> Option<&Currency> {
+        self.currencies.get(usize::from(id.0))
+    }
+
+    /// Converts `owo` to its compact form, interning its currency
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::compact::{CompactOwo, CurrencyTable};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut table = CurrencyTable::new();
+    ///
+    /// let rows: Vec<CompactOwo> = [Owo::new(500,ngn.clone()), Owo::new(300,usd.clone()), Owo::new(700,ngn.clone())]
+    ///     .iter()
+    ///     .map(|owo| table.compact(owo).unwrap())
+    ///     .collect();
+    ///
+    /// assert_eq!(std::mem::size_of::<CompactOwo>(), 12);
+    /// assert_eq!(rows[0].currency, rows[2].currency);
+    /// assert_eq!(table.expand(&rows[1]).unwrap(), Owo::new(300,usd.clone()));
+    /// ```
+    pub fn compact(&mut self, owo: &Owo) -> Result<CompactOwo, OwoError> {
+        Ok(CompactOwo {
+            amount: owo.amount,
+            currency: self.intern(&owo.currency)?,
+        })
+    }
+
+    /// Resolves a compact value back to a full `Owo`, erroring if its
+    /// currency id isn't in the table.
+    pub fn expand(&self, compact: &CompactOwo) -> Result<Owo, OwoError> {
+        let id = compact.currency;
+        let currency = self
+            .resolve(id)
+            .ok_or_else(|| OwoError::UnknownCurrency(format!("id {}", id.0)))?;
+        Ok(Owo::new(compact.amount, *currency))
+    }
+
+    /// Returns the number of currencies in the table.
+    pub fn len(&self) -> usize {
+        self.currencies.len()
+    }
+
+    /// Returns `true` if the table holds no currencies.
+    pub fn is_empty(&self) -> bool {
+        self.currencies.is_empty()
+    }
+}
@@ -0,0 +1,135 @@
+//! Reading and writing collections of `Owo` as CSV, with `amount` and
+//! `currency` columns.
+//!
+//! Amounts are written either in minor units (`599`) or as decimal strings
+//! (`5.99`), and currency codes are resolved from the ISO 4217 registry when
+//! reading.
+
+use crate::error::OwoError;
+use crate::{Owo, registry};
+use std::io;
+
+/// How the `amount` column is written and read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmountFormat {
+    /// Integer minor units, e.g. `599`.
+    MinorUnits,
+    /// Decimal major units, e.g. `5.99`.
+    #[default]
+    Decimal,
+}
+
+/// Writes `items` as CSV with an `amount,currency` header row
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::csv::{AmountFormat, write_owos};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let items = vec![Owo::new(599,usd.clone()), Owo::new(-1250,usd.clone())];
+///
+/// let mut out = Vec::new();
+/// write_owos(&mut out, &items, AmountFormat::Decimal).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "amount,currency\n5.99,USD\n-12.50,USD\n");
+/// ```
+pub fn write_owos<W: io::Write>(
+    writer: W,
+    items: &[Owo],
+    format: AmountFormat,
+) -> Result<(), OwoError> {
+    let mut writer = ::csv::Writer::from_writer(writer);
+    writer.write_record(["amount", "currency"])?;
+    for owo in items {
+        let amount = match format {
+            AmountFormat::MinorUnits => owo.amount.to_string(),
+            AmountFormat::Decimal => owo.to_decimal_string(),
+        };
+        writer.write_record([amount.as_str(), owo.currency.code])?;
+    }
+    writer.flush().map_err(::csv::Error::from)?;
+    Ok(())
+}
+
+/// Reads CSV with `amount` and `currency` header columns, in any order and
+/// alongside other columns, returning one result per row
+///
+/// A malformed row yields an error naming its index, counting from 0 for
+/// the first row after the header, and reading carries on with the next.
+/// An unreadable header fails the whole read.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::csv::{AmountFormat, read_owos};
+///
+/// let data = "id,currency,amount\n1,NGN,1234.50\n2,XYZ,1.00\n3,USD,abc\n4,JPY,500\n";
+/// let rows = read_owos(data.as_bytes(), AmountFormat::Decimal).unwrap();
+///
+/// assert_eq!(rows[0].as_ref().unwrap().get_amount(), 123450);
+/// assert!(rows[1].is_err());
+/// assert!(rows[2].as_ref().unwrap_err().to_string().starts_with("Item 2"));
+/// assert_eq!(rows[3].as_ref().unwrap().format(), "¥500");
+/// ```
+pub fn read_owos<R: io::Read>(
+    reader: R,
+    format: AmountFormat,
+) -> Result<Vec<Result<Owo, OwoError>>, OwoError> {
+    let mut reader = ::csv::Reader::from_reader(reader);
+    let headers = reader.headers()?.clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header.trim().eq_ignore_ascii_case(name))
+            .ok_or_else(|| OwoError::ParseError {
+                input: headers.iter().collect::<Vec<_>>().join(","),
+                reason: format!("missing a {name} column"),
+            })
+    };
+    let amount_column = column("amount")?;
+    let currency_column = column("currency")?;
+
+    Ok(reader
+        .records()
+        .enumerate()
+        .map(|(index, record)| {
+            parse_row(record, amount_column, currency_column, format).map_err(|source| {
+                OwoError::AtIndex {
+                    index,
+                    source: Box::new(source),
+                }
+            })
+        })
+        .collect())
+}
+
+// Helper turning one CSV record into an `Owo`
+fn parse_row(
+    record: Result<::csv::StringRecord, ::csv::Error>,
+    amount_column: usize,
+    currency_column: usize,
+    format: AmountFormat,
+) -> Result<Owo, OwoError> {
+    let record = record?;
+    let field = |column: usize| {
+        record
+            .get(column)
+            .map(str::trim)
+            .ok_or_else(|| OwoError::ParseError {
+                input: record.iter().collect::<Vec<_>>().join(","),
+                reason: "row is missing a column".to_string(),
+            })
+    };
+    let currency = registry::currency(field(currency_column)?)?;
+    let amount = field(amount_column)?;
+    match format {
+        AmountFormat::Decimal => Owo::from_decimal_str(amount, currency),
+        AmountFormat::MinorUnits => amount
+            .parse::<i64>()
+            .map(|amount| Owo::new(amount, currency))
+            .map_err(|error| OwoError::ParseError {
+                input: amount.to_string(),
+                reason: error.to_string(),
+            }),
+    }
+}
@@ -1,20 +1,354 @@
+use crate::error::OwoError;
+use serde::de::Error as _;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::{OnceLock, RwLock};
 
 /// A representation of a currency, such as USD or NGN.
-#[derive(Serialize, Deserialize, Debug, Clone,PartialEq,Eq)]
+///
+/// The code and symbol are `'static` strings, so `Currency` (and `Owo`) are
+/// `Copy` and currencies can live in `static` items.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Currency {
-    pub code: String,
-    pub symbol: String,
+    pub code: &'static str,
+    pub symbol: &'static str,
     pub precision: u8,
 }
 
+// The owned shape currencies deserialize through before resolving
+#[derive(Deserialize)]
+struct CurrencyRepr {
+    code: String,
+    symbol: String,
+    precision: u8,
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CurrencyRepr::deserialize(deserializer)?;
+        Currency::resolve(&repr.code, &repr.symbol, repr.precision).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for Currency {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh::BorshSerialize::serialize(self.code, writer)?;
+        borsh::BorshSerialize::serialize(self.symbol, writer)?;
+        borsh::BorshSerialize::serialize(&self.precision, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for Currency {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let code = String::deserialize_reader(reader)?;
+        let symbol = String::deserialize_reader(reader)?;
+        let precision = u8::deserialize_reader(reader)?;
+        Currency::resolve(&code, &symbol, precision).map_err(|err| {
+            borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, err.to_string())
+        })
+    }
+}
+
+// Powers of ten for every supported precision, so scaling never calls pow
+const SCALE_FACTORS: [i64; Currency::MAX_PRECISION as usize + 1] = {
+    let mut factors = [1i64; Currency::MAX_PRECISION as usize + 1];
+    let mut index = 1;
+    while index < factors.len() {
+        factors[index] = factors[index - 1] * 10;
+        index += 1;
+    }
+    factors
+};
+
 impl Currency {
+    /// The largest supported precision; `10^19` no longer fits in an `i64`.
+    pub const MAX_PRECISION: u8 = 18;
+
+    /// Returns the number of minor units in one major unit, `10^precision`,
+    /// from a lookup table.
+    ///
+    /// Every constructor and decoder rejects precisions above
+    /// [`Currency::MAX_PRECISION`], so the lookup always succeeds.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// assert_eq!(Currency::new("NGN", "₦", 2).scale_factor(), 100);
+    /// assert_eq!(Currency::new("JPY", "¥", 0).scale_factor(), 1);
+    /// ```
+    pub const fn scale_factor(&self) -> i64 {
+        SCALE_FACTORS[self.precision as usize]
+    }
+
+    // Helper for the scale factor as a float
+    pub(crate) fn scale_factor_f64(&self) -> f64 {
+        self.scale_factor() as f64
+    }
+
     /// Creates a new currency definition.
+    ///
+    /// Codes and symbols from the ISO 4217 registry are borrowed from it
+    /// after a scan of the registry. Any other string is looked up in the
+    /// intern table and, the first time it is seen, copied and leaked for the
+    /// life of the process, so build custom currencies from a bounded set of
+    /// codes, never from untrusted input; decoders use [`Currency::resolve`]
+    /// instead.
+    ///
+    /// Panics if `precision` is above [`Currency::MAX_PRECISION`]; see
+    /// [`Currency::try_new`].
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::registry;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// assert!(std::ptr::eq(usd.code, registry::find("USD").unwrap().code));
+    ///
+    /// let points = Currency::new("PTS", "★", 0);
+    /// assert!(std::ptr::eq(points.code, Currency::new("PTS", "★", 0).code));
+    /// ```
     pub fn new(code: &str, symbol: &str, precision: u8) -> Self {
+        assert!(
+            precision <= Self::MAX_PRECISION,
+            "currency precision is above Currency::MAX_PRECISION"
+        );
+        Self::from_static(intern(code), intern(symbol), precision)
+    }
+
+    /// Resolves a currency definition received from outside, such as a
+    /// decoded payload, against the ISO 4217 registry and the custom
+    /// currencies already created with [`Currency::new`] or passed to
+    /// [`Currency::register`].
+    ///
+    /// Unlike `new`, this never interns anything, so untrusted input can't
+    /// grow memory: a code or symbol that isn't already known is rejected,
+    /// as is a precision above [`Currency::MAX_PRECISION`].
+    /// Every decoder in this crate resolves currencies this way; create
+    /// custom currencies with `new`, or register `const` ones, before
+    /// decoding amounts in them.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// assert_eq!(Currency::resolve("USD", "$", 2).unwrap(), Currency::new("USD", "$", 2));
+    ///
+    /// assert!(Currency::resolve("USD", "$", 40).is_err());
+    /// assert!(Currency::resolve("QQQ", "Q", 0).is_err());
+    /// let qqq = Currency::new("QQQ", "Q", 0);
+    /// assert_eq!(Currency::resolve("QQQ", "Q", 0).unwrap(), qqq);
+    /// ```
+    pub fn resolve(code: &str, symbol: &str, precision: u8) -> Result<Self, OwoError> {
+        if precision > Self::MAX_PRECISION {
+            return Err(OwoError::InvalidPrecision(precision));
+        }
+        match (interned(code), interned(symbol)) {
+            (Some(code), Some(symbol)) => Ok(Self {
+                code,
+                symbol,
+                precision,
+            }),
+            _ => Err(OwoError::UnknownCurrency(code.to_string())),
+        }
+    }
+
+    /// Creates a currency definition from `'static` strings without
+    /// allocating, usable in `const` and `static` items.
+    ///
+    /// Panics, or fails to compile in a `const`, if `precision` is above
+    /// [`Currency::MAX_PRECISION`]. Call [`Currency::register`] on custom
+    /// currencies built this way before decoding amounts in them.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// static USD: Currency = Currency::from_static("USD", "$", 2);
+    /// const FEE: Owo = Owo::new(250, Currency::from_static("USD", "$", 2));
+    ///
+    /// assert_eq!(USD.code, "USD");
+    /// assert_eq!(FEE.format(), "$2.50");
+    /// assert_eq!(FEE.currency, Currency::new("USD", "$", 2));
+    /// ```
+    pub const fn from_static(code: &'static str, symbol: &'static str, precision: u8) -> Self {
+        assert!(
+            precision <= Self::MAX_PRECISION,
+            "currency precision is above Currency::MAX_PRECISION"
+        );
         Self {
-            code: code.to_string(),
-            symbol: symbol.to_string(),
+            code,
+            symbol,
             precision,
         }
     }
+
+    /// Makes a currency built with [`Currency::from_static`] or
+    /// [`currency!`](crate::currency!) known to [`Currency::resolve`], so
+    /// amounts in it can be decoded, and returns it.
+    ///
+    /// Its strings are already `'static`, so nothing is copied or leaked.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::currency;
+    ///
+    /// static POINTS: Currency = currency!("PTQ", "♦", 0);
+    /// let balance = Owo::new(120,POINTS);
+    /// let json = serde_json::to_string(&balance).unwrap();
+    /// assert!(serde_json::from_str::<Owo>(&json).is_err());
+    ///
+    /// POINTS.register();
+    /// assert_eq!(serde_json::from_str::<Owo>(&json).unwrap(), balance);
+    /// ```
+    pub fn register(self) -> Self {
+        let mut interned = interned_strings()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        interned.insert(self.code);
+        interned.insert(self.symbol);
+        self
+    }
+
+    /// Creates a new currency definition, rejecting precisions whose scale
+    /// factor wouldn't fit in an `i64` minor-unit amount.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let btc = Currency::try_new("BTC", "₿", 8).unwrap();
+    /// assert_eq!(btc.precision, 8);
+    ///
+    /// assert!(Currency::try_new("XXX", "X", 19).is_err());
+    /// ```
+    pub fn try_new(code: &str, symbol: &str, precision: u8) -> Result<Self, OwoError> {
+        if precision > Self::MAX_PRECISION {
+            return Err(OwoError::InvalidPrecision(precision));
+        }
+        Ok(Self::new(code, symbol, precision))
+    }
+
+    /// Starts building a currency definition by named fields, validated on
+    /// [`CurrencyBuilder::build`].
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::builder().code("NGN").symbol("₦").precision(2).build().unwrap();
+    /// assert_eq!(ngn, Currency::new("NGN", "₦", 2));
+    ///
+    /// // The symbol defaults to the code
+    /// let points = Currency::builder().code("PTS").precision(0).build().unwrap();
+    /// assert_eq!(points.symbol, "PTS");
+    ///
+    /// assert!(Currency::builder().code("ngn").precision(2).build().is_err());
+    /// assert!(Currency::builder().code("NGN").precision(19).build().is_err());
+    /// assert!(Currency::builder().symbol("₦").precision(2).build().is_err());
+    /// ```
+    pub fn builder() -> CurrencyBuilder {
+        CurrencyBuilder::default()
+    }
+}
+
+/// Builds a [`Currency`] by named fields, so the code, symbol and precision
+/// can't be passed in the wrong order.
+#[derive(Debug, Clone, Default)]
+pub struct CurrencyBuilder {
+    code: Option<String>,
+    symbol: Option<String>,
+    precision: Option<u8>,
+}
+
+impl CurrencyBuilder {
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_string());
+        self
+    }
+
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Builds the currency, requiring a code of upper-case ASCII letters or
+    /// digits and a precision of at most [`Currency::MAX_PRECISION`]. The
+    /// symbol defaults to the code.
+    pub fn build(self) -> Result<Currency, OwoError> {
+        let code = self.code.ok_or(OwoError::MissingField("code"))?;
+        if !is_valid_code(&code) {
+            return Err(OwoError::InvalidCurrencyCode(code));
+        }
+        let precision = self.precision.ok_or(OwoError::MissingField("precision"))?;
+        let symbol = self.symbol.unwrap_or_else(|| code.clone());
+        Currency::try_new(&code, &symbol, precision)
+    }
+}
+
+// The registry's codes and symbols, plus strings interned by `Currency::new`
+// or registered with `Currency::register`, which only trusted code calls
+static INTERNED: OnceLock<RwLock<HashSet<&'static str>>> = OnceLock::new();
+
+// Helper returning the interned strings, seeded with the registry's on
+// first use so later lookups are a single hash probe
+fn interned_strings() -> &'static RwLock<HashSet<&'static str>> {
+    INTERNED.get_or_init(|| {
+        let historic = crate::registry::historic::HISTORIC_CURRENCIES
+            .iter()
+            .map(|historic| &historic.currency);
+        let strings = crate::registry::ISO_CURRENCIES
+            .iter()
+            .chain(historic)
+            .flat_map(|iso| [iso.code, iso.symbol])
+            .collect();
+        RwLock::new(strings)
+    })
+}
+
+// Helper returning the `'static` copy of `text` from the registry or the
+// interned strings, without interning anything
+fn interned(text: &str) -> Option<&'static str> {
+    interned_strings()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(text)
+        .copied()
+}
+
+// Helper returning a `'static` copy of `text`, borrowed from the registry
+// where possible and otherwise leaked once and reused
+fn intern(text: &str) -> &'static str {
+    if let Some(existing) = interned(text) {
+        return existing;
+    }
+    let mut interned = interned_strings()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match interned.get(text) {
+        Some(existing) => existing,
+        None => {
+            let leaked: &'static str = Box::leak(text.into());
+            interned.insert(leaked);
+            leaked
+        }
+    }
+}
+
+// Helper checking a code is non-empty upper-case ASCII letters or digits
+pub(crate) const fn is_valid_code(code: &str) -> bool {
+    let bytes = code.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_uppercase() && !bytes[index].is_ascii_digit() {
+            return false;
+        }
+        index += 1;
+    }
+    !bytes.is_empty()
 }
@@ -0,0 +1,13 @@
+//! Database integrations. Driver-specific integrations are behind their own
+//! feature.
+
+pub mod numeric;
+
+#[cfg(feature = "bson")]
+pub mod bson;
+
+#[cfg(feature = "diesel")]
+pub mod diesel;
+
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
@@ -0,0 +1,142 @@
+//! MongoDB `Decimal128` support, so amounts stored in MongoDB keep exact
+//! decimal semantics end to end.
+//!
+//! Amounts are written with an exponent of minus the currency's precision,
+//! so `$12.34` is stored as `12.34` and sorts and aggregates correctly in
+//! MongoDB queries.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo, registry};
+use ::bson::Decimal128;
+use ::serde::de::Error as _;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const EXPONENT_BIAS: i32 = 6176;
+const MAX_COEFFICIENT: u128 = 10u128.pow(34) - 1;
+
+/// Converts the amount into a `Decimal128` at the currency's precision.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::bson;
+///
+/// let decimal = bson::to_decimal128(&Owo::new(1234, Currency::new("USD", "$", 2)));
+/// assert_eq!(decimal.to_string(), "12.34");
+/// ```
+pub fn to_decimal128(owo: &Owo) -> Decimal128 {
+    let sign = if owo.amount < 0 { 1u128 << 127 } else { 0 };
+    let exponent = (EXPONENT_BIAS - owo.currency.precision as i32) as u128;
+    let coefficient = owo.amount.unsigned_abs() as u128;
+    Decimal128::from_bytes((sign | (exponent << 113) | coefficient).to_le_bytes())
+}
+
+/// Converts a `Decimal128` into an `Owo` of the given currency, erroring on
+/// NaN, infinity, values out of range, and values with more fraction digits
+/// than the currency allows.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::bson;
+/// let usd = Currency::new("USD", "$", 2);
+/// let jpy = Currency::new("JPY", "¥", 0);
+///
+/// let decimal = bson::to_decimal128(&Owo::new(-1250, usd.clone()));
+/// assert_eq!(bson::from_decimal128(&decimal, usd).unwrap().get_amount(), -1250);
+///
+/// // -12.50 has a non-zero fraction digit that JPY can't hold
+/// assert!(bson::from_decimal128(&decimal, jpy).is_err());
+/// ```
+pub fn from_decimal128(decimal: &Decimal128, currency: Currency) -> Result<Owo, OwoError> {
+    let decode_error = |reason: &str| OwoError::DecodeError(format!("Decimal128: {}", reason));
+    let bits = u128::from_le_bytes(decimal.bytes());
+    if (bits >> 122) & 0b11110 == 0b11110 {
+        return Err(decode_error("NaN and infinity are not money"));
+    }
+    let (exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+        // Coefficients in this form exceed 10^34 - 1 and are non-canonical zeros
+        ((bits >> 111) & 0x3fff, 0)
+    } else {
+        ((bits >> 113) & 0x3fff, bits & ((1u128 << 113) - 1))
+    };
+    let coefficient = if coefficient > MAX_COEFFICIENT {
+        0
+    } else {
+        coefficient as i128
+    };
+
+    let shift = exponent as i32 - EXPONENT_BIAS + currency.precision as i32;
+    let magnitude = if shift >= 0 {
+        10i128
+            .checked_pow(shift as u32)
+            .and_then(|factor| coefficient.checked_mul(factor))
+            .ok_or_else(|| decode_error("amount out of range"))?
+    } else {
+        let divisor = 10i128
+            .checked_pow(shift.unsigned_abs())
+            .unwrap_or(i128::MAX);
+        if coefficient % divisor != 0 {
+            return Err(OwoError::ParseError {
+                input: decimal.to_string(),
+                reason: format!("more than {} fraction digits", currency.precision),
+            });
+        }
+        coefficient / divisor
+    };
+    let amount = if bits >> 127 == 1 {
+        -magnitude
+    } else {
+        magnitude
+    };
+    let amount = i64::try_from(amount).map_err(|_| decode_error("amount out of range"))?;
+    Ok(Owo::new(amount, currency))
+}
+
+/// Serializes `Owo` as a `Decimal128` amount and a currency code, e.g.
+/// `{ amount: NumberDecimal("12.34"), currency: "USD" }`.
+///
+/// Currencies are resolved from the ISO 4217 registry on deserialization.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Order {
+///     #[serde(with = "cowry::db::bson::decimal128")]
+///     total: Owo,
+/// }
+///
+/// let order = Order { total: Owo::new(1234, Currency::new("USD", "$", 2)) };
+/// let document = bson::to_document(&order).unwrap();
+///
+/// let total = document.get_document("total").unwrap();
+/// assert_eq!(total.get("amount").unwrap().to_string(), "12.34");
+/// assert_eq!(total.get_str("currency").unwrap(), "USD");
+///
+/// let back: Order = bson::from_document(document).unwrap();
+/// assert_eq!(back.total, order.total);
+/// ```
+pub mod decimal128 {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Repr {
+        amount: Decimal128,
+        currency: String,
+    }
+
+    pub fn serialize<S: Serializer>(owo: &Owo, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            amount: to_decimal128(owo),
+            currency: owo.currency.code.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Owo, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        let currency = registry::currency(&repr.currency).map_err(D::Error::custom)?;
+        from_decimal128(&repr.amount, currency).map_err(D::Error::custom)
+    }
+}
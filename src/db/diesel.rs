@@ -0,0 +1,162 @@
+//! Diesel integration for `Owo`, for every backend Diesel supports.
+//!
+//! Two column layouts are supported:
+//!
+//! - **Amount + currency columns** (recommended): `Owo` implements
+//!   `Queryable<(BigInt, Text), _>`, so selecting a `BIGINT` amount in minor
+//!   units and a `VARCHAR`/`TEXT` currency code loads straight into an `Owo`.
+//!   Write it with `amount.eq(owo.amount)` and `currency.eq(owo.currency.code)`.
+//! - **Single text column**: `Owo` implements `FromSql<Text, _>` using the
+//!   compact `"USD 5.99"` format. Write it by wrapping it in a
+//!   [`CompactOwo`], which implements `ToSql<Text, _>` and `AsExpression<Text>`.
+//!
+//! Currencies are validated against the ISO 4217 registry on load, so an
+//! unknown code is reported as a deserialization error instead of producing
+//! an `Owo` with a made-up currency.
+//!
+//! ```
+//! # use cowry::prelude::*;
+//! use cowry::db::diesel::CompactOwo;
+//! use diesel::prelude::*;
+//! use diesel::sqlite::SqliteConnection;
+//!
+//! diesel::table! {
+//!     orders (id) {
+//!         id -> BigInt,
+//!         amount -> BigInt,
+//!         currency -> Text,
+//!         total -> Text,
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let conn = &mut SqliteConnection::establish(":memory:")?;
+//! # diesel::sql_query("CREATE TABLE orders (id BIGINT PRIMARY KEY, amount BIGINT NOT NULL, currency TEXT NOT NULL, total TEXT NOT NULL)").execute(conn)?;
+//! let total = Owo::new(599,Currency::new("USD", "$", 2));
+//!
+//! diesel::insert_into(orders::table)
+//!     .values((
+//!         orders::id.eq(1),
+//!         orders::amount.eq(total.amount),
+//!         orders::currency.eq(total.currency.code),
+//!         orders::total.eq(CompactOwo::from(total)),
+//!     ))
+//!     .execute(conn)?;
+//!
+//! let columns: Owo = orders::table
+//!     .select((orders::amount, orders::currency))
+//!     .first(conn)?;
+//! let text: Owo = orders::table.select(orders::total).first(conn)?;
+//! assert_eq!(columns, total);
+//! assert_eq!(text, total);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Owo, registry};
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql, FromSqlRow, FromStaticSqlRow, Queryable};
+use ::diesel::expression::AsExpression;
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::{BigInt, Text};
+
+/// An `Owo` bound as its compact `"USD 5.99"` text, for writing to and
+/// reading from a single text column.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::diesel::CompactOwo;
+///
+/// let compact = CompactOwo::from(Owo::new(599,Currency::new("USD", "$", 2)));
+/// assert_eq!(compact.as_str(), "USD 5.99");
+/// assert_eq!(compact.owo().amount, 599);
+/// ```
+#[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Text)]
+pub struct CompactOwo {
+    owo: Owo,
+    text: String,
+}
+
+impl CompactOwo {
+    /// Returns the wrapped amount.
+    pub fn owo(&self) -> Owo {
+        self.owo
+    }
+
+    /// Returns the compact text written to the column.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<Owo> for CompactOwo {
+    fn from(owo: Owo) -> Self {
+        Self {
+            owo,
+            text: owo.to_compact_string(),
+        }
+    }
+}
+
+impl From<CompactOwo> for Owo {
+    fn from(compact: CompactOwo) -> Self {
+        compact.owo
+    }
+}
+
+impl<DB> ToSql<Text, DB> for CompactOwo
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.text.as_str().to_sql(out)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for CompactOwo
+where
+    DB: Backend,
+    Owo: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Ok(Owo::from_sql(bytes)?.into())
+    }
+}
+
+impl<DB> Queryable<(BigInt, Text), DB> for Owo
+where
+    DB: Backend,
+    (i64, String): FromStaticSqlRow<(BigInt, Text), DB>,
+{
+    type Row = (i64, String);
+
+    fn build((amount, code): Self::Row) -> deserialize::Result<Self> {
+        Ok(Owo::new(amount, registry::currency(&code)?))
+    }
+}
+
+impl<DB> FromSql<Text, DB> for Owo
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let text = String::from_sql(bytes)?;
+        Ok(Owo::from_compact_str(&text)?)
+    }
+}
+
+impl<DB> Queryable<Text, DB> for Owo
+where
+    DB: Backend,
+    Owo: FromSql<Text, DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
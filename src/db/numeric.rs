@@ -0,0 +1,195 @@
+//! Lossless conversion between `Owo` and Postgres `NUMERIC` values, in both
+//! the textual and the binary wire format.
+//!
+//! Values are written at the currency's precision. When reading, trailing
+//! zeros beyond the precision (as produced by a `NUMERIC(p, s)` column with a
+//! larger scale) are accepted, but any non-zero digit the currency can't
+//! represent is an error rather than being silently rounded.
+
+use crate::error::OwoError;
+use crate::owo::parse_decimal;
+use crate::{Currency, Owo};
+
+const SIGN_POSITIVE: u16 = 0x0000;
+const SIGN_NEGATIVE: u16 = 0x4000;
+const NBASE: i128 = 10_000;
+
+/// Formats the amount as `NUMERIC` text at the currency's precision.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::numeric;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// assert_eq!(numeric::to_text(&Owo::new(-123456, usd)), "-1234.56");
+/// ```
+pub fn to_text(owo: &Owo) -> String {
+    owo.to_decimal_string()
+}
+
+/// Parses `NUMERIC` text into an `Owo` of the given currency.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::numeric;
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// assert_eq!(numeric::from_text("1234.5600", usd.clone()).unwrap().get_amount(), 123456);
+/// assert!(numeric::from_text("1234.567", usd.clone()).is_err());
+/// assert!(numeric::from_text("NaN", usd).is_err());
+/// ```
+pub fn from_text(text: &str, currency: Currency) -> Result<Owo, OwoError> {
+    let trimmed = match text.split_once('.') {
+        Some((whole, fraction)) if fraction.len() > currency.precision as usize => {
+            let (kept, extra) = fraction.split_at(currency.precision as usize);
+            if extra.bytes().any(|b| b != b'0') {
+                return Err(OwoError::ParseError {
+                    input: text.to_string(),
+                    reason: format!("more than {} fraction digits", currency.precision),
+                });
+            }
+            match kept.is_empty() {
+                true => whole.to_string(),
+                false => format!("{}.{}", whole, kept),
+            }
+        }
+        _ => text.to_string(),
+    };
+    let amount = parse_decimal(&trimmed, currency.precision).map_err(|_| OwoError::ParseError {
+        input: text.to_string(),
+        reason: "expected a finite NUMERIC value".to_string(),
+    })?;
+    Ok(Owo::new(amount, currency))
+}
+
+/// Encodes the amount in the Postgres binary `NUMERIC` format, with a
+/// display scale equal to the currency's precision.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::numeric;
+///
+/// let owo = Owo::new(123456, Currency::new("USD", "$", 2));
+/// let bytes = numeric::to_binary(&owo);
+///
+/// // 2 base-10000 digits, weight 0, positive, scale 2: [1234, 5600]
+/// assert_eq!(bytes, [0, 2, 0, 0, 0, 0, 0, 2, 0x04, 0xd2, 0x15, 0xe0]);
+/// ```
+pub fn to_binary(owo: &Owo) -> Vec<u8> {
+    let precision = owo.currency.precision as u32;
+    let abs = owo.amount.unsigned_abs() as i128;
+    let scale = 10i128.pow(precision);
+
+    let mut whole = abs / scale;
+    let mut whole_digits = Vec::new();
+    while whole > 0 {
+        whole_digits.insert(0, (whole % NBASE) as i16);
+        whole /= NBASE;
+    }
+
+    let fraction_groups = precision.div_ceil(4);
+    let mut fraction = (abs % scale) * 10i128.pow(fraction_groups * 4 - precision);
+    let mut fraction_digits = vec![0i16; fraction_groups as usize];
+    for slot in fraction_digits.iter_mut().rev() {
+        *slot = (fraction % NBASE) as i16;
+        fraction /= NBASE;
+    }
+
+    let mut weight = whole_digits.len() as i16 - 1;
+    let mut digits: Vec<i16> = whole_digits.into_iter().chain(fraction_digits).collect();
+    while digits.first() == Some(&0) {
+        digits.remove(0);
+        weight -= 1;
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let sign = if owo.amount < 0 {
+        SIGN_NEGATIVE
+    } else {
+        SIGN_POSITIVE
+    };
+    let mut bytes = Vec::with_capacity(8 + digits.len() * 2);
+    bytes.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    bytes.extend_from_slice(&weight.to_be_bytes());
+    bytes.extend_from_slice(&sign.to_be_bytes());
+    bytes.extend_from_slice(&(precision as u16).to_be_bytes());
+    for digit in digits {
+        bytes.extend_from_slice(&digit.to_be_bytes());
+    }
+    bytes
+}
+
+/// Decodes a Postgres binary `NUMERIC` value into an `Owo` of the given
+/// currency.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::db::numeric;
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// let owo = Owo::new(-5, usd.clone());
+/// assert_eq!(numeric::from_binary(&numeric::to_binary(&owo), usd.clone()).unwrap(), owo);
+///
+/// // 0.005 has more fraction digits than USD allows
+/// let half_cent = [0, 1, 0xff, 0xff, 0, 0, 0, 3, 0, 50];
+/// assert!(numeric::from_binary(&half_cent, usd).is_err());
+/// ```
+pub fn from_binary(bytes: &[u8], currency: Currency) -> Result<Owo, OwoError> {
+    let decode_error = |reason: &str| OwoError::DecodeError(format!("NUMERIC: {}", reason));
+    if bytes.len() < 8 {
+        return Err(decode_error("header too short"));
+    }
+    let read = |at: usize| u16::from_be_bytes([bytes[at], bytes[at + 1]]);
+    let ndigits = read(0) as usize;
+    let weight = read(2) as i16 as i32;
+    let sign = read(4);
+    if bytes.len() != 8 + ndigits * 2 {
+        return Err(decode_error("digit count doesn't match length"));
+    }
+    if sign != SIGN_POSITIVE && sign != SIGN_NEGATIVE {
+        return Err(decode_error("NaN and infinity are not money"));
+    }
+
+    let precision = currency.precision as i32;
+    let mut magnitude: i128 = 0;
+    for i in 0..ndigits {
+        let digit = read(8 + i * 2) as i128;
+        if digit >= NBASE {
+            return Err(decode_error("digit out of range"));
+        }
+        let exponent = 4 * (weight - i as i32) + precision;
+        let value = if exponent >= 0 {
+            10i128
+                .checked_pow(exponent as u32)
+                .and_then(|factor| digit.checked_mul(factor))
+        } else {
+            let divisor = 10i128.pow((-exponent).min(4) as u32);
+            if digit % divisor != 0 || (-exponent > 4 && digit != 0) {
+                return Err(OwoError::ParseError {
+                    input: format!("{:?}", bytes),
+                    reason: format!("more than {} fraction digits", currency.precision),
+                });
+            }
+            Some(digit / divisor)
+        };
+        magnitude = value
+            .and_then(|value| magnitude.checked_add(value))
+            .ok_or_else(|| decode_error("amount out of range"))?;
+    }
+    let amount = if sign == SIGN_NEGATIVE {
+        -magnitude
+    } else {
+        magnitude
+    };
+    let amount = i64::try_from(amount).map_err(|_| decode_error("amount out of range"))?;
+    Ok(Owo::new(amount, currency))
+}
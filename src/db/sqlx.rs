@@ -0,0 +1,93 @@
+//! `sqlx` integration for `Owo`, for every database `sqlx` supports.
+//!
+//! Two column layouts are supported:
+//!
+//! - **Amount + currency columns** (recommended): a `BIGINT` column named
+//!   `amount` holding minor units and a text column named `currency` holding
+//!   the ISO 4217 code. `Owo` implements `FromRow`, so it can be read
+//!   directly or embedded in your own row type with `#[sqlx(flatten)]`.
+//!   Bind `owo.amount` and `owo.currency.code` when writing.
+//! - **Single text column**: `Owo` implements `Type`, `Encode` and `Decode`
+//!   using the compact `"USD 5.99"` format, so it can be bound and fetched
+//!   like a string.
+//!
+//! Currencies are resolved from the ISO 4217 registry when reading.
+//!
+//! ```ignore
+//! #[derive(sqlx::FromRow)]
+//! struct Order {
+//!     id: i64,
+//!     #[sqlx(flatten)]
+//!     total: Owo,
+//! }
+//!
+//! sqlx::query("INSERT INTO orders (id, amount, currency) VALUES ($1, $2, $3)")
+//!     .bind(1_i64)
+//!     .bind(total.amount)
+//!     .bind(&total.currency.code)
+//!     .execute(&pool)
+//!     .await?;
+//!
+//! let order: Order = sqlx::query_as("SELECT id, amount, currency FROM orders")
+//!     .fetch_one(&pool)
+//!     .await?;
+//! ```
+
+use crate::{Owo, registry};
+use ::sqlx::database::Database;
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::{ColumnIndex, Decode, Encode, Error, FromRow, Row, Type};
+
+impl<'r, R> FromRow<'r, R> for Owo
+where
+    R: Row,
+    &'static str: ColumnIndex<R>,
+    i64: Decode<'r, R::Database> + Type<R::Database>,
+    String: Decode<'r, R::Database> + Type<R::Database>,
+{
+    fn from_row(row: &'r R) -> Result<Self, Error> {
+        let amount: i64 = row.try_get("amount")?;
+        let code: String = row.try_get("currency")?;
+        let currency = registry::currency(&code).map_err(|e| Error::ColumnDecode {
+            index: "currency".to_string(),
+            source: Box::new(e),
+        })?;
+        Ok(Owo::new(amount, currency))
+    }
+}
+
+impl<DB: Database> Type<DB> for Owo
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for Owo
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.to_compact_string().encode(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for Owo
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = <&'r str as Decode<'r, DB>>::decode(value)?;
+        Ok(Owo::from_compact_str(text)?)
+    }
+}
@@ -0,0 +1,228 @@
+//! Breaking amounts into physical notes and coins.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo};
+use serde::{Deserialize, Serialize};
+
+/// A number of notes or coins of one denomination.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Piece {
+    pub denomination: Owo,
+    pub count: u32,
+}
+
+impl Piece {
+    /// Returns the value of all the pieces together.
+    pub fn total(&self) -> Result<Owo, OwoError> {
+        self.denomination
+            .amount
+            .checked_mul(i64::from(self.count))
+            .map(|amount| Owo::new(amount, self.denomination.currency))
+            .ok_or_else(|| self.denomination.overflow("multiply", self.count))
+    }
+}
+
+/// Returns the notes and coins in circulation for a few common currencies,
+/// largest first, or `None` if the currency isn't covered
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::denominations::standard;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let notes = standard(&ngn).unwrap();
+///
+/// assert_eq!(notes[0], Owo::new(100000,ngn.clone()));
+/// assert!(standard(&Currency::new("XYZ", "X", 2)).is_none());
+/// ```
+pub fn standard(currency: &Currency) -> Option<Vec<Owo>> {
+    let minor: &[i64] = match currency.code {
+        "EUR" => &[
+            50000, 20000, 10000, 5000, 2000, 1000, 500, 200, 100, 50, 20, 10, 5, 2, 1,
+        ],
+        "GBP" => &[5000, 2000, 1000, 500, 200, 100, 50, 20, 10, 5, 2, 1],
+        "JPY" => &[10000, 5000, 2000, 1000, 500, 100, 50, 10, 5, 1],
+        "NGN" => &[
+            100000, 50000, 20000, 10000, 5000, 2000, 1000, 500, 200, 100, 50,
+        ],
+        "USD" => &[10000, 5000, 2000, 1000, 500, 200, 100, 25, 10, 5, 1],
+        _ => return None,
+    };
+    Some(
+        minor
+            .iter()
+            .map(|&amount| Owo::new(amount, *currency))
+            .collect(),
+    )
+}
+
+/// Breaks `amount` into notes and coins from `denominations`, using as many
+/// of the largest pieces as still allows an exact breakdown
+///
+/// Pieces with a count of zero are left out. Returns an error if the amount
+/// is negative, a denomination isn't positive or is in another currency, or
+/// no combination adds up to the amount exactly; round the amount to the
+/// smallest piece first if it may hold fractions of it.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::denominations::{Piece, breakdown};
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let notes = [Owo::new(100000,ngn.clone()), Owo::new(50000,ngn.clone()), Owo::new(20000,ngn.clone()), Owo::new(10000,ngn.clone())];
+///
+/// let pieces = breakdown(&Owo::new(580000,ngn.clone()), &notes).unwrap();
+/// assert_eq!(pieces, vec![
+///     Piece { denomination: Owo::new(100000,ngn.clone()), count: 5 },
+///     Piece { denomination: Owo::new(50000,ngn.clone()), count: 1 },
+///     Piece { denomination: Owo::new(20000,ngn.clone()), count: 1 },
+///     Piece { denomination: Owo::new(10000,ngn.clone()), count: 1 },
+/// ]);
+///
+/// assert!(breakdown(&Owo::new(5050,ngn.clone()), &notes).is_err());
+/// ```
+pub fn breakdown(amount: &Owo, denominations: &[Owo]) -> Result<Vec<Piece>, OwoError> {
+    let stock: Vec<(Owo, u32)> = denominations
+        .iter()
+        .map(|denomination| (*denomination, u32::MAX))
+        .collect();
+    breakdown_with_inventory(amount, &stock)
+}
+
+/// Breaks `amount` into notes and coins drawn from a limited `inventory` of
+/// `(denomination, available)` pairs, using as many of the largest pieces as
+/// still allows an exact breakdown
+///
+/// Unlike a greedy count this finds change whenever it exists, e.g. ₦600
+/// from one ₦500 and three ₦200 notes. Denominations that are out of stock
+/// are skipped, and a till too awkward to settle within [`SEARCH_BUDGET`]
+/// steps returns `InsufficientChange` rather than stalling the caller.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::denominations::{Piece, breakdown_with_inventory};
+/// use cowry::error::OwoError;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let till = [(Owo::new(50000,ngn.clone()), 1), (Owo::new(20000,ngn.clone()), 3)];
+///
+/// let pieces = breakdown_with_inventory(&Owo::new(60000,ngn.clone()), &till).unwrap();
+/// assert_eq!(pieces, vec![Piece { denomination: Owo::new(20000,ngn.clone()), count: 3 }]);
+///
+/// assert!(breakdown_with_inventory(&Owo::new(120000,ngn.clone()), &till).is_err());
+///
+/// let no_kobo = [(Owo::new(100000,ngn.clone()), 40), (Owo::new(50,ngn.clone()), 0)];
+/// assert!(breakdown_with_inventory(&Owo::new(5000050,ngn.clone()), &no_kobo).is_err());
+///
+/// assert!(matches!(
+///     breakdown_with_inventory(&Owo::new(-100,ngn.clone()), &till),
+///     Err(OwoError::NegativeAmount(_))
+/// ));
+/// assert!(matches!(
+///     breakdown_with_inventory(&Owo::new(100,ngn.clone()), &[(Owo::new(0,ngn.clone()), 1)]),
+///     Err(OwoError::InvalidDenomination(_))
+/// ));
+/// ```
+pub fn breakdown_with_inventory(
+    amount: &Owo,
+    inventory: &[(Owo, u32)],
+) -> Result<Vec<Piece>, OwoError> {
+    if amount.amount < 0 {
+        return Err(OwoError::NegativeAmount(amount.to_compact_string()));
+    }
+    for (denomination, _) in inventory {
+        amount.check_currency(denomination)?;
+        if denomination.amount <= 0 {
+            return Err(OwoError::InvalidDenomination(
+                denomination.to_compact_string(),
+            ));
+        }
+    }
+
+    // Largest denominations in stock first, so the search prefers big pieces
+    let mut stock: Vec<(i64, u32)> = inventory
+        .iter()
+        .filter(|&&(_, available)| available > 0)
+        .map(|(denomination, available)| (denomination.amount, *available))
+        .collect();
+    stock.sort_by_key(|&(denomination, _)| std::cmp::Reverse(denomination));
+
+    // What every suffix of denominations can reach is a multiple of its gcd
+    // and at most its total value
+    let mut limits = vec![(0, 0); stock.len() + 1];
+    for index in (0..stock.len()).rev() {
+        let (denomination, available) = stock[index];
+        let (gcd_after, total_after) = limits[index + 1];
+        limits[index] = (
+            gcd(gcd_after, denomination),
+            total_after + i128::from(denomination) * i128::from(available),
+        );
+    }
+
+    let mut counts = vec![0; stock.len()];
+    let mut budget = SEARCH_BUDGET;
+    if !search(amount.amount, &stock, &limits, 0, &mut counts, &mut budget) {
+        return Err(OwoError::InsufficientChange(amount.to_compact_string()));
+    }
+    Ok(stock
+        .iter()
+        .zip(counts)
+        .filter(|&(_, count)| count > 0)
+        .map(|(&(denomination, _), count)| Piece {
+            denomination: Owo::new(denomination, amount.currency),
+            count,
+        })
+        .collect())
+}
+
+/// The most steps [`breakdown_with_inventory`] searches before giving up.
+pub const SEARCH_BUDGET: u32 = 1_000_000;
+
+// Helper to fill `counts[index..]` so they add up to `remaining`, trying the
+// most pieces of each denomination first, pruned by the `(gcd, total)` that
+// each suffix of `stock` can reach and stopping once `budget` runs out
+fn search(
+    remaining: i64,
+    stock: &[(i64, u32)],
+    limits: &[(i64, i128)],
+    index: usize,
+    counts: &mut [u32],
+    budget: &mut u32,
+) -> bool {
+    if remaining == 0 {
+        return true;
+    }
+    let (suffix_gcd, suffix_total) = limits[index];
+    if index == stock.len()
+        || remaining % suffix_gcd != 0
+        || i128::from(remaining) > suffix_total
+        || *budget == 0
+    {
+        return false;
+    }
+    *budget -= 1;
+    let (denomination, available) = stock[index];
+    let most = (remaining / denomination).min(i64::from(available));
+    for count in (0..=most).rev() {
+        if search(
+            remaining - count * denomination,
+            stock,
+            limits,
+            index + 1,
+            counts,
+            budget,
+        ) {
+            counts[index] = count as u32;
+            return true;
+        }
+    }
+    false
+}
+
+// Helper for the greatest common divisor of two non-negative numbers
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
@@ -0,0 +1,151 @@
+//! Checkout discounts: percentage-off, fixed-amount-off and capped
+//! percentage discounts, alone or stacked.
+//!
+//! Percentages are in percent, as with `Owo::percentage`, so `10.0` is 10%
+//! off. A discount never takes a price below zero.
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// A reduction applied to a price.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Discount {
+    /// A percentage of the price off.
+    Percentage(f64),
+    /// A fixed amount off.
+    Fixed(Owo),
+    /// A percentage of the price off, but never more than `cap`.
+    Capped { percent: f64, cap: Owo },
+}
+
+/// How several discounts combine.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscountStacking {
+    /// Each discount applies to the price left by the ones before it.
+    #[default]
+    Sequential,
+    /// Every discount applies to the original price.
+    OnOriginal,
+}
+
+/// A price before and after discounting.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DiscountResult {
+    /// The price before any discount.
+    pub original: Owo,
+    /// The total taken off.
+    pub discount: Owo,
+    /// The price to pay, always `original - discount`.
+    pub final_amount: Owo,
+}
+
+impl Discount {
+    /// Returns the amount this discount takes off `price`, rounded with
+    /// `mode` and limited to the price itself
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::discount::Discount;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let price = Owo::new(50000,ngn.clone());
+    ///
+    /// let off = Discount::Percentage(10.0).amount_off(&price, RoundingMode::Nearest).unwrap();
+    /// assert_eq!(off, Owo::new(5000,ngn.clone()));
+    ///
+    /// let capped = Discount::Capped { percent: 20.0, cap: Owo::new(7500,ngn.clone()) };
+    /// assert_eq!(capped.amount_off(&price, RoundingMode::Nearest).unwrap(), Owo::new(7500,ngn.clone()));
+    ///
+    /// let coupon = Discount::Fixed(Owo::new(80000,ngn.clone()));
+    /// assert_eq!(coupon.amount_off(&price, RoundingMode::Nearest).unwrap(), price);
+    /// ```
+    pub fn amount_off(&self, price: &Owo, mode: RoundingMode) -> Result<Owo, OwoError> {
+        let off = match self {
+            Discount::Percentage(percent) => price.percentage_with_mode(*percent, mode)?,
+            Discount::Fixed(amount) => {
+                price.check_currency(amount)?;
+                *amount
+            }
+            Discount::Capped { percent, cap } => {
+                let off = price.percentage_with_mode(*percent, mode)?;
+                price.check_currency(cap)?;
+                if off.amount > cap.amount { *cap } else { off }
+            }
+        };
+        Ok(clamp_to(off, price))
+    }
+
+    /// Applies this discount to `price`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::discount::Discount;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let result = Discount::Percentage(15.0).apply(&Owo::new(20000,ngn.clone()), RoundingMode::Nearest).unwrap();
+    ///
+    /// assert_eq!(result.discount, Owo::new(3000,ngn.clone()));
+    /// assert_eq!(result.final_amount, Owo::new(17000,ngn.clone()));
+    /// ```
+    pub fn apply(&self, price: &Owo, mode: RoundingMode) -> Result<DiscountResult, OwoError> {
+        apply_all(
+            price,
+            std::slice::from_ref(self),
+            DiscountStacking::Sequential,
+            mode,
+        )
+    }
+}
+
+// Helper limiting a discount to between zero and `price`
+fn clamp_to(off: Owo, price: &Owo) -> Owo {
+    let amount = off.amount.clamp(0, price.amount.max(0));
+    Owo::new(amount, off.currency)
+}
+
+/// Applies `discounts` to `price` in order, combining them by `stacking`
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::discount::{apply_all, Discount, DiscountStacking};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let price = Owo::new(10000,usd.clone());
+/// let discounts = [Discount::Percentage(10.0), Discount::Fixed(Owo::new(1000,usd.clone()))];
+///
+/// // 10% off $100.00, then $10.00 off $90.00
+/// let sequential = apply_all(&price, &discounts, DiscountStacking::Sequential, RoundingMode::Nearest).unwrap();
+/// assert_eq!(sequential.final_amount, Owo::new(8000,usd.clone()));
+///
+/// // 10% and 20% off $100.00
+/// let discounts = [Discount::Percentage(10.0), Discount::Percentage(20.0)];
+/// let sequential = apply_all(&price, &discounts, DiscountStacking::Sequential, RoundingMode::Nearest).unwrap();
+/// let on_original = apply_all(&price, &discounts, DiscountStacking::OnOriginal, RoundingMode::Nearest).unwrap();
+/// assert_eq!(sequential.final_amount, Owo::new(7200,usd.clone()));
+/// assert_eq!(on_original.final_amount, Owo::new(7000,usd.clone()));
+/// ```
+pub fn apply_all(
+    price: &Owo,
+    discounts: &[Discount],
+    stacking: DiscountStacking,
+    mode: RoundingMode,
+) -> Result<DiscountResult, OwoError> {
+    let mut remaining = *price;
+    for discount in discounts {
+        let base = match stacking {
+            DiscountStacking::Sequential => &remaining,
+            DiscountStacking::OnOriginal => price,
+        };
+        let off = clamp_to(discount.amount_off(base, mode)?, &remaining);
+        remaining = remaining.checked_sub(&off)?;
+    }
+    Ok(DiscountResult {
+        original: *price,
+        discount: price.checked_sub(&remaining)?,
+        final_amount: remaining,
+    })
+}
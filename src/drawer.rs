@@ -0,0 +1,113 @@
+//! Cash drawer reconciliation for point-of-sale tills.
+
+use crate::Owo;
+use crate::denominations::Piece;
+use crate::error::OwoError;
+use serde::{Deserialize, Serialize};
+
+/// The result of counting a drawer at close.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DrawerCount {
+    /// Opening float plus cash sales less payouts.
+    pub expected: Owo,
+    pub counted: Owo,
+    /// Positive when the drawer holds more than expected, negative when short.
+    pub over_short: Owo,
+}
+
+/// A till's opening float and the cash that moved through it during a shift.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CashDrawer {
+    opening: Owo,
+    sales: Owo,
+    payouts: Owo,
+}
+
+impl CashDrawer {
+    /// Opens a drawer with `float` in it.
+    pub fn new(float: Owo) -> Self {
+        let zero = Owo::new(0, float.currency);
+        Self {
+            opening: float,
+            sales: zero,
+            payouts: zero,
+        }
+    }
+
+    /// Records cash taken in for a sale.
+    pub fn record_sale(&mut self, amount: &Owo) -> Result<(), OwoError> {
+        self.sales = self.sales.checked_add(amount)?;
+        Ok(())
+    }
+
+    /// Records cash paid out of the drawer, e.g. a refund or petty cash.
+    pub fn record_payout(&mut self, amount: &Owo) -> Result<(), OwoError> {
+        self.payouts = self.payouts.checked_add(amount)?;
+        Ok(())
+    }
+
+    pub fn opening(&self) -> &Owo {
+        &self.opening
+    }
+
+    pub fn sales(&self) -> &Owo {
+        &self.sales
+    }
+
+    pub fn payouts(&self) -> &Owo {
+        &self.payouts
+    }
+
+    /// Returns the cash the drawer should hold: opening float plus sales less
+    /// payouts.
+    pub fn expected(&self) -> Result<Owo, OwoError> {
+        self.opening
+            .checked_add(&self.sales)?
+            .checked_sub(&self.payouts)
+    }
+
+    /// Compares the notes and coins counted at close with what the drawer
+    /// should hold
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::denominations::Piece;
+    /// use cowry::drawer::CashDrawer;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut drawer = CashDrawer::new(Owo::new(1000000,ngn.clone()));
+    /// drawer.record_sale(&Owo::new(450000,ngn.clone())).unwrap();
+    /// drawer.record_payout(&Owo::new(50000,ngn.clone())).unwrap();
+    ///
+    /// let counted = [
+    ///     Piece { denomination: Owo::new(100000,ngn.clone()), count: 13 },
+    ///     Piece { denomination: Owo::new(50000,ngn.clone()), count: 2 },
+    ///     Piece { denomination: Owo::new(20000,ngn.clone()), count: 4 },
+    /// ];
+    /// let count = drawer.close(&counted).unwrap();
+    ///
+    /// assert_eq!(count.expected, Owo::new(1400000,ngn.clone()));
+    /// assert_eq!(count.counted, Owo::new(1480000,ngn.clone()));
+    /// assert_eq!(count.over_short, Owo::new(80000,ngn.clone()));
+    /// ```
+    pub fn close(&self, counted: &[Piece]) -> Result<DrawerCount, OwoError> {
+        let expected = self.expected()?;
+        let mut total = Owo::new(0, self.opening.currency);
+        for (index, piece) in counted.iter().enumerate() {
+            total = piece
+                .total()
+                .and_then(|value| total.checked_add(&value))
+                .map_err(|source| OwoError::AtIndex {
+                    index,
+                    source: Box::new(source),
+                })?;
+        }
+        let over_short = total.checked_sub(&expected)?;
+        Ok(DrawerCount {
+            expected,
+            counted: total,
+            over_short,
+        })
+    }
+}
@@ -3,9 +3,113 @@ pub enum OwoError {
     #[error("Currency mismatch: {0} vs {1}")]
     CurrencyMismatch(String, String),
 
+    #[error("Invalid currency code: {0}")]
+    InvalidCurrencyCode(String),
+
+    #[error("Missing required field: {0}")]
+    MissingField(&'static str),
+
     #[error("Division by zero is not allowed")]
     DivisionByZero,
 
+    #[error("Scalar must be a finite number, got {0}")]
+    InvalidScalar(f64),
+
+    #[error("Arithmetic overflow in {op}: {lhs} and {rhs}")]
+    Overflow {
+        op: &'static str,
+        lhs: String,
+        rhs: String,
+    },
+
+    #[error("Could not parse {input:?}: {reason}")]
+    ParseError { input: String, reason: String },
+
+    #[error("Invalid binary encoding: {0}")]
+    DecodeError(String),
+
+    #[error("Unsupported precision: {0}")]
+    InvalidPrecision(u8),
+
+    #[error("Unknown currency: {0}")]
+    UnknownCurrency(String),
+
+    #[error("Currency {code} doesn't match its registered definition: {reason}")]
+    CurrencyDefinitionMismatch { code: String, reason: String },
+
+    #[error("Cannot operate on an empty collection")]
+    EmptyCollection,
+
+    #[error("Percentile must be between 0 and 100, got {0}")]
+    InvalidPercentile(f64),
+
+    #[error("No exchange rate from {0} to {1}")]
+    MissingRate(String, String),
+
+    #[error("Slippage of {deviation_bps:.2} bps exceeds the {max_bps} bps limit")]
+    SlippageExceeded { deviation_bps: f64, max_bps: i64 },
+
+    #[error("Period {period} is outside 1..={periods}")]
+    InvalidPeriod { period: u32, periods: u32 },
+
+    #[error("Invalid interval: {0}")]
+    InvalidInterval(String),
+
+    #[error("Invalid range: {0}")]
+    InvalidRange(String),
+
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+
+    #[error("Cannot convert between {0} and {1}")]
+    IncompatibleUnits(String, String),
+
+    #[error("Invalid tier: {0}")]
+    InvalidTier(String),
+
+    #[error("Unknown account: {0}")]
+    UnknownAccount(String),
+
+    #[error("Unknown meter: {0}")]
+    UnknownMeter(String),
+
+    #[error("Account already exists: {0}")]
+    DuplicateAccount(String),
+
+    #[error("Entry does not balance: {0} left over")]
+    UnbalancedEntry(String),
+
+    #[error("Amount must not be negative: {0}")]
+    NegativeAmount(String),
+
+    #[error("Insufficient funds: {available} available, {requested} requested")]
+    InsufficientFunds { available: String, requested: String },
+
+    #[error("Cannot make exact change for {0}")]
+    InsufficientChange(String),
+
+    #[error("Denomination must be positive: {0}")]
+    InvalidDenomination(String),
+
+    #[error("Invalid budget: {0}")]
+    InvalidBudget(String),
+
+    #[error("Unknown envelope: {0}")]
+    UnknownEnvelope(String),
+
+    #[error("Invalid split: {0}")]
+    InvalidSplit(String),
+
+    #[error("Calculation did not converge: {0}")]
+    NoConvergence(String),
+
+    #[error("Item {index}: {source}")]
+    AtIndex { index: usize, source: Box<OwoError> },
+
     #[error("Invalid JSON: {0}")]
     SerdeError(#[from] serde_json::Error),
-}
\ No newline at end of file
+
+    #[cfg(feature = "csv")]
+    #[error("Invalid CSV: {0}")]
+    CsvError(#[from] ::csv::Error),
+}
@@ -0,0 +1,173 @@
+//! Estimated amounts with low and high bounds, for quotes and budgets where
+//! a figure is a range rather than exact.
+//!
+//! Arithmetic follows interval rules: adding two estimates adds their
+//! bounds, subtracting pairs each low bound with the other's high, and
+//! multiplying by a negative factor swaps the bounds, so the result always
+//! covers every combination of the inputs.
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// An expected amount with the lowest and highest it could turn out to be.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct OwoEstimate {
+    pub low: Owo,
+    pub expected: Owo,
+    pub high: Owo,
+}
+
+impl OwoEstimate {
+    /// Creates an estimate, requiring one currency and
+    /// `low <= expected <= high`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::estimate::OwoEstimate;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let roof = OwoEstimate::new(
+    ///     Owo::new(400000,ngn.clone()),
+    ///     Owo::new(450000,ngn.clone()),
+    ///     Owo::new(600000,ngn.clone()),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(roof.spread().unwrap(), Owo::new(200000,ngn.clone()));
+    ///
+    /// let backwards = OwoEstimate::new(
+    ///     Owo::new(600000,ngn.clone()),
+    ///     Owo::new(450000,ngn.clone()),
+    ///     Owo::new(400000,ngn.clone()),
+    /// );
+    /// assert!(backwards.is_err());
+    /// ```
+    pub fn new(low: Owo, expected: Owo, high: Owo) -> Result<Self, OwoError> {
+        low.check_currency(&expected)?;
+        low.check_currency(&high)?;
+        if low.amount > expected.amount || expected.amount > high.amount {
+            return Err(OwoError::InvalidRange(format!(
+                "{} to {} does not contain {}",
+                low.to_compact_string(),
+                high.to_compact_string(),
+                expected.to_compact_string()
+            )));
+        }
+        Ok(Self {
+            low,
+            expected,
+            high,
+        })
+    }
+
+    /// Creates an estimate known exactly, with all three amounts equal.
+    pub fn exact(amount: Owo) -> Self {
+        Self {
+            low: amount,
+            expected: amount,
+            high: amount,
+        }
+    }
+
+    /// Returns `high - low`.
+    pub fn spread(&self) -> Result<Owo, OwoError> {
+        self.high.checked_sub(&self.low)
+    }
+
+    /// Checks whether `amount` lies within the bounds.
+    pub fn contains(&self, amount: &Owo) -> bool {
+        amount.currency == self.low.currency
+            && self.low.amount <= amount.amount
+            && amount.amount <= self.high.amount
+    }
+
+    /// Adds two estimates bound by bound
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::estimate::OwoEstimate;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let labour = OwoEstimate::new(Owo::new(80000,usd.clone()), Owo::new(100000,usd.clone()), Owo::new(150000,usd.clone())).unwrap();
+    /// let parts = OwoEstimate::exact(Owo::new(25000,usd.clone()));
+    ///
+    /// let total = labour.checked_add(&parts).unwrap();
+    /// assert_eq!(total.low, Owo::new(105000,usd.clone()));
+    /// assert_eq!(total.expected, Owo::new(125000,usd.clone()));
+    /// assert_eq!(total.high, Owo::new(175000,usd.clone()));
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, OwoError> {
+        Ok(Self {
+            low: self.low.checked_add(&rhs.low)?,
+            expected: self.expected.checked_add(&rhs.expected)?,
+            high: self.high.checked_add(&rhs.high)?,
+        })
+    }
+
+    /// Subtracts an estimate, taking `rhs.high` from `low` and `rhs.low`
+    /// from `high` so the result covers every outcome
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::estimate::OwoEstimate;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let revenue = OwoEstimate::new(Owo::new(9000,usd.clone()), Owo::new(10000,usd.clone()), Owo::new(12000,usd.clone())).unwrap();
+    /// let costs = OwoEstimate::new(Owo::new(6000,usd.clone()), Owo::new(7000,usd.clone()), Owo::new(8000,usd.clone())).unwrap();
+    ///
+    /// let profit = revenue.checked_sub(&costs).unwrap();
+    /// assert_eq!(profit.low, Owo::new(1000,usd.clone()));
+    /// assert_eq!(profit.expected, Owo::new(3000,usd.clone()));
+    /// assert_eq!(profit.high, Owo::new(6000,usd.clone()));
+    /// ```
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, OwoError> {
+        Ok(Self {
+            low: self.low.checked_sub(&rhs.high)?,
+            expected: self.expected.checked_sub(&rhs.expected)?,
+            high: self.high.checked_sub(&rhs.low)?,
+        })
+    }
+
+    /// Multiplies every bound by `scalar`, rounding with `mode` and
+    /// swapping the bounds when `scalar` is negative
+    ///
+    /// Rounding applies to each bound, so with `Nearest` a bound can move by
+    /// half a minor unit; use `Floor` or `Ceil` to widen deliberately.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::estimate::OwoEstimate;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let unit = OwoEstimate::new(Owo::new(100,usd.clone()), Owo::new(120,usd.clone()), Owo::new(150,usd.clone())).unwrap();
+    ///
+    /// let refund = unit.multiply_with_mode(-2.0, RoundingMode::Nearest).unwrap();
+    /// assert_eq!(refund.low, Owo::new(-300,usd.clone()));
+    /// assert_eq!(refund.high, Owo::new(-200,usd.clone()));
+    /// ```
+    pub fn multiply_with_mode(&self, scalar: f64, mode: RoundingMode) -> Result<Self, OwoError> {
+        let low = self.low.multiply_with_mode(scalar, mode)?;
+        let expected = self.expected.multiply_with_mode(scalar, mode)?;
+        let high = self.high.multiply_with_mode(scalar, mode)?;
+        let (low, high) = if scalar < 0.0 {
+            (high, low)
+        } else {
+            (low, high)
+        };
+        Ok(Self {
+            low,
+            expected,
+            high,
+        })
+    }
+}
+
+impl From<Owo> for OwoEstimate {
+    fn from(amount: Owo) -> Self {
+        Self::exact(amount)
+    }
+}
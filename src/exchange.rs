@@ -0,0 +1,434 @@
+use crate::basis_points::BasisPoints;
+use crate::error::OwoError;
+use crate::finance::day_count::{CalendarDate, DayCount};
+use crate::{Currency, Owo, RoundingMode, registry};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+/// A table of exchange rates between currency codes.
+///
+/// A rate of `r` from `A` to `B` means one major unit of `A` buys `r`
+/// major units of `B`.
+#[derive(Debug, Clone, Default)]
+pub struct RateTable {
+    // Keyed by `from` then `to`, so lookups borrow the codes
+    rates: HashMap<String, HashMap<String, f64>>,
+}
+
+impl RateTable {
+    /// Creates an empty rate table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rate used to convert from `from` to `to`, rejecting rates
+    /// that aren't finite and positive.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let mut rates = RateTable::new();
+    /// rates.set_rate("USD", "NGN", 1500.0).unwrap();
+    ///
+    /// assert_eq!(rates.get_rate("USD", "NGN"), Some(1500.0));
+    /// assert_eq!(rates.get_rate("NGN", "USD"), None);
+    ///
+    /// assert!(rates.set_rate("USD", "EUR", f64::NAN).is_err());
+    /// assert!(rates.set_rate("USD", "JPY", -2.0).is_err());
+    /// ```
+    pub fn set_rate(&mut self, from: &str, to: &str, rate: f64) -> Result<(), OwoError> {
+        let rate = check_rate(rate)?;
+        self.rates
+            .entry(from.to_string())
+            .or_default()
+            .insert(to.to_string(), rate);
+        Ok(())
+    }
+
+    /// Sets the rate for an [`ExchangeRate`]'s pair, rejecting rates that
+    /// aren't finite and positive.
+    pub fn set_pair_rate(&mut self, rate: &ExchangeRate) -> Result<(), OwoError> {
+        self.set_rate(rate.pair.base.code, rate.pair.quote.code, rate.rate)
+    }
+
+    /// Returns the rate from `from` to `to`, if known.
+    ///
+    /// Converting a currency to itself always has a rate of `1.0`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let rates = RateTable::new();
+    ///
+    /// assert_eq!(rates.get_rate("EUR", "EUR"), Some(1.0));
+    /// assert_eq!(rates.get_rate("EUR", "USD"), None);
+    /// ```
+    pub fn get_rate(&self, from: &str, to: &str) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+        self.rates.get(from)?.get(to).copied()
+    }
+}
+
+/// A currency pair such as EUR/USD, quoted as units of `quote` per one
+/// unit of `base`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CurrencyPair {
+    pub base: Currency,
+    pub quote: Currency,
+}
+
+impl CurrencyPair {
+    pub fn new(base: Currency, quote: Currency) -> Self {
+        Self { base, quote }
+    }
+
+    /// Returns the pair with base and quote swapped, e.g. USD/EUR for
+    /// EUR/USD.
+    pub fn invert(&self) -> Self {
+        Self {
+            base: self.quote,
+            quote: self.base,
+        }
+    }
+}
+
+impl fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base.code, self.quote.code)
+    }
+}
+
+impl FromStr for CurrencyPair {
+    type Err = OwoError;
+
+    /// Parses `"EUR/USD"`, resolving both codes from the ISO 4217 registry.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (base, quote) = input.split_once('/').ok_or_else(|| OwoError::ParseError {
+            input: input.to_string(),
+            reason: "expected BASE/QUOTE".to_string(),
+        })?;
+        Ok(Self::new(
+            registry::currency(base)?,
+            registry::currency(quote)?,
+        ))
+    }
+}
+
+/// A rate for a [`CurrencyPair`]: one unit of `base` buys `rate` units of
+/// `quote`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ExchangeRate {
+    pub pair: CurrencyPair,
+    pub rate: f64,
+}
+
+impl ExchangeRate {
+    /// Creates a rate, rejecting rates that aren't finite and positive.
+    pub fn new(pair: CurrencyPair, rate: f64) -> Result<Self, OwoError> {
+        Ok(Self {
+            pair,
+            rate: check_rate(rate)?,
+        })
+    }
+
+    /// Returns the rate for the inverted pair, `1 / rate` rounded to
+    /// `decimals` decimal places with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::RoundingMode;
+    /// use cowry::exchange::{CurrencyPair, ExchangeRate};
+    ///
+    /// let pair: CurrencyPair = "EUR/USD".parse().unwrap();
+    /// let eur_usd = ExchangeRate::new(pair, 1.0850).unwrap();
+    ///
+    /// let usd_eur = eur_usd.invert(4, RoundingMode::Nearest).unwrap();
+    /// assert_eq!(usd_eur.pair.to_string(), "USD/EUR");
+    /// assert_eq!(usd_eur.rate, 0.9217);
+    /// assert_eq!(usd_eur.to_string(), "USD/EUR 0.9217");
+    ///
+    /// // Too few decimals to represent the reciprocal at all
+    /// let usd_ngn = ExchangeRate::new("USD/NGN".parse().unwrap(), 1500.0).unwrap();
+    /// assert!(usd_ngn.invert(2, RoundingMode::Nearest).is_err());
+    /// assert_eq!(usd_ngn.invert(6, RoundingMode::Nearest).unwrap().rate, 0.000667);
+    /// ```
+    pub fn invert(&self, decimals: u8, mode: RoundingMode) -> Result<Self, OwoError> {
+        let scale = 10f64.powi(i32::from(decimals));
+        let scaled = scale / self.rate;
+        let rounded = match mode {
+            RoundingMode::Nearest => scaled.round(),
+            RoundingMode::Floor => scaled.floor(),
+            RoundingMode::Ceil => scaled.ceil(),
+        };
+        Self::new(self.pair.invert(), rounded / scale)
+    }
+
+    /// Converts an amount in the base currency into the quote currency,
+    /// rounding with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::exchange::ExchangeRate;
+    ///
+    /// let eur_usd = ExchangeRate::new("EUR/USD".parse().unwrap(), 1.0850).unwrap();
+    /// let usd = eur_usd.convert(&Owo::new(10000,Currency::new("EUR", "€", 2)), RoundingMode::Nearest).unwrap();
+    /// assert_eq!(usd, Owo::new(10850,Currency::new("USD", "$", 2)));
+    ///
+    /// assert!(eur_usd.convert(&usd, RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn convert(&self, amount: &Owo, mode: RoundingMode) -> Result<Owo, OwoError> {
+        if amount.currency != self.pair.base {
+            return Err(OwoError::CurrencyMismatch(
+                self.pair.base.code.to_string(),
+                amount.currency.code.to_string(),
+            ));
+        }
+        amount.convert_at_rate(check_rate(self.rate)?, &self.pair.quote, mode)
+    }
+
+    /// Converts like [`ExchangeRate::convert`], but first checks this rate
+    /// is within `max_slippage` of `reference`, such as the rate a customer
+    /// was quoted earlier
+    ///
+    /// The error reports the actual deviation in basis points, positive when
+    /// this rate is higher than the reference. A negative `max_slippage` is
+    /// rejected with `InvalidScalar`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::basis_points::BasisPoints;
+    /// use cowry::exchange::{CurrencyPair, ExchangeRate};
+    ///
+    /// let pair: CurrencyPair = "USD/NGN".parse().unwrap();
+    /// let quoted = ExchangeRate::new(pair, 1500.0).unwrap();
+    /// let amount = Owo::new(10000,pair.base);
+    ///
+    /// let live = ExchangeRate::new(pair, 1503.0).unwrap(); // 20 bps higher
+    /// let paid = live.convert_with_slippage(&amount, &quoted, BasisPoints(25), RoundingMode::Nearest);
+    /// assert_eq!(paid.unwrap(), Owo::new(15030000,pair.quote));
+    ///
+    /// let moved = ExchangeRate::new(pair, 1494.0).unwrap(); // 40 bps lower
+    /// let err = moved.convert_with_slippage(&amount, &quoted, BasisPoints(25), RoundingMode::Nearest);
+    /// assert_eq!(err.unwrap_err().to_string(), "Slippage of -40.00 bps exceeds the 25 bps limit");
+    ///
+    /// assert!(live.convert_with_slippage(&amount, &quoted, BasisPoints(-25), RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn convert_with_slippage(
+        &self,
+        amount: &Owo,
+        reference: &ExchangeRate,
+        max_slippage: BasisPoints,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        if self.pair != reference.pair {
+            return Err(OwoError::CurrencyMismatch(
+                reference.pair.to_string(),
+                self.pair.to_string(),
+            ));
+        }
+        if max_slippage.get() < 0 {
+            return Err(OwoError::InvalidScalar(max_slippage.get() as f64));
+        }
+        let deviation = (self.rate - reference.rate) / reference.rate * 10_000.0;
+        if deviation.abs() > max_slippage.get() as f64 {
+            return Err(OwoError::SlippageExceeded {
+                deviation_bps: deviation,
+                max_bps: max_slippage.get(),
+            });
+        }
+        self.convert(amount, mode)
+    }
+}
+
+impl fmt::Display for ExchangeRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.pair, self.rate)
+    }
+}
+
+impl TryFrom<ExchangeRate> for RateTable {
+    type Error = OwoError;
+
+    fn try_from(rate: ExchangeRate) -> Result<Self, Self::Error> {
+        let mut table = RateTable::new();
+        table.set_pair_rate(&rate)?;
+        Ok(table)
+    }
+}
+
+/// How a [`RateHistory`] finds a rate for a date it has none for, such as a
+/// weekend or holiday.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapPolicy {
+    /// Only a rate set for that exact date.
+    #[default]
+    Exact,
+    /// The latest rate on or before the date, i.e. the previous business
+    /// day's rate when rates are published on business days.
+    Previous,
+    /// The rate linearly interpolated by day between the nearest rates on
+    /// either side of the date.
+    Interpolate,
+}
+
+/// Exchange rates between currency codes by date, with a [`GapPolicy`]
+/// chosen per lookup for dates without a rate.
+///
+/// As with [`RateTable`], a rate of `r` from `A` to `B` means one major unit
+/// of `A` buys `r` major units of `B`.
+#[derive(Debug, Clone, Default)]
+pub struct RateHistory {
+    // Keyed by `from` then `to`, so lookups borrow the codes
+    rates: HashMap<String, HashMap<String, BTreeMap<CalendarDate, f64>>>,
+}
+
+impl RateHistory {
+    /// Creates an empty rate history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rate from `from` to `to` on `date`, rejecting rates that
+    /// aren't finite and positive.
+    pub fn set_rate(
+        &mut self,
+        from: &str,
+        to: &str,
+        date: impl Into<CalendarDate>,
+        rate: f64,
+    ) -> Result<(), OwoError> {
+        let rate = check_rate(rate)?;
+        self.rates
+            .entry(from.to_string())
+            .or_default()
+            .entry(to.to_string())
+            .or_default()
+            .insert(date.into(), rate);
+        Ok(())
+    }
+
+    /// Returns the rate from `from` to `to` on `date`, filling gaps with
+    /// `policy`
+    ///
+    /// Converting a currency to itself always has a rate of `1.0`.
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::exchange::{GapPolicy, RateHistory};
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// let mut history = RateHistory::new();
+    /// history.set_rate("USD", "NGN", CalendarDate::new(2024, 3, 1), 1500.0).unwrap(); // Friday
+    /// history.set_rate("USD", "NGN", CalendarDate::new(2024, 3, 4), 1530.0).unwrap(); // Monday
+    ///
+    /// let sunday = CalendarDate::new(2024, 3, 3);
+    /// assert_eq!(history.get_rate("USD", "NGN", sunday, GapPolicy::Exact), None);
+    /// assert_eq!(history.get_rate("USD", "NGN", sunday, GapPolicy::Previous), Some(1500.0));
+    /// assert_eq!(history.get_rate("USD", "NGN", sunday, GapPolicy::Interpolate), Some(1520.0));
+    ///
+    /// // Interpolation needs a rate on both sides
+    /// let later = CalendarDate::new(2024, 3, 5);
+    /// assert_eq!(history.get_rate("USD", "NGN", later, GapPolicy::Interpolate), None);
+    /// assert_eq!(history.get_rate("USD", "NGN", later, GapPolicy::Previous), Some(1530.0));
+    /// ```
+    pub fn get_rate(
+        &self,
+        from: &str,
+        to: &str,
+        date: impl Into<CalendarDate>,
+        policy: GapPolicy,
+    ) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+        let date = date.into();
+        let rates = self.rates.get(from)?.get(to)?;
+        if let Some(rate) = rates.get(&date) {
+            return Some(*rate);
+        }
+        let previous = rates.range(..date).next_back();
+        match policy {
+            GapPolicy::Exact => None,
+            GapPolicy::Previous => previous.map(|(_, rate)| *rate),
+            GapPolicy::Interpolate => {
+                let (start, low) = previous?;
+                let (end, high) = rates.range(date..).next()?;
+                let elapsed = DayCount::Act365Fixed.days_between(*start, date) as f64;
+                let span = DayCount::Act365Fixed.days_between(*start, *end) as f64;
+                Some(low + (high - low) * elapsed / span)
+            }
+        }
+    }
+
+    /// Converts `amount` into `target` at the rate on `date`, filling gaps in
+    /// the history with `policy`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::exchange::{GapPolicy, RateHistory};
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut history = RateHistory::new();
+    /// history.set_rate("USD", "NGN", CalendarDate::new(2024, 3, 1), 1500.0).unwrap();
+    ///
+    /// let saturday = CalendarDate::new(2024, 3, 2);
+    /// let owo = Owo::new(1000,usd);
+    /// assert!(history.convert_on(&owo, &ngn, saturday, GapPolicy::Exact, RoundingMode::Nearest).is_err());
+    ///
+    /// let converted = history.convert_on(&owo, &ngn, saturday, GapPolicy::Previous, RoundingMode::Nearest);
+    /// assert_eq!(converted.unwrap(), Owo::new(1500000,ngn));
+    /// ```
+    pub fn convert_on(
+        &self,
+        amount: &Owo,
+        target: &Currency,
+        date: impl Into<CalendarDate>,
+        policy: GapPolicy,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let rate = self
+            .get_rate(amount.currency.code, target.code, date, policy)
+            .ok_or_else(|| {
+                OwoError::MissingRate(amount.currency.code.to_string(), target.code.to_string())
+            })?;
+        amount.convert_at_rate(rate, target, mode)
+    }
+
+    /// Returns every pair's rate on `date` under `policy` as a [`RateTable`],
+    /// for converting many amounts with `Owo::convert`.
+    pub fn rates_on(&self, date: impl Into<CalendarDate>, policy: GapPolicy) -> RateTable {
+        let date = date.into();
+        let mut table = RateTable::new();
+        for (from, targets) in &self.rates {
+            for to in targets.keys() {
+                if let Some(rate) = self.get_rate(from, to, date, policy) {
+                    // Rates between checked rates are checked too
+                    table
+                        .rates
+                        .entry(from.clone())
+                        .or_default()
+                        .insert(to.clone(), rate);
+                }
+            }
+        }
+        table
+    }
+}
+
+// Helper rejecting exchange rates that aren't finite and positive
+fn check_rate(rate: f64) -> Result<f64, OwoError> {
+    if rate.is_finite() && rate > 0.0 {
+        Ok(rate)
+    } else {
+        Err(OwoError::InvalidScalar(rate))
+    }
+}
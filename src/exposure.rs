@@ -0,0 +1,109 @@
+//! Per-currency exposure reports over mixed-currency collections.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo, RateTable, RoundingMode};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The positions held in one currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CurrencyExposure {
+    /// The sum of the positive amounts.
+    pub long: Owo,
+    /// The sum of the negative amounts.
+    pub short: Owo,
+    /// `long + short`.
+    pub net: Owo,
+}
+
+/// Net exposure per currency, sorted by currency code.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ExposureReport {
+    pub exposures: Vec<CurrencyExposure>,
+}
+
+impl ExposureReport {
+    /// Groups `items` by currency, totalling long and short positions
+    ///
+    /// Returns an error if two definitions share a currency code, or a total
+    /// overflows.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::exposure::ExposureReport;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let eur = Currency::new("EUR", "€", 2);
+    /// let items = vec![Owo::new(10000,usd.clone()),Owo::new(-2500,usd.clone()),Owo::new(-4000,eur.clone())];
+    ///
+    /// let report = ExposureReport::new(&items).unwrap();
+    /// assert_eq!(report.exposures[0].net, Owo::new(-4000,eur.clone()));
+    /// assert_eq!(report.exposures[1].long, Owo::new(10000,usd.clone()));
+    /// assert_eq!(report.exposures[1].net, Owo::new(7500,usd.clone()));
+    /// assert_eq!(report.net("GBP"), None);
+    /// ```
+    pub fn new<'a>(items: impl IntoIterator<Item = &'a Owo>) -> Result<Self, OwoError> {
+        let mut groups: BTreeMap<&str, CurrencyExposure> = BTreeMap::new();
+        for item in items {
+            let exposure = groups.entry(item.currency.code).or_insert_with(|| {
+                let zero = Owo::new(0, item.currency);
+                CurrencyExposure {
+                    long: zero,
+                    short: zero,
+                    net: zero,
+                }
+            });
+            if item.amount < 0 {
+                exposure.short = exposure.short.checked_add(item)?;
+            } else {
+                exposure.long = exposure.long.checked_add(item)?;
+            }
+            exposure.net = exposure.net.checked_add(item)?;
+        }
+        Ok(Self {
+            exposures: groups.into_values().collect(),
+        })
+    }
+
+    /// Returns the net exposure in the currency with `code`, if any.
+    pub fn net(&self, code: &str) -> Option<&Owo> {
+        self.exposures
+            .iter()
+            .find(|exposure| exposure.net.currency.code == code)
+            .map(|exposure| &exposure.net)
+    }
+
+    /// Converts every net exposure into `reporting` with `rates`, rounding
+    /// each conversion with `mode`, and returns their sum
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::exposure::ExposureReport;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut rates = RateTable::new();
+    /// rates.set_rate("NGN", "USD", 0.0005).unwrap();
+    ///
+    /// let items = vec![Owo::new(10000,usd.clone()),Owo::new(-10000000,ngn.clone())];
+    /// let report = ExposureReport::new(&items).unwrap();
+    ///
+    /// // $100 long, ₦100,000 (about $50) short
+    /// assert_eq!(report.total_in(&rates, &usd, RoundingMode::Nearest).unwrap(), Owo::new(5000,usd.clone()));
+    /// assert!(report.total_in(&RateTable::new(), &usd, RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn total_in(
+        &self,
+        rates: &RateTable,
+        reporting: &Currency,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let mut total = Owo::new(0, *reporting);
+        for exposure in &self.exposures {
+            total = total.checked_add(&exposure.net.convert(rates, reporting, mode)?)?;
+        }
+        Ok(total)
+    }
+}
@@ -0,0 +1,53 @@
+//! Financial calculations on `Owo` amounts.
+//!
+//! Rates are fractions, so `0.05` is 5%. Every function takes an explicit
+//! `RoundingMode` for the amounts it returns.
+
+pub mod amortization;
+pub mod cashflow;
+pub mod day_count;
+pub mod interest;
+pub mod loan;
+pub mod proration;
+pub mod rate;
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use interest::Compounding;
+
+pub use cashflow::{future_value, irr, npv, present_value};
+pub use loan::{PaymentTiming, ipmt, pmt, ppmt};
+pub use proration::prorate;
+pub use rate::InterestRate;
+
+// Helper converting a nominal annual rate to a per-period rate, rejecting
+// rates that aren't finite or would shrink a balance to zero or below
+pub(crate) fn periodic_rate(annual_rate: f64, compounding: Compounding) -> Result<f64, OwoError> {
+    let rate = annual_rate / f64::from(compounding.periods_per_year());
+    check_rate(rate).map_err(|_| OwoError::InvalidScalar(annual_rate))
+}
+
+// Helper rounding an amount in `like`'s minor units to an `Owo`, reporting
+// overflow as an error from `op`
+pub(crate) fn owo_from_minor(
+    like: &Owo,
+    minor: f64,
+    mode: RoundingMode,
+    op: &'static str,
+) -> Result<Owo, OwoError> {
+    let raw = minor / like.currency.scale_factor_f64();
+    Ok(Owo {
+        amount: like
+            .checked_round_amount_with_mode(raw, mode)
+            .ok_or_else(|| like.overflow(op, minor))?,
+        currency: like.currency,
+    })
+}
+
+// Helper rejecting per-period rates that aren't finite or are -100% or less
+pub(crate) fn check_rate(rate: f64) -> Result<f64, OwoError> {
+    if !rate.is_finite() || rate <= -1.0 {
+        return Err(OwoError::InvalidScalar(rate));
+    }
+    Ok(rate)
+}
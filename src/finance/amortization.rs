@@ -0,0 +1,86 @@
+//! Amortization schedules for fixed-payment loans.
+
+use crate::error::OwoError;
+use crate::finance::interest::Compounding;
+use crate::finance::loan::{PaymentTiming, annuity_factor};
+use crate::finance::periodic_rate;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// One period of an amortization schedule.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AmortizationRow {
+    /// The 1-based period number.
+    pub period: u32,
+    /// The total paid this period, `interest + principal`.
+    pub payment: Owo,
+    /// The part of the payment covering interest.
+    pub interest: Owo,
+    /// The part of the payment reducing the balance.
+    pub principal: Owo,
+    /// The balance left after this period's payment.
+    pub balance: Owo,
+}
+
+/// Builds the schedule for repaying `principal` at the nominal `annual_rate`
+/// over `term_years`, with one payment per `frequency` period
+///
+/// Payments and interest are rounded with `mode`; the final row absorbs the
+/// rounding drift so the principal portions sum exactly to `principal` and
+/// the last balance is zero.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::amortization::schedule;
+/// use cowry::finance::interest::Compounding;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let loan = Owo::new(1000000,usd.clone());
+///
+/// let rows = schedule(&loan, 0.06, 1, Compounding::Monthly, RoundingMode::Nearest).unwrap();
+///
+/// assert_eq!(rows.len(), 12);
+/// assert_eq!(rows[0].payment, Owo::new(86066,usd.clone()));
+/// assert_eq!(rows[0].interest, Owo::new(5000,usd.clone()));
+/// assert_eq!(rows[0].principal, Owo::new(81066,usd.clone()));
+/// assert_eq!(rows[11].balance, Owo::new(0,usd.clone()));
+/// assert_eq!(rows.iter().map(|row| row.principal.amount).sum::<i64>(), loan.amount);
+/// ```
+pub fn schedule(
+    principal: &Owo,
+    annual_rate: f64,
+    term_years: u32,
+    frequency: Compounding,
+    mode: RoundingMode,
+) -> Result<Vec<AmortizationRow>, OwoError> {
+    let periods = term_years
+        .checked_mul(frequency.periods_per_year())
+        .ok_or_else(|| principal.overflow("schedule", term_years))?;
+    if periods == 0 {
+        return Err(OwoError::DivisionByZero);
+    }
+    let rate = periodic_rate(annual_rate, frequency)?;
+    let payment =
+        principal.multiply_with_mode(annuity_factor(rate, periods, PaymentTiming::End), mode)?;
+
+    let mut rows = Vec::with_capacity(periods as usize);
+    let mut balance = *principal;
+    for period in 1..=periods {
+        let interest = balance.multiply_with_mode(rate, mode)?;
+        let (payment, repaid) = if period == periods {
+            (interest.checked_add(&balance)?, balance)
+        } else {
+            (payment, payment.checked_sub(&interest)?)
+        };
+        balance = balance.checked_sub(&repaid)?;
+        rows.push(AmortizationRow {
+            period,
+            payment,
+            interest,
+            principal: repaid,
+            balance,
+        });
+    }
+    Ok(rows)
+}
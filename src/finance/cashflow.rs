@@ -0,0 +1,162 @@
+//! Discounting and projecting amounts over time: present and future value
+//! of a single amount, and net present value and internal rate of return
+//! over same-currency cash flows, where the flow at index `i` occurs at the
+//! end of period `i` and the first flow is undiscounted.
+
+use crate::error::OwoError;
+use crate::finance::{check_rate, owo_from_minor};
+use crate::owo::common_currency;
+use crate::{Owo, RoundingMode};
+
+// Helper summing the cash flows discounted at `rate`, in minor units
+fn discounted_sum(rate: f64, cashflows: &[Owo]) -> f64 {
+    cashflows
+        .iter()
+        .enumerate()
+        .map(|(period, flow)| flow.amount as f64 / (1.0 + rate).powi(period as i32))
+        .sum()
+}
+
+/// Returns what `future` received after `periods` periods is worth today,
+/// discounted at `rate` per period
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::present_value;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let future = Owo::new(100000,usd.clone());
+///
+/// let today = present_value(&future, 0.05, 3, RoundingMode::Nearest).unwrap();
+/// assert_eq!(today, Owo::new(86384,usd.clone()));
+///
+/// assert!(present_value(&future, -1.0, 3, RoundingMode::Nearest).is_err());
+/// ```
+pub fn present_value(
+    future: &Owo,
+    rate: f64,
+    periods: u32,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let growth = (1.0 + check_rate(rate)?).powf(f64::from(periods));
+    future.divide_with_mode(growth, mode)
+}
+
+/// Returns what `present` grows to after `periods` periods at `rate` per
+/// period, with interest reinvested each period
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::future_value;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let savings = Owo::new(100000,usd.clone());
+///
+/// let later = future_value(&savings, 0.05, 4, RoundingMode::Nearest).unwrap();
+/// assert_eq!(later, Owo::new(121551,usd.clone()));
+///
+/// let later = future_value(&savings, 0.05, 4, RoundingMode::Floor).unwrap();
+/// assert_eq!(later, Owo::new(121550,usd.clone()));
+/// ```
+pub fn future_value(
+    present: &Owo,
+    rate: f64,
+    periods: u32,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let growth = (1.0 + check_rate(rate)?).powf(f64::from(periods));
+    present.multiply_with_mode(growth, mode)
+}
+
+/// Returns the net present value of `cashflows` discounted at `rate` per
+/// period, rounded with `mode`
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::npv;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let flows = vec![
+///     Owo::new(-100000,usd.clone()),
+///     Owo::new(30000,usd.clone()),
+///     Owo::new(40000,usd.clone()),
+///     Owo::new(50000,usd.clone()),
+/// ];
+///
+/// assert_eq!(npv(0.1, &flows, RoundingMode::Nearest).unwrap(), Owo::new(-2104,usd.clone()));
+/// assert_eq!(npv(0.0, &flows, RoundingMode::Nearest).unwrap(), Owo::new(20000,usd.clone()));
+/// assert!(npv(0.1, &[], RoundingMode::Nearest).is_err());
+/// ```
+pub fn npv(rate: f64, cashflows: &[Owo], mode: RoundingMode) -> Result<Owo, OwoError> {
+    let first = common_currency(cashflows)?;
+    check_rate(rate)?;
+    owo_from_minor(first, discounted_sum(rate, cashflows), mode, "npv")
+}
+
+/// Returns the internal rate of return of `cashflows`: the per-period rate
+/// at which their net present value is zero
+///
+/// Returns an error if the flows never change sign, since no such rate
+/// exists then, or if the rate lies below -99% per period or too far out for
+/// the discounted flows to fit in an `f64`. When several rates qualify, one
+/// of them is returned.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::irr;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let flows = vec![Owo::new(-100000,usd.clone()),Owo::new(60000,usd.clone()),Owo::new(60000,usd.clone())];
+///
+/// let rate = irr(&flows).unwrap();
+/// assert!((rate - 0.130662).abs() < 1e-6);
+///
+/// assert!(irr(&[Owo::new(100,usd.clone()),Owo::new(100,usd.clone())]).is_err());
+///
+/// // Fifty quiet periods between the outlay and the payoff
+/// let mut flows = vec![Owo::new(-100000,usd.clone())];
+/// flows.extend(std::iter::repeat_n(Owo::new(0,usd.clone()), 50));
+/// flows.push(Owo::new(200000,usd.clone()));
+/// let rate = irr(&flows).unwrap();
+/// assert!((rate - 0.013683).abs() < 1e-6);
+/// ```
+pub fn irr(cashflows: &[Owo]) -> Result<f64, OwoError> {
+    common_currency(cashflows)?;
+    let no_root = || OwoError::NoConvergence("cash flows have no internal rate of return".into());
+
+    // Bracket a sign change of the NPV, widening the upper bound as needed.
+    // Nearer -100% the discount factors of long series underflow to zero.
+    let mut low = -0.99;
+    let mut high = 1.0;
+    let low_value = discounted_sum(low, cashflows);
+    if !low_value.is_finite() {
+        return Err(no_root());
+    }
+    let low_sign = low_value.signum();
+    loop {
+        let high_value = discounted_sum(high, cashflows);
+        if !high_value.is_finite() || high > 1e9 {
+            return Err(no_root());
+        }
+        if high_value.signum() != low_sign {
+            break;
+        }
+        low = high;
+        high *= 2.0;
+    }
+
+    // Bisect down to the limits of f64 precision
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if discounted_sum(mid, cashflows).signum() == low_sign {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok((low + high) / 2.0)
+}
@@ -0,0 +1,275 @@
+//! Day-count conventions for accruing interest between two dates.
+//!
+//! Dates are [`CalendarDate`]s; with the `chrono` feature every function also
+//! accepts a `chrono::NaiveDate`.
+//!
+//! #Example
+//! ```
+//! # #[cfg(feature = "chrono")] {
+//! use chrono::NaiveDate;
+//! use cowry::finance::day_count::DayCount;
+//!
+//! let start = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+//!
+//! assert_eq!(DayCount::Act360.days_between(start, end), 29);
+//! # }
+//! ```
+
+use crate::error::OwoError;
+use serde::{Deserialize, Serialize};
+
+/// A proleptic Gregorian calendar date.
+///
+/// Deserializing goes through [`CalendarDate::try_new`], so a month outside
+/// 1-12 or a day past the end of its month is rejected.
+///
+/// #Example
+/// ```
+/// use cowry::finance::day_count::CalendarDate;
+///
+/// let date: CalendarDate = serde_json::from_str(r#"{"year":2024,"month":2,"day":29}"#).unwrap();
+/// assert_eq!(date, CalendarDate::new(2024, 2, 29));
+///
+/// assert!(serde_json::from_str::<CalendarDate>(r#"{"year":2023,"month":2,"day":29}"#).is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "CalendarDateParts")]
+pub struct CalendarDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+// Helper for deserializing a `CalendarDate` before it is checked
+#[derive(Deserialize)]
+struct CalendarDateParts {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl TryFrom<CalendarDateParts> for CalendarDate {
+    type Error = OwoError;
+
+    fn try_from(parts: CalendarDateParts) -> Result<Self, Self::Error> {
+        Self::try_new(parts.year, parts.month, parts.day)
+    }
+}
+
+impl CalendarDate {
+    /// Creates a date from its year, month (1-12) and day of month.
+    ///
+    /// Panics, or fails to compile in a `const`, if the month or day is out
+    /// of range; see [`CalendarDate::try_new`].
+    pub const fn new(year: i32, month: u32, day: u32) -> Self {
+        assert!(Self::is_valid(year, month, day), "invalid calendar date");
+        Self { year, month, day }
+    }
+
+    /// Creates a date from its year, month (1-12) and day of month, rejecting
+    /// dates that don't exist such as February 30th.
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// assert_eq!(CalendarDate::try_new(2024, 2, 29).unwrap(), CalendarDate::new(2024, 2, 29));
+    ///
+    /// assert!(CalendarDate::try_new(2024, 2, 30).is_err());
+    /// assert!(CalendarDate::try_new(2024, 13, 1).is_err());
+    /// assert!(CalendarDate::try_new(2024, 0, 1).is_err());
+    /// ```
+    pub fn try_new(year: i32, month: u32, day: u32) -> Result<Self, OwoError> {
+        if !Self::is_valid(year, month, day) {
+            return Err(OwoError::InvalidDate(format!("{year}-{month:02}-{day:02}")));
+        }
+        Ok(Self { year, month, day })
+    }
+
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    // Helper checking the month is 1-12 and the day falls within it
+    const fn is_valid(year: i32, month: u32, day: u32) -> bool {
+        month >= 1 && month <= 12 && day >= 1 && day <= Self::days_in_month(year, month)
+    }
+
+    /// Returns the number of days in `month` of `year`.
+    pub const fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            4 | 6 | 9 | 11 => 30,
+            2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+            2 => 28,
+            _ => 31,
+        }
+    }
+
+    /// Returns the date `days` days later, or earlier when negative
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// assert_eq!(CalendarDate::new(2024, 2, 28).add_days(2), CalendarDate::new(2024, 3, 1));
+    /// assert_eq!(CalendarDate::new(2024, 1, 1).add_days(-1), CalendarDate::new(2023, 12, 31));
+    /// ```
+    pub fn add_days(self, days: i64) -> Self {
+        let days = self.days_since_epoch() + days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self::new(year as i32, month as u32, day as u32)
+    }
+
+    /// Returns the same day `months` months later, or earlier when negative,
+    /// moved back to the month's last day when it is shorter
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::day_count::CalendarDate;
+    ///
+    /// assert_eq!(CalendarDate::new(2024, 1, 31).add_months(1), CalendarDate::new(2024, 2, 29));
+    /// assert_eq!(CalendarDate::new(2024, 1, 31).add_months(2), CalendarDate::new(2024, 3, 31));
+    /// assert_eq!(CalendarDate::new(2024, 1, 15).add_months(-1), CalendarDate::new(2023, 12, 15));
+    /// ```
+    pub fn add_months(self, months: i64) -> Self {
+        let index = i64::from(self.year) * 12 + i64::from(self.month) - 1 + months;
+        let year = index.div_euclid(12) as i32;
+        let month = index.rem_euclid(12) as u32 + 1;
+        Self::new(year, month, self.day.min(Self::days_in_month(year, month)))
+    }
+
+    // Helper counting days since 1970-01-01
+    fn days_since_epoch(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    // Helper counting February 29ths on or before this date since year 0
+    fn leap_days_through(self) -> i64 {
+        let year = i64::from(self.year);
+        let leap_years_before =
+            |year: i64| year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+        let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let past_leap_day = self.month > 2 || (self.month == 2 && self.day == 29);
+        leap_years_before(year - 1) + i64::from(is_leap && past_leap_day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for CalendarDate {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        CalendarDate::new(date.year(), date.month(), date.day())
+    }
+}
+
+/// A market convention for counting days and year fractions.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCount {
+    /// Actual days over a 360-day year, common for money markets.
+    Act360,
+    /// Actual days over a fixed 365-day year.
+    Act365Fixed,
+    /// 30-day months over a 360-day year (US bond basis).
+    Thirty360,
+    /// Actual days, skipping February 29th, over a 365-day year.
+    Act365NoLeap,
+}
+
+impl DayCount {
+    /// Returns the number of days from `start` to `end` under this
+    /// convention, negative when `end` is before `start`
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::day_count::{CalendarDate, DayCount};
+    ///
+    /// let start = CalendarDate::new(2024, 1, 31);
+    /// let end = CalendarDate::new(2024, 3, 31);
+    ///
+    /// assert_eq!(DayCount::Act360.days_between(start, end), 60);
+    /// assert_eq!(DayCount::Thirty360.days_between(start, end), 60);
+    /// assert_eq!(DayCount::Thirty360.days_between(CalendarDate::new(2024, 1, 15), end), 76);
+    /// assert_eq!(DayCount::Act365NoLeap.days_between(start, end), 59);
+    /// ```
+    pub fn days_between(self, start: impl Into<CalendarDate>, end: impl Into<CalendarDate>) -> i64 {
+        let (start, end) = (start.into(), end.into());
+        match self {
+            DayCount::Act360 | DayCount::Act365Fixed => {
+                end.days_since_epoch() - start.days_since_epoch()
+            }
+            DayCount::Act365NoLeap => {
+                let actual = end.days_since_epoch() - start.days_since_epoch();
+                actual - (end.leap_days_through() - start.leap_days_through())
+            }
+            DayCount::Thirty360 => {
+                let start_day = start.day.min(30);
+                let end_day = if start_day == 30 {
+                    end.day.min(30)
+                } else {
+                    end.day
+                };
+                360 * (i64::from(end.year) - i64::from(start.year))
+                    + 30 * (i64::from(end.month) - i64::from(start.month))
+                    + (i64::from(end_day) - i64::from(start_day))
+            }
+        }
+    }
+
+    /// The number of days in a year under this convention.
+    pub fn days_in_year(self) -> u32 {
+        match self {
+            DayCount::Act360 | DayCount::Thirty360 => 360,
+            DayCount::Act365Fixed | DayCount::Act365NoLeap => 365,
+        }
+    }
+
+    /// Returns the fraction of a year from `start` to `end` under this
+    /// convention
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::day_count::{CalendarDate, DayCount};
+    ///
+    /// let start = CalendarDate::new(2023, 1, 1);
+    /// let end = CalendarDate::new(2024, 1, 1);
+    ///
+    /// assert_eq!(DayCount::Act365Fixed.year_fraction(start, end), 1.0);
+    /// assert_eq!(DayCount::Act360.year_fraction(start, end), 365.0 / 360.0);
+    /// assert_eq!(DayCount::Thirty360.year_fraction(start, end), 1.0);
+    /// ```
+    pub fn year_fraction(
+        self,
+        start: impl Into<CalendarDate>,
+        end: impl Into<CalendarDate>,
+    ) -> f64 {
+        self.days_between(start, end) as f64 / f64::from(self.days_in_year())
+    }
+}
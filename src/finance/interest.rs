@@ -0,0 +1,131 @@
+//! Simple, compound and accrued interest on an `Owo` principal.
+
+use crate::error::OwoError;
+use crate::finance::day_count::{CalendarDate, DayCount};
+use crate::finance::periodic_rate;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// How often interest is compounded within a year.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compounding {
+    Annually,
+    SemiAnnually,
+    Quarterly,
+    Monthly,
+    Weekly,
+    Daily,
+}
+
+impl Compounding {
+    /// The number of compounding periods in a year.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::finance::interest::Compounding;
+    /// assert_eq!(Compounding::Monthly.periods_per_year(), 12);
+    /// assert_eq!(Compounding::Daily.periods_per_year(), 365);
+    /// ```
+    pub fn periods_per_year(self) -> u32 {
+        match self {
+            Compounding::Annually => 1,
+            Compounding::SemiAnnually => 2,
+            Compounding::Quarterly => 4,
+            Compounding::Monthly => 12,
+            Compounding::Weekly => 52,
+            Compounding::Daily => 365,
+        }
+    }
+}
+
+/// Returns the simple interest earned on `principal` at `rate` per period
+/// over `periods` periods
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::interest::simple_interest;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let principal = Owo::new(100000,ngn.clone());
+///
+/// let interest = simple_interest(&principal, 0.05, 3, RoundingMode::Nearest).unwrap();
+/// assert_eq!(interest, Owo::new(15000,ngn.clone()));
+///
+/// assert!(simple_interest(&principal, f64::NAN, 3, RoundingMode::Nearest).is_err());
+/// ```
+pub fn simple_interest(
+    principal: &Owo,
+    rate: f64,
+    periods: u32,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    principal.multiply_with_mode(rate * f64::from(periods), mode)
+}
+
+/// Returns the interest earned on `principal` at the nominal `annual_rate`,
+/// compounded `compounding` times a year, over `periods` compounding periods
+///
+/// Returns an error if the rate is not finite or would shrink a period's
+/// balance to zero or below.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::interest::{compound_interest, Compounding};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let principal = Owo::new(100000,usd.clone());
+///
+/// // $1,000 at 6% compounded monthly for two years
+/// let interest = compound_interest(&principal, 0.06, Compounding::Monthly, 24, RoundingMode::Nearest).unwrap();
+/// assert_eq!(interest, Owo::new(12716,usd.clone()));
+///
+/// let interest = compound_interest(&principal, 0.06, Compounding::Monthly, 24, RoundingMode::Floor).unwrap();
+/// assert_eq!(interest, Owo::new(12715,usd.clone()));
+///
+/// assert!(compound_interest(&principal, -12.0, Compounding::Monthly, 1, RoundingMode::Nearest).is_err());
+/// ```
+pub fn compound_interest(
+    principal: &Owo,
+    annual_rate: f64,
+    compounding: Compounding,
+    periods: u32,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let growth = 1.0 + periodic_rate(annual_rate, compounding)?;
+    principal.multiply_with_mode(growth.powf(f64::from(periods)) - 1.0, mode)
+}
+
+/// Returns the simple interest accrued on `principal` at `annual_rate`
+/// between `start` and `end`, with the year fraction given by `convention`
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::day_count::{CalendarDate, DayCount};
+/// use cowry::finance::interest::accrued_interest;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let principal = Owo::new(1000000,usd.clone());
+/// let start = CalendarDate::new(2024, 1, 1);
+/// let end = CalendarDate::new(2024, 4, 1);
+///
+/// // 91 actual days at 5%
+/// let act = accrued_interest(&principal, 0.05, DayCount::Act360, start, end, RoundingMode::Nearest).unwrap();
+/// assert_eq!(act, Owo::new(12639,usd.clone()));
+///
+/// // three 30-day months at 5%
+/// let thirty = accrued_interest(&principal, 0.05, DayCount::Thirty360, start, end, RoundingMode::Nearest).unwrap();
+/// assert_eq!(thirty, Owo::new(12500,usd.clone()));
+/// ```
+pub fn accrued_interest(
+    principal: &Owo,
+    annual_rate: f64,
+    convention: DayCount,
+    start: impl Into<CalendarDate>,
+    end: impl Into<CalendarDate>,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    principal.multiply_with_mode(annual_rate * convention.year_fraction(start, end), mode)
+}
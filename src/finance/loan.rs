@@ -0,0 +1,169 @@
+//! Spreadsheet-compatible loan payment functions.
+//!
+//! Like `PMT`, `IPMT` and `PPMT` in Excel and LibreOffice, payments are
+//! returned with the opposite sign to the present value, so a positive loan
+//! yields negative payments, and the loan is fully repaid by the last period.
+
+use crate::error::OwoError;
+use crate::finance::{check_rate, owo_from_minor};
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// When payments fall within each period, the spreadsheet `type` argument.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaymentTiming {
+    /// Payments at the end of each period (`type = 0`).
+    #[default]
+    End,
+    /// Payments at the start of each period (`type = 1`).
+    Beginning,
+}
+
+// Helper returning the payment per unit of present value, as a positive number
+pub(crate) fn annuity_factor(rate: f64, nper: u32, timing: PaymentTiming) -> f64 {
+    if rate == 0.0 {
+        return 1.0 / f64::from(nper);
+    }
+    let growth = (1.0 + rate).powf(f64::from(nper));
+    let factor = rate * growth / (growth - 1.0);
+    match timing {
+        PaymentTiming::End => factor,
+        PaymentTiming::Beginning => factor / (1.0 + rate),
+    }
+}
+
+// Helper returning the unrounded payment and interest portion of `period`, in minor units
+fn payment_parts(
+    rate: f64,
+    period: u32,
+    nper: u32,
+    pv: &Owo,
+    timing: PaymentTiming,
+) -> Result<(f64, f64), OwoError> {
+    check_rate(rate)?;
+    if nper == 0 {
+        return Err(OwoError::DivisionByZero);
+    }
+    if period == 0 || period > nper {
+        return Err(OwoError::InvalidPeriod {
+            period,
+            periods: nper,
+        });
+    }
+    let principal = pv.amount as f64;
+    let payment = -principal * annuity_factor(rate, nper, timing);
+    if timing == PaymentTiming::Beginning && period == 1 {
+        return Ok((payment, 0.0));
+    }
+
+    // The balance owed after `elapsed` payments, with its spreadsheet sign
+    let elapsed = f64::from(period - 1);
+    let growth = (1.0 + rate).powf(elapsed);
+    let annuity = if rate == 0.0 {
+        elapsed
+    } else {
+        (growth - 1.0) / rate
+    };
+    let carried = match timing {
+        PaymentTiming::End => 1.0,
+        PaymentTiming::Beginning => 1.0 + rate,
+    };
+    let balance = -(principal * growth + payment * carried * annuity);
+    let interest = match timing {
+        PaymentTiming::End => balance * rate,
+        PaymentTiming::Beginning => balance * rate / (1.0 + rate),
+    };
+    Ok((payment, interest))
+}
+
+/// Returns the fixed payment per period that repays `pv` over `nper` periods
+/// at `rate` per period, like the spreadsheet `PMT` function
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::{PaymentTiming, pmt};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let loan = Owo::new(1000000,usd.clone());
+///
+/// // =PMT(0.5%, 12, 10000) → -860.66
+/// let payment = pmt(0.005, 12, &loan, PaymentTiming::End, RoundingMode::Nearest).unwrap();
+/// assert_eq!(payment, Owo::new(-86066,usd.clone()));
+///
+/// // =PMT(0.5%, 12, 10000, 0, 1) → -856.38
+/// let payment = pmt(0.005, 12, &loan, PaymentTiming::Beginning, RoundingMode::Nearest).unwrap();
+/// assert_eq!(payment, Owo::new(-85638,usd.clone()));
+///
+/// assert!(pmt(0.005, 0, &loan, PaymentTiming::End, RoundingMode::Nearest).is_err());
+/// ```
+pub fn pmt(
+    rate: f64,
+    nper: u32,
+    pv: &Owo,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let (payment, _) = payment_parts(rate, 1, nper, pv, timing)?;
+    owo_from_minor(pv, payment, mode, "pmt")
+}
+
+/// Returns the interest portion of the payment in `period` (1-based), like
+/// the spreadsheet `IPMT` function
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::{PaymentTiming, ipmt};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let loan = Owo::new(1000000,usd.clone());
+///
+/// // =IPMT(0.5%, 1, 12, 10000) → -50.00
+/// let interest = ipmt(0.005, 1, 12, &loan, PaymentTiming::End, RoundingMode::Nearest).unwrap();
+/// assert_eq!(interest, Owo::new(-5000,usd.clone()));
+///
+/// // =IPMT(0.5%, 12, 12, 10000) → -4.28
+/// let interest = ipmt(0.005, 12, 12, &loan, PaymentTiming::End, RoundingMode::Nearest).unwrap();
+/// assert_eq!(interest, Owo::new(-428,usd.clone()));
+///
+/// assert!(ipmt(0.005, 13, 12, &loan, PaymentTiming::End, RoundingMode::Nearest).is_err());
+/// ```
+pub fn ipmt(
+    rate: f64,
+    period: u32,
+    nper: u32,
+    pv: &Owo,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let (_, interest) = payment_parts(rate, period, nper, pv, timing)?;
+    owo_from_minor(pv, interest, mode, "ipmt")
+}
+
+/// Returns the principal portion of the payment in `period` (1-based), like
+/// the spreadsheet `PPMT` function
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::{PaymentTiming, ppmt};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let loan = Owo::new(1000000,usd.clone());
+///
+/// // =PPMT(0.5%, 1, 12, 10000) → -810.66
+/// let principal = ppmt(0.005, 1, 12, &loan, PaymentTiming::End, RoundingMode::Nearest).unwrap();
+/// assert_eq!(principal, Owo::new(-81066,usd.clone()));
+/// ```
+pub fn ppmt(
+    rate: f64,
+    period: u32,
+    nper: u32,
+    pv: &Owo,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let (payment, interest) = payment_parts(rate, period, nper, pv, timing)?;
+    owo_from_minor(pv, payment - interest, mode, "ppmt")
+}
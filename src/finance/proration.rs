@@ -0,0 +1,56 @@
+//! Day-weighted proration of an amount over part of a billing period, for
+//! rent, salaries and mid-cycle subscription changes.
+
+use crate::error::OwoError;
+use crate::finance::day_count::{CalendarDate, DayCount};
+use crate::{Owo, RoundingMode};
+
+/// Returns the share of `amount`, billed for `period_start..period_end`,
+/// that covers `usage_start..usage_end`, rounded with `mode`
+///
+/// Ranges are half-open: the end date is the first day not included. Usage
+/// outside the period is ignored. `convention` chooses how days are counted:
+/// `Act365Fixed` counts actual days, `Act365NoLeap` skips February 29th, and
+/// `Thirty360` treats every month as 30 days, so each month prorates alike.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::finance::day_count::{CalendarDate, DayCount};
+/// use cowry::finance::prorate;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let rent = Owo::new(3000000,ngn.clone());
+/// let (start, end) = (CalendarDate::new(2024, 2, 1), CalendarDate::new(2024, 3, 1));
+/// let moved_in = CalendarDate::new(2024, 2, 20);
+///
+/// // 10 of February's 29 days
+/// let share = prorate(&rent, start, end, moved_in, end, DayCount::Act365Fixed, RoundingMode::Nearest).unwrap();
+/// assert_eq!(share, Owo::new(1034483,ngn.clone()));
+///
+/// // 11 of 30 days when February counts as a 30-day month
+/// let share = prorate(&rent, start, end, moved_in, end, DayCount::Thirty360, RoundingMode::Nearest).unwrap();
+/// assert_eq!(share, Owo::new(1100000,ngn.clone()));
+///
+/// assert!(prorate(&rent, start, start, start, end, DayCount::Act365Fixed, RoundingMode::Nearest).is_err());
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn prorate(
+    amount: &Owo,
+    period_start: impl Into<CalendarDate>,
+    period_end: impl Into<CalendarDate>,
+    usage_start: impl Into<CalendarDate>,
+    usage_end: impl Into<CalendarDate>,
+    convention: DayCount,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let (period_start, period_end) = (period_start.into(), period_end.into());
+    let period_days = convention.days_between(period_start, period_end);
+    if period_days <= 0 {
+        return Err(OwoError::DivisionByZero);
+    }
+    let usage_start = usage_start.into().max(period_start);
+    let usage_end = usage_end.into().min(period_end);
+    let usage_days = convention.days_between(usage_start, usage_end).max(0);
+    amount.multiply_with_mode(usage_days as f64 / period_days as f64, mode)
+}
@@ -0,0 +1,119 @@
+//! Annual interest rates that carry their own meaning: a nominal rate (APR)
+//! with its compounding frequency, or an effective rate (APY).
+
+use crate::error::OwoError;
+use crate::finance::interest::Compounding;
+use crate::finance::{check_rate, periodic_rate};
+use serde::{Deserialize, Serialize};
+
+/// An annual interest rate, as a fraction (`0.05` is 5%).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum InterestRate {
+    /// A nominal annual rate (APR), compounded `compounding` times a year.
+    Nominal { rate: f64, compounding: Compounding },
+    /// An effective annual rate (APY), with compounding already folded in.
+    Effective(f64),
+}
+
+impl InterestRate {
+    /// Creates a nominal annual rate, rejecting rates that aren't finite or
+    /// would take a period's balance to zero or below.
+    pub fn nominal(rate: f64, compounding: Compounding) -> Result<Self, OwoError> {
+        periodic_rate(rate, compounding)?;
+        Ok(InterestRate::Nominal { rate, compounding })
+    }
+
+    /// Creates an effective annual rate, rejecting rates that aren't finite
+    /// or are -100% or less.
+    pub fn effective(rate: f64) -> Result<Self, OwoError> {
+        Ok(InterestRate::Effective(check_rate(rate)?))
+    }
+
+    /// Returns the effective annual rate (APY)
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::InterestRate;
+    /// use cowry::finance::interest::Compounding;
+    ///
+    /// let apr = InterestRate::nominal(0.12, Compounding::Monthly).unwrap();
+    /// assert!((apr.apy() - 0.126825).abs() < 1e-6);
+    ///
+    /// assert_eq!(InterestRate::effective(0.05).unwrap().apy(), 0.05);
+    /// ```
+    pub fn apy(self) -> f64 {
+        match self {
+            InterestRate::Nominal { rate, compounding } => {
+                let periods = compounding.periods_per_year();
+                (1.0 + rate / f64::from(periods)).powf(f64::from(periods)) - 1.0
+            }
+            InterestRate::Effective(rate) => rate,
+        }
+    }
+
+    /// Returns the nominal annual rate (APR) that, compounded `compounding`
+    /// times a year, gives the same effective rate
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::finance::InterestRate;
+    /// use cowry::finance::interest::Compounding;
+    ///
+    /// let apy = InterestRate::effective(0.126825).unwrap();
+    /// assert!((apy.apr(Compounding::Monthly) - 0.12).abs() < 1e-6);
+    ///
+    /// let apr = InterestRate::nominal(0.06, Compounding::Quarterly).unwrap();
+    /// assert_eq!(apr.apr(Compounding::Quarterly), 0.06);
+    /// ```
+    pub fn apr(self, compounding: Compounding) -> f64 {
+        match self {
+            InterestRate::Nominal {
+                rate,
+                compounding: own,
+            } if own == compounding => rate,
+            _ => f64::from(compounding.periods_per_year()) * self.periodic(compounding),
+        }
+    }
+
+    /// Returns the rate per period when interest is compounded
+    /// `compounding` times a year, for use with the per-period functions in
+    /// `cowry::finance`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::finance::{InterestRate, PaymentTiming, pmt};
+    /// use cowry::finance::interest::Compounding;
+    ///
+    /// let apr = InterestRate::nominal(0.06, Compounding::Monthly).unwrap();
+    /// assert_eq!(apr.periodic(Compounding::Monthly), 0.005);
+    ///
+    /// let loan = Owo::new(1000000,Currency::new("USD", "$", 2));
+    /// let payment = pmt(apr.periodic(Compounding::Monthly), 12, &loan, PaymentTiming::End, RoundingMode::Nearest).unwrap();
+    /// assert_eq!(payment.amount, -86066);
+    /// ```
+    pub fn periodic(self, compounding: Compounding) -> f64 {
+        let periods = f64::from(compounding.periods_per_year());
+        match self {
+            InterestRate::Nominal {
+                rate,
+                compounding: own,
+            } if own == compounding => rate / periods,
+            _ => (1.0 + self.apy()).powf(1.0 / periods) - 1.0,
+        }
+    }
+
+    /// Converts to the equivalent nominal rate compounded `compounding`
+    /// times a year.
+    pub fn to_nominal(self, compounding: Compounding) -> InterestRate {
+        InterestRate::Nominal {
+            rate: self.apr(compounding),
+            compounding,
+        }
+    }
+
+    /// Converts to the equivalent effective annual rate.
+    pub fn to_effective(self) -> InterestRate {
+        InterestRate::Effective(self.apy())
+    }
+}
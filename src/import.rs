@@ -0,0 +1,256 @@
+//! Bank statement import from OFX and QIF exports.
+//!
+//! Both parsers return the statement's transactions in file order, with
+//! credits positive and debits negative.
+
+use crate::error::OwoError;
+use crate::finance::day_count::CalendarDate;
+use crate::{Currency, Owo, registry};
+use serde::{Deserialize, Serialize};
+
+/// One transaction from a bank statement.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BankTransaction {
+    pub date: CalendarDate,
+    pub payee: String,
+    /// Positive for money in, negative for money out.
+    pub amount: Owo,
+}
+
+/// Parses the `<STMTTRN>` transactions of an OFX file, SGML (1.x) or XML
+/// (2.x), in the currency named by its `<CURDEF>` tag
+///
+/// The payee is taken from `<NAME>`, falling back to `<MEMO>`. Returns an
+/// error naming the first transaction that can't be parsed, or if the file
+/// has no `<CURDEF>`.
+///
+/// #Example
+/// ```
+/// use cowry::import::parse_ofx;
+/// use cowry::finance::day_count::CalendarDate;
+///
+/// let ofx = "OFXHEADER:100
+/// <OFX><BANKMSGSRSV1><STMTTRNRS><STMTRS>
+/// <CURDEF>NGN
+/// <BANKTRANLIST>
+/// <STMTTRN><TRNTYPE>DEBIT<DTPOSTED>20240115120000[+1:WAT]<TRNAMT>-2500.50<NAME>Chicken Republic</STMTTRN>
+/// <STMTTRN><TRNTYPE>CREDIT<DTPOSTED>20240131<TRNAMT>450000.00<MEMO>Salary</STMTTRN>
+/// </BANKTRANLIST></STMTRS></STMTTRNRS></BANKMSGSRSV1></OFX>";
+///
+/// let transactions = parse_ofx(ofx).unwrap();
+/// assert_eq!(transactions[0].date, CalendarDate::new(2024, 1, 15));
+/// assert_eq!(transactions[0].payee, "Chicken Republic");
+/// assert_eq!(transactions[0].amount.get_amount(), -250050);
+/// assert_eq!(transactions[1].payee, "Salary");
+/// ```
+pub fn parse_ofx(input: &str) -> Result<Vec<BankTransaction>, OwoError> {
+    let mut currency: Option<Currency> = None;
+    let mut transactions = Vec::new();
+    let mut current: Option<OfxFields> = None;
+
+    // Each tag's value runs up to the next `<`, whether or not it's closed
+    for segment in input.split('<').skip(1) {
+        let (tag, value) = segment.split_once('>').unwrap_or((segment, ""));
+        let value = unescape(value.trim());
+        match tag.trim().to_ascii_uppercase().as_str() {
+            "CURDEF" => currency = Some(registry::currency(&value)?),
+            "STMTTRN" => current = Some(OfxFields::default()),
+            "/STMTTRN" => {
+                if let Some(fields) = current.take() {
+                    let index = transactions.len();
+                    let currency = currency.ok_or_else(|| OwoError::ParseError {
+                        input: "OFX".to_string(),
+                        reason: "no <CURDEF> before the first transaction".to_string(),
+                    })?;
+                    let transaction =
+                        fields
+                            .finish(currency)
+                            .map_err(|source| OwoError::AtIndex {
+                                index,
+                                source: Box::new(source),
+                            })?;
+                    transactions.push(transaction);
+                }
+            }
+            "DTPOSTED" => with_fields(&mut current, |fields| fields.date = Some(value)),
+            "TRNAMT" => with_fields(&mut current, |fields| fields.amount = Some(value)),
+            "NAME" => with_fields(&mut current, |fields| fields.name = Some(value)),
+            "MEMO" => with_fields(&mut current, |fields| fields.memo = Some(value)),
+            _ => {}
+        }
+    }
+    Ok(transactions)
+}
+
+/// Parses the transactions of a QIF file in `currency`, which QIF doesn't
+/// record
+///
+/// Dates are US-style month/day/year; a two-digit year after an apostrophe
+/// (`1/15'24`) is in the 2000s and after a slash (`1/15/99`) in the 1900s.
+/// Amounts may use thousands separators.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::import::parse_qif;
+/// use cowry::finance::day_count::CalendarDate;
+///
+/// let qif = "!Type:Bank\nD01/15/2024\nT-1,250.00\nPCorner Shop\n^\nD2/1'24\nT3000\nPEmployer\n^\n";
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// let transactions = parse_qif(qif, &usd).unwrap();
+/// assert_eq!(transactions[0].amount, Owo::new(-125000,usd.clone()));
+/// assert_eq!(transactions[1].date, CalendarDate::new(2024, 2, 1));
+/// assert_eq!(transactions[1].payee, "Employer");
+/// ```
+pub fn parse_qif(input: &str, currency: &Currency) -> Result<Vec<BankTransaction>, OwoError> {
+    let mut transactions = Vec::new();
+    let (mut date, mut amount, mut payee) = (None, None, String::new());
+    for line in input.lines().map(str::trim) {
+        let (code, value) = match line.chars().next() {
+            Some(code) => (code, line[code.len_utf8()..].trim()),
+            None => continue,
+        };
+        match code {
+            'D' => date = Some(value.to_string()),
+            'T' | 'U' => amount = Some(value.to_string()),
+            'P' => payee = value.to_string(),
+            '^' => {
+                let index = transactions.len();
+                let transaction = qif_transaction(date.take(), amount.take(), &payee, currency)
+                    .map_err(|source| OwoError::AtIndex {
+                        index,
+                        source: Box::new(source),
+                    })?;
+                transactions.push(transaction);
+                payee.clear();
+            }
+            _ => {}
+        }
+    }
+    Ok(transactions)
+}
+
+// The fields collected from one OFX `<STMTTRN>` block
+#[derive(Default)]
+struct OfxFields {
+    date: Option<String>,
+    amount: Option<String>,
+    name: Option<String>,
+    memo: Option<String>,
+}
+
+impl OfxFields {
+    // Helper building the transaction once the block is closed
+    fn finish(self, currency: Currency) -> Result<BankTransaction, OwoError> {
+        let date = self.date.ok_or_else(|| missing("DTPOSTED"))?;
+        let amount = self.amount.ok_or_else(|| missing("TRNAMT"))?;
+        Ok(BankTransaction {
+            date: parse_ofx_date(&date)?,
+            payee: self.name.or(self.memo).unwrap_or_default(),
+            amount: parse_amount(&amount, currency)?,
+        })
+    }
+}
+
+// Helper applying `f` to the open transaction, ignoring tags outside one
+fn with_fields(current: &mut Option<OfxFields>, f: impl FnOnce(&mut OfxFields)) {
+    if let Some(fields) = current {
+        f(fields);
+    }
+}
+
+// Helper for the error when a required field is absent
+fn missing(field: &str) -> OwoError {
+    OwoError::ParseError {
+        input: String::new(),
+        reason: format!("missing {field}"),
+    }
+}
+
+// Helper decoding the XML entities OFX 2.x may use in text values
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+// Helper parsing an OFX date, `YYYYMMDD` optionally followed by a time and
+// time zone, which are ignored
+fn parse_ofx_date(input: &str) -> Result<CalendarDate, OwoError> {
+    let digits = input
+        .get(..8)
+        .filter(|d| d.bytes().all(|b| b.is_ascii_digit()));
+    let digits = digits.ok_or_else(|| OwoError::ParseError {
+        input: input.to_string(),
+        reason: "expected a YYYYMMDD date".to_string(),
+    })?;
+    checked_date(
+        input,
+        digits[..4].parse().unwrap_or_default(),
+        digits[4..6].parse().unwrap_or_default(),
+        digits[6..].parse().unwrap_or_default(),
+    )
+}
+
+// Helper parsing a QIF record into a transaction
+fn qif_transaction(
+    date: Option<String>,
+    amount: Option<String>,
+    payee: &str,
+    currency: &Currency,
+) -> Result<BankTransaction, OwoError> {
+    let date = date.ok_or_else(|| missing("date (D)"))?;
+    let amount = amount.ok_or_else(|| missing("amount (T)"))?;
+    Ok(BankTransaction {
+        date: parse_qif_date(&date)?,
+        payee: payee.to_string(),
+        amount: parse_amount(&amount.replace(',', ""), *currency)?,
+    })
+}
+
+// Helper parsing a US-style QIF date such as `1/15/2024`, `01/15'24` or
+// `1-15-24`
+fn parse_qif_date(input: &str) -> Result<CalendarDate, OwoError> {
+    let invalid = || OwoError::ParseError {
+        input: input.to_string(),
+        reason: "expected a month/day/year date".to_string(),
+    };
+    let parts: Vec<&str> = input.split(['/', '\'', '-', '.']).map(str::trim).collect();
+    let [month, day, year] = parts[..] else {
+        return Err(invalid());
+    };
+    let number = |part: &str| part.parse::<u32>().map_err(|_| invalid());
+    let (month, day, mut year) = (number(month)?, number(day)?, number(year)? as i32);
+    if year < 100 {
+        year += if input.contains('\'') { 2000 } else { 1900 };
+    }
+    checked_date(input, year, month, day)
+}
+
+// Helper building a date, rejecting months and days that don't exist
+fn checked_date(input: &str, year: i32, month: u32, day: u32) -> Result<CalendarDate, OwoError> {
+    CalendarDate::try_new(year, month, day).map_err(|_| OwoError::ParseError {
+        input: input.to_string(),
+        reason: "no such date".to_string(),
+    })
+}
+
+// Helper parsing a statement amount, accepting a comma decimal separator
+// and a missing leading zero
+fn parse_amount(input: &str, currency: Currency) -> Result<Owo, OwoError> {
+    let mut amount = input.trim().to_string();
+    if !amount.contains('.') {
+        amount = amount.replace(',', ".");
+    }
+    for sign in ["", "-", "+"] {
+        if amount.starts_with(&format!("{sign}.")) {
+            amount.insert(sign.len(), '0');
+            break;
+        }
+    }
+    Owo::from_decimal_str(&amount, currency)
+}
@@ -0,0 +1,8 @@
+//! Conversions between `Owo` and the money representations used by other
+//! systems and protocols.
+
+pub mod fix;
+pub mod google;
+pub mod iso20022;
+pub mod stripe;
+pub mod swift;
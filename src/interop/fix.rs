@@ -0,0 +1,131 @@
+//! FIX protocol price and amount fields, such as `44=1234.56` (Price) or
+//! `152=250000` (CashOrderQty).
+//!
+//! FIX `Price`, `Amt` and `Qty` values are plain decimals: an optional
+//! leading minus, no plus sign, no grouping and no exponent. Each field
+//! carries a maximum number of decimals agreed with the counterparty, so
+//! formatting drops trailing zeros to fit it and fails rather than round.
+
+use crate::error::OwoError;
+use crate::owo::parse_decimal;
+use crate::{Currency, Owo};
+
+/// Formats the amount as a FIX decimal value with at most `max_decimals`
+/// fraction digits, dropping trailing zeros only where needed to fit.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::fix;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let btc = Currency::new("BTC", "₿", 8);
+///
+/// assert_eq!(fix::format_value(&Owo::new(123456,usd.clone()), 4).unwrap(), "1234.56");
+/// assert_eq!(fix::format_value(&Owo::new(-5,usd.clone()), 2).unwrap(), "-0.05");
+/// assert_eq!(fix::format_value(&Owo::new(123400,usd.clone()), 0).unwrap(), "1234");
+/// assert_eq!(fix::format_value(&Owo::new(150000000,btc.clone()), 2).unwrap(), "1.50");
+///
+/// assert!(fix::format_value(&Owo::new(123456,usd), 1).is_err());
+/// ```
+pub fn format_value(owo: &Owo, max_decimals: u8) -> Result<String, OwoError> {
+    let value = owo.to_decimal_string();
+    let excess = owo.currency.precision.saturating_sub(max_decimals) as usize;
+    if excess == 0 {
+        return Ok(value);
+    }
+    let (kept, dropped) = value.split_at(value.len() - excess);
+    if dropped.bytes().any(|b| b != b'0') {
+        return Err(OwoError::ParseError {
+            input: value.clone(),
+            reason: format!("more than {} decimals", max_decimals),
+        });
+    }
+    Ok(kept.trim_end_matches('.').to_string())
+}
+
+/// Formats the amount as a FIX `tag=value` field.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::fix;
+///
+/// let price = Owo::new(10125,Currency::new("USD", "$", 2));
+/// assert_eq!(fix::format_field(44, &price, 4).unwrap(), "44=101.25");
+/// ```
+pub fn format_field(tag: u32, owo: &Owo, max_decimals: u8) -> Result<String, OwoError> {
+    Ok(format!("{}={}", tag, format_value(owo, max_decimals)?))
+}
+
+/// Parses a FIX decimal value in `currency`, accepting trailing zeros past
+/// the currency's precision but no other extra digits.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::fix;
+///
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// assert_eq!(fix::parse_value("101.2500", usd.clone()).unwrap(), Owo::new(10125,usd.clone()));
+/// assert_eq!(fix::parse_value("-3", usd.clone()).unwrap(), Owo::new(-300,usd.clone()));
+///
+/// assert!(fix::parse_value("101.255", usd.clone()).is_err());
+/// assert!(fix::parse_value("+101.25", usd.clone()).is_err());
+/// assert!(fix::parse_value("1,000.00", usd.clone()).is_err());
+/// assert!(fix::parse_value("1e3", usd).is_err());
+/// ```
+pub fn parse_value(value: &str, currency: Currency) -> Result<Owo, OwoError> {
+    if value.starts_with('+') {
+        return Err(OwoError::ParseError {
+            input: value.to_string(),
+            reason: "FIX values have no plus sign".to_string(),
+        });
+    }
+    let trimmed = match value.split_once('.') {
+        Some((whole, fraction)) if fraction.len() > currency.precision as usize => {
+            let kept = fraction.trim_end_matches('0');
+            let kept = &fraction[..kept.len().max(currency.precision as usize)];
+            if kept.is_empty() {
+                whole.to_string()
+            } else {
+                format!("{}.{}", whole, kept)
+            }
+        }
+        _ => value.to_string(),
+    };
+    let amount = parse_decimal(&trimmed, currency.precision).map_err(|_| OwoError::ParseError {
+        input: value.to_string(),
+        reason: format!(
+            "expected a FIX decimal with at most {} decimals",
+            currency.precision
+        ),
+    })?;
+    Ok(Owo::new(amount, currency))
+}
+
+/// Parses a FIX `tag=value` field, returning the tag and the amount.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::fix;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let (tag, price) = fix::parse_field("44=101.25", usd.clone()).unwrap();
+/// assert_eq!((tag, price), (44, Owo::new(10125,usd.clone())));
+///
+/// assert!(fix::parse_field("101.25", usd).is_err());
+/// ```
+pub fn parse_field(field: &str, currency: Currency) -> Result<(u32, Owo), OwoError> {
+    let parse_error = |reason: &str| OwoError::ParseError {
+        input: field.to_string(),
+        reason: reason.to_string(),
+    };
+    let (tag, value) = field
+        .split_once('=')
+        .ok_or_else(|| parse_error("expected tag=value"))?;
+    let tag = tag.parse().map_err(|_| parse_error("invalid tag number"))?;
+    Ok((tag, parse_value(value, currency)?))
+}
@@ -0,0 +1,125 @@
+//! Interop with the protobuf `google.type.Money` message.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo, registry};
+use serde::{Deserialize, Serialize};
+
+const NANOS_PER_UNIT: i64 = 1_000_000_000;
+
+/// The shape of `google.type.Money`: whole `units` plus `nanos` (billionths
+/// of a unit), both carrying the sign of the amount.
+///
+/// Copy the fields to and from your generated protobuf type at the service
+/// boundary.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Money {
+    pub currency_code: String,
+    pub units: i64,
+    pub nanos: i32,
+}
+
+impl Money {
+    /// Converts an `Owo` into `google.type.Money`.
+    ///
+    /// Fails for currencies with more than 9 decimal places, which `nanos`
+    /// cannot represent.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::interop::google::Money;
+    ///
+    /// let usd = Money::from_owo(&Owo::new(-1250, Currency::new("USD", "$", 2))).unwrap();
+    /// assert_eq!((usd.units, usd.nanos), (-12, -500_000_000));
+    ///
+    /// let kwd = Money::from_owo(&Owo::new(1005, Currency::new("KWD", "KD", 3))).unwrap();
+    /// assert_eq!((kwd.units, kwd.nanos), (1, 5_000_000));
+    ///
+    /// assert!(Money::from_owo(&Owo::new(1, Currency::new("ETH", "Ξ", 18))).is_err());
+    /// ```
+    pub fn from_owo(owo: &Owo) -> Result<Money, OwoError> {
+        let nanos_per_minor = nanos_per_minor(&owo.currency)?;
+        let divisor = NANOS_PER_UNIT / nanos_per_minor;
+        Ok(Money {
+            currency_code: owo.currency.code.to_string(),
+            units: owo.amount / divisor,
+            nanos: ((owo.amount % divisor) * nanos_per_minor) as i32,
+        })
+    }
+
+    /// Converts into an `Owo`, resolving the currency from the ISO 4217
+    /// registry.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::interop::google::Money;
+    ///
+    /// let money = Money { currency_code: "JPY".to_string(), units: 1200, nanos: 0 };
+    /// assert_eq!(money.to_owo().unwrap().format(), "¥1200");
+    /// ```
+    pub fn to_owo(&self) -> Result<Owo, OwoError> {
+        self.to_owo_with_currency(registry::currency(&self.currency_code)?)
+    }
+
+    /// Converts into an `Owo` of the given currency, failing if the currency
+    /// code doesn't match, the signs of `units` and `nanos` disagree, or
+    /// `nanos` holds more precision than the currency allows.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::interop::google::Money;
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// let money = Money { currency_code: "USD".to_string(), units: -12, nanos: -500_000_000 };
+    /// assert_eq!(money.to_owo_with_currency(usd.clone()).unwrap().get_amount(), -1250);
+    ///
+    /// let sub_cent = Money { currency_code: "USD".to_string(), units: 1, nanos: 5_000_000 };
+    /// assert!(sub_cent.to_owo_with_currency(usd.clone()).is_err());
+    ///
+    /// let mixed_sign = Money { currency_code: "USD".to_string(), units: 1, nanos: -500_000_000 };
+    /// assert!(mixed_sign.to_owo_with_currency(usd).is_err());
+    /// ```
+    pub fn to_owo_with_currency(&self, currency: Currency) -> Result<Owo, OwoError> {
+        if !self.currency_code.eq_ignore_ascii_case(currency.code) {
+            return Err(OwoError::CurrencyMismatch(
+                self.currency_code.clone(),
+                currency.code.to_string(),
+            ));
+        }
+        let invalid = |reason: &str| OwoError::ParseError {
+            input: format!("{:?}", self),
+            reason: reason.to_string(),
+        };
+        let nanos = self.nanos as i64;
+        if nanos.abs() >= NANOS_PER_UNIT {
+            return Err(invalid(
+                "nanos must be between -999,999,999 and +999,999,999",
+            ));
+        }
+        if (self.units > 0 && nanos < 0) || (self.units < 0 && nanos > 0) {
+            return Err(invalid("units and nanos must have the same sign"));
+        }
+        let nanos_per_minor = nanos_per_minor(&currency)?;
+        if nanos % nanos_per_minor != 0 {
+            return Err(invalid("nanos exceed the currency precision"));
+        }
+
+        let minor = self
+            .units
+            .checked_mul(NANOS_PER_UNIT / nanos_per_minor)
+            .and_then(|whole| whole.checked_add(nanos / nanos_per_minor))
+            .ok_or_else(|| invalid("amount out of range"))?;
+        Ok(Owo::new(minor, currency))
+    }
+}
+
+// Helper returning how many nanos make up one minor unit of `currency`
+fn nanos_per_minor(currency: &Currency) -> Result<i64, OwoError> {
+    if currency.precision > 9 {
+        return Err(OwoError::InvalidPrecision(currency.precision));
+    }
+    Ok(10i64.pow(9 - currency.precision as u32))
+}
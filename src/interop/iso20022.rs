@@ -0,0 +1,115 @@
+//! ISO 20022 amounts, as used in payment files such as pain.001 and camt.053:
+//! `<InstdAmt Ccy="EUR">1234.56</InstdAmt>`.
+//!
+//! Amounts are non-negative, use a dot as the decimal separator, have at most
+//! 18 digits in total, and carry no more fraction digits than the currency
+//! allows.
+
+use crate::error::OwoError;
+use crate::owo::parse_decimal;
+use crate::{Owo, registry};
+
+const MAX_TOTAL_DIGITS: usize = 18;
+
+/// Formats the amount as an ISO 20022 decimal value, e.g. `"1234.56"`.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::iso20022;
+///
+/// let eur = Currency::new("EUR", "€", 2);
+/// assert_eq!(iso20022::format_amount(&Owo::new(123456, eur.clone())).unwrap(), "1234.56");
+/// assert!(iso20022::format_amount(&Owo::new(-100, eur)).is_err());
+/// ```
+pub fn format_amount(owo: &Owo) -> Result<String, OwoError> {
+    let value = owo.to_decimal_string();
+    validate(&value)?;
+    Ok(value)
+}
+
+/// Formats the amount as an ISO 20022 element with a `Ccy` attribute.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::iso20022;
+///
+/// let owo = Owo::new(123456, Currency::new("EUR", "€", 2));
+/// assert_eq!(
+///     iso20022::format_element("InstdAmt", &owo).unwrap(),
+///     r#"<InstdAmt Ccy="EUR">1234.56</InstdAmt>"#
+/// );
+/// ```
+pub fn format_element(tag: &str, owo: &Owo) -> Result<String, OwoError> {
+    Ok(format!(
+        r#"<{tag} Ccy="{}">{}</{tag}>"#,
+        owo.currency.code,
+        format_amount(owo)?
+    ))
+}
+
+/// Parses an ISO 20022 amount value in the currency named by `ccy`.
+///
+/// #Example
+/// ```
+/// use cowry::interop::iso20022;
+///
+/// assert_eq!(iso20022::parse_amount("EUR", "1234.5").unwrap().get_amount(), 123450);
+/// assert!(iso20022::parse_amount("JPY", "1234.5").is_err());
+/// assert!(iso20022::parse_amount("EUR", "-1").is_err());
+/// ```
+pub fn parse_amount(ccy: &str, value: &str) -> Result<Owo, OwoError> {
+    validate(value)?;
+    let currency = registry::currency(ccy)?;
+    let amount = parse_decimal(value, currency.precision)?;
+    Ok(Owo::new(amount, currency))
+}
+
+/// Parses an ISO 20022 amount element such as
+/// `<InstdAmt Ccy="EUR">1234.56</InstdAmt>`.
+///
+/// #Example
+/// ```
+/// use cowry::interop::iso20022;
+///
+/// let owo = iso20022::parse_element(r#"<InstdAmt Ccy="EUR">1234.56</InstdAmt>"#).unwrap();
+/// assert_eq!(owo.get_amount(), 123456);
+/// assert_eq!(owo.get_currency(), "EUR");
+///
+/// assert!(iso20022::parse_element("<InstdAmt>1234.56</InstdAmt>").is_err());
+/// ```
+pub fn parse_element(element: &str) -> Result<Owo, OwoError> {
+    let parse_error = |reason: &str| OwoError::ParseError {
+        input: element.to_string(),
+        reason: reason.to_string(),
+    };
+    let (_, rest) = element
+        .split_once("Ccy=\"")
+        .ok_or_else(|| parse_error("missing Ccy attribute"))?;
+    let (ccy, rest) = rest
+        .split_once('"')
+        .ok_or_else(|| parse_error("unterminated Ccy attribute"))?;
+    let (_, rest) = rest
+        .split_once('>')
+        .ok_or_else(|| parse_error("unterminated start tag"))?;
+    let (value, _) = rest
+        .split_once('<')
+        .ok_or_else(|| parse_error("missing end tag"))?;
+    parse_amount(ccy, value.trim())
+}
+
+// Helper checking the sign and total digit limits of an amount value
+fn validate(value: &str) -> Result<(), OwoError> {
+    let parse_error = |reason: &str| OwoError::ParseError {
+        input: value.to_string(),
+        reason: reason.to_string(),
+    };
+    if value.starts_with(['-', '+']) {
+        return Err(parse_error("amounts must be unsigned and non-negative"));
+    }
+    if value.bytes().filter(u8::is_ascii_digit).count() > MAX_TOTAL_DIGITS {
+        return Err(parse_error("more than 18 digits"));
+    }
+    Ok(())
+}
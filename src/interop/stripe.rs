@@ -0,0 +1,141 @@
+//! Interop with Stripe amounts: integer minor units and lowercase currency
+//! codes, scaled the way Stripe's API expects.
+//!
+//! Stripe's minor units don't always match ISO 4217. Zero-decimal
+//! currencies such as JPY and KRW are sent as whole units, three-decimal
+//! currencies such as KWD must end in a zero, and ISK, zero-decimal in ISO
+//! 4217, is sent with two decimals. [`StripeAmount`] handles the scaling so
+//! a ¥500 charge is never sent as ¥50,000.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo, registry};
+use serde::{Deserialize, Serialize};
+
+/// Currencies Stripe sends without minor units.
+pub const ZERO_DECIMAL_CURRENCIES: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "JPY", "KMF", "KRW", "MGA", "PYG", "RWF", "UGX", "VND", "VUV",
+    "XAF", "XOF", "XPF",
+];
+
+/// Currencies Stripe sends with three decimals, always ending in a zero.
+pub const THREE_DECIMAL_CURRENCIES: &[&str] = &["BHD", "JOD", "KWD", "OMR", "TND"];
+
+/// The `amount` and `currency` fields of a Stripe payload.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StripeAmount {
+    /// The amount in Stripe's minor units for the currency.
+    pub amount: i64,
+    /// The lowercase ISO 4217 code, e.g. `"usd"`.
+    pub currency: String,
+}
+
+/// Returns the number of decimals Stripe uses for `code`, in any case.
+///
+/// #Example
+/// ```
+/// use cowry::interop::stripe::decimals;
+///
+/// assert_eq!(decimals("usd"), 2);
+/// assert_eq!(decimals("JPY"), 0);
+/// assert_eq!(decimals("kwd"), 3);
+/// assert_eq!(decimals("isk"), 2);
+/// ```
+pub fn decimals(code: &str) -> u8 {
+    let listed = |list: &[&str]| list.iter().any(|c| c.eq_ignore_ascii_case(code));
+    if listed(ZERO_DECIMAL_CURRENCIES) {
+        0
+    } else if listed(THREE_DECIMAL_CURRENCIES) {
+        3
+    } else {
+        2
+    }
+}
+
+impl StripeAmount {
+    /// Converts an `Owo` into a Stripe amount
+    ///
+    /// Fails if the amount has more precision than Stripe accepts for the
+    /// currency, including a non-zero last digit for three-decimal
+    /// currencies.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::interop::stripe::StripeAmount;
+    ///
+    /// let usd = StripeAmount::from_owo(&Owo::new(1999, Currency::new("USD", "$", 2))).unwrap();
+    /// assert_eq!((usd.amount, usd.currency.as_str()), (1999, "usd"));
+    ///
+    /// let jpy = StripeAmount::from_owo(&Owo::new(500, Currency::new("JPY", "¥", 0))).unwrap();
+    /// assert_eq!(jpy.amount, 500);
+    ///
+    /// let isk = StripeAmount::from_owo(&Owo::new(1500, Currency::new("ISK", "kr", 0))).unwrap();
+    /// assert_eq!(isk.amount, 150000);
+    ///
+    /// assert!(StripeAmount::from_owo(&Owo::new(1005, Currency::new("KWD", "KD", 3))).is_err());
+    /// ```
+    pub fn from_owo(owo: &Owo) -> Result<StripeAmount, OwoError> {
+        let target = decimals(owo.currency.code);
+        let mut amount = rescale(owo.amount, owo.currency.precision, target)?;
+        if target == 3 {
+            amount = rescale(rescale(amount, 3, 2)?, 2, 3)?;
+        }
+        Ok(StripeAmount {
+            amount,
+            currency: owo.currency.code.to_ascii_lowercase(),
+        })
+    }
+
+    /// Converts into an `Owo`, resolving the currency from the ISO 4217
+    /// registry
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::interop::stripe::StripeAmount;
+    ///
+    /// let payload: StripeAmount = serde_json::from_str(r#"{"amount":500,"currency":"jpy"}"#).unwrap();
+    /// assert_eq!(payload.to_owo().unwrap().format(), "¥500");
+    ///
+    /// let krw = StripeAmount { amount: 15000, currency: "krw".to_string() };
+    /// assert_eq!(krw.to_owo().unwrap().get_amount(), 15000);
+    /// ```
+    pub fn to_owo(&self) -> Result<Owo, OwoError> {
+        self.to_owo_with_currency(registry::currency(&self.currency)?)
+    }
+
+    /// Converts into an `Owo` of the given currency, failing if the codes
+    /// don't match or the amount can't be represented exactly.
+    pub fn to_owo_with_currency(&self, currency: Currency) -> Result<Owo, OwoError> {
+        if !self.currency.eq_ignore_ascii_case(currency.code) {
+            return Err(OwoError::CurrencyMismatch(
+                self.currency.clone(),
+                currency.code.to_string(),
+            ));
+        }
+        let amount = rescale(self.amount, decimals(&self.currency), currency.precision)?;
+        Ok(Owo::new(amount, currency))
+    }
+}
+
+// Helper moving `amount` from `from` decimals to `to` decimals, failing
+// rather than dropping digits or overflowing
+fn rescale(amount: i64, from: u8, to: u8) -> Result<i64, OwoError> {
+    let out_of_range = || OwoError::ParseError {
+        input: amount.to_string(),
+        reason: format!("cannot be expressed exactly with {to} decimals"),
+    };
+    if to >= from {
+        10i64
+            .checked_pow(u32::from(to - from))
+            .and_then(|factor| amount.checked_mul(factor))
+            .ok_or_else(out_of_range)
+    } else {
+        let factor = 10i64
+            .checked_pow(u32::from(from - to))
+            .ok_or_else(out_of_range)?;
+        if amount % factor != 0 {
+            return Err(out_of_range());
+        }
+        Ok(amount / factor)
+    }
+}
@@ -0,0 +1,117 @@
+//! SWIFT MT amounts, as used in fields such as `:32A:` of an MT103 or
+//! `:60F:` of an MT940: `USD1234,56`.
+//!
+//! Amounts are non-negative, use a comma as the decimal separator (which is
+//! always present, even with no fraction digits), have no thousands
+//! separators, and are at most 15 characters including the comma.
+
+use crate::error::OwoError;
+use crate::owo::parse_decimal;
+use crate::{Owo, registry};
+
+/// The maximum length of an MT amount, including the decimal comma.
+pub const MAX_AMOUNT_LENGTH: usize = 15;
+
+/// Formats the amount as an MT amount, e.g. `"1234,56"` or `"500,"`.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::swift;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let jpy = Currency::new("JPY", "¥", 0);
+///
+/// assert_eq!(swift::format_amount(&Owo::new(123456,usd.clone())).unwrap(), "1234,56");
+/// assert_eq!(swift::format_amount(&Owo::new(500,jpy)).unwrap(), "500,");
+///
+/// assert!(swift::format_amount(&Owo::new(-100,usd.clone())).is_err());
+/// assert!(swift::format_amount(&Owo::new(i64::MAX,usd)).is_err());
+/// ```
+pub fn format_amount(owo: &Owo) -> Result<String, OwoError> {
+    let decimal = owo.to_decimal_string();
+    let value = match decimal.split_once('.') {
+        Some((whole, fraction)) => format!("{},{}", whole, fraction),
+        None => format!("{},", decimal),
+    };
+    validate(&value)?;
+    Ok(value)
+}
+
+/// Formats the amount prefixed with its currency code, e.g. `"USD1234,56"`.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::interop::swift;
+///
+/// let owo = Owo::new(123456,Currency::new("USD", "$", 2));
+/// assert_eq!(swift::format_currency_amount(&owo).unwrap(), "USD1234,56");
+/// ```
+pub fn format_currency_amount(owo: &Owo) -> Result<String, OwoError> {
+    Ok(format!("{}{}", owo.currency.code, format_amount(owo)?))
+}
+
+/// Parses an MT amount in the currency named by `ccy`.
+///
+/// #Example
+/// ```
+/// use cowry::interop::swift;
+///
+/// assert_eq!(swift::parse_amount("EUR", "1234,5").unwrap().get_amount(), 123450);
+/// assert_eq!(swift::parse_amount("EUR", "1234,").unwrap().get_amount(), 123400);
+///
+/// assert!(swift::parse_amount("EUR", "1234.50").is_err());
+/// assert!(swift::parse_amount("EUR", "1.234,50").is_err());
+/// assert!(swift::parse_amount("JPY", "1234,5").is_err());
+/// assert!(swift::parse_amount("EUR", "-1,").is_err());
+/// ```
+pub fn parse_amount(ccy: &str, value: &str) -> Result<Owo, OwoError> {
+    validate(value)?;
+    let currency = registry::currency(ccy)?;
+    let decimal = value.trim_end_matches(',').replacen(',', ".", 1);
+    let amount = parse_decimal(&decimal, currency.precision)?;
+    Ok(Owo::new(amount, currency))
+}
+
+/// Parses a currency code followed by an MT amount, e.g. `"USD1234,56"`.
+///
+/// #Example
+/// ```
+/// use cowry::interop::swift;
+///
+/// let owo = swift::parse_currency_amount("USD1234,56").unwrap();
+/// assert_eq!(owo.get_amount(), 123456);
+/// assert_eq!(owo.get_currency(), "USD");
+///
+/// assert!(swift::parse_currency_amount("US1234,56").is_err());
+/// ```
+pub fn parse_currency_amount(input: &str) -> Result<Owo, OwoError> {
+    match input.get(..3) {
+        Some(ccy) if ccy.bytes().all(|b| b.is_ascii_uppercase()) => parse_amount(ccy, &input[3..]),
+        _ => Err(OwoError::ParseError {
+            input: input.to_string(),
+            reason: "expected a three-letter currency code".to_string(),
+        }),
+    }
+}
+
+// Helper checking the characters, decimal comma and length of an MT amount
+fn validate(value: &str) -> Result<(), OwoError> {
+    let parse_error = |reason: &str| OwoError::ParseError {
+        input: value.to_string(),
+        reason: reason.to_string(),
+    };
+    if !value.bytes().all(|b| b.is_ascii_digit() || b == b',') {
+        return Err(parse_error("amounts must be digits and a decimal comma"));
+    }
+    if value.bytes().filter(|&b| b == b',').count() != 1 || value.starts_with(',') {
+        return Err(parse_error(
+            "expected exactly one decimal comma after the whole part",
+        ));
+    }
+    if value.len() > MAX_AMOUNT_LENGTH {
+        return Err(parse_error("more than 15 characters"));
+    }
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Interpolation between amounts, for dynamic pricing and vesting
+//! schedules.
+//!
+//! Every evaluation rounds exactly once, from the unrounded interpolated
+//! value to the currency's precision with the caller's `RoundingMode`.
+
+use crate::error::OwoError;
+use crate::finance::owo_from_minor;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+impl Owo {
+    /// Returns the amount a fraction `t` of the way from `a` to `b`,
+    /// rounded once with `mode`
+    ///
+    /// `t` of `0.0` gives `a` and `1.0` gives `b`; values outside that range
+    /// extrapolate.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let early = Owo::new(1000,usd.clone());
+    /// let late = Owo::new(2500,usd.clone());
+    ///
+    /// assert_eq!(Owo::lerp(&early, &late, 0.5, RoundingMode::Nearest).unwrap(), Owo::new(1750,usd.clone()));
+    /// assert_eq!(Owo::lerp(&early, &late, 1.0 / 3.0, RoundingMode::Floor).unwrap(), Owo::new(1500,usd.clone()));
+    /// assert!(Owo::lerp(&early, &late, f64::NAN, RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn lerp(a: &Owo, b: &Owo, t: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        a.check_currency(b)?;
+        if !t.is_finite() {
+            return Err(OwoError::InvalidScalar(t));
+        }
+        let minor = a.amount as f64 + (b.amount as f64 - a.amount as f64) * t;
+        owo_from_minor(a, minor, mode, "lerp")
+    }
+}
+
+/// How a [`Curve`] fills the gaps between its points.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Hold each point's amount until the next point.
+    Step,
+    /// Move in a straight line from each point to the next.
+    #[default]
+    Linear,
+}
+
+/// Amounts at points along an axis such as days, months or units sold,
+/// evaluated anywhere along it.
+///
+/// Before the first point the curve holds the first amount, and after the
+/// last point it holds the last.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Curve {
+    points: Vec<(f64, Owo)>,
+    interpolation: Interpolation,
+}
+
+impl Curve {
+    /// Creates a curve from `(position, amount)` points in any order,
+    /// requiring at least one point, finite distinct positions and a single
+    /// currency.
+    pub fn new(points: &[(f64, Owo)], interpolation: Interpolation) -> Result<Self, OwoError> {
+        let first = points.first().ok_or(OwoError::EmptyCollection)?;
+        for (position, amount) in points {
+            if !position.is_finite() {
+                return Err(OwoError::InvalidScalar(*position));
+            }
+            first.1.check_currency(amount)?;
+        }
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some(pair) = points.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(OwoError::InvalidRange(format!(
+                "two points at {}",
+                pair[0].0
+            )));
+        }
+        Ok(Self {
+            points,
+            interpolation,
+        })
+    }
+
+    pub fn points(&self) -> &[(f64, Owo)] {
+        &self.points
+    }
+
+    /// Returns the amount at `position`, rounding once with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::interpolate::{Curve, Interpolation};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// // 4-year vesting with a 1-year cliff, in months
+    /// let points = [
+    ///     (0.0, Owo::new(0,ngn.clone())),
+    ///     (12.0, Owo::new(2500000,ngn.clone())),
+    ///     (48.0, Owo::new(10000000,ngn.clone())),
+    /// ];
+    ///
+    /// let step = Curve::new(&points, Interpolation::Step).unwrap();
+    /// assert_eq!(step.at(11.0, RoundingMode::Floor).unwrap(), Owo::new(0,ngn.clone()));
+    /// assert_eq!(step.at(30.0, RoundingMode::Floor).unwrap(), Owo::new(2500000,ngn.clone()));
+    ///
+    /// let linear = Curve::new(&points, Interpolation::Linear).unwrap();
+    /// assert_eq!(linear.at(30.0, RoundingMode::Floor).unwrap(), Owo::new(6250000,ngn.clone()));
+    /// assert_eq!(linear.at(60.0, RoundingMode::Floor).unwrap(), Owo::new(10000000,ngn.clone()));
+    /// ```
+    pub fn at(&self, position: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        if !position.is_finite() {
+            return Err(OwoError::InvalidScalar(position));
+        }
+        let after = self.points.partition_point(|(x, _)| *x <= position);
+        let (x0, a) = match after {
+            0 => return Ok(self.points[0].1),
+            index => self.points[index - 1],
+        };
+        match (self.interpolation, self.points.get(after)) {
+            (Interpolation::Linear, Some((x1, b))) => {
+                Owo::lerp(&a, b, (position - x0) / (x1 - x0), mode)
+            }
+            _ => Ok(a),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Invoices built from line items with per-line discounts and tax rates.
+//!
+//! Each line's amount is `unit_price * quantity`, rounded with the invoice's
+//! rounding mode, and any discount comes off that amount before tax. Tax is
+//! then rounded according to [`TaxRounding`]:
+//!
+//! - `PerLine` rounds the tax on every line and adds the rounded values, as
+//!   most point-of-sale systems do.
+//! - `PerInvoice` adds the unrounded tax of every line sharing a rate and
+//!   rounds once per rate, as many VAT regimes require. The rounded tax is
+//!   then apportioned back to the lines.
+//!
+//! Either way the line taxes sum exactly to the invoice tax, and
+//! `subtotal + tax == total`.
+
+use crate::discount::Discount;
+use crate::error::OwoError;
+use crate::finance::owo_from_minor;
+use crate::tax::apportion;
+use crate::{Currency, Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// When tax is rounded on an [`Invoice`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxRounding {
+    /// Round each line's tax, then add them up.
+    #[default]
+    PerLine,
+    /// Add up the unrounded tax per rate, then round each rate's total.
+    PerInvoice,
+}
+
+/// One line on an invoice.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LineItem {
+    pub description: String,
+    pub unit_price: Owo,
+    /// The number of units, which may be fractional such as hours worked.
+    pub quantity: f64,
+    /// The tax rate in percent, `0.0` for untaxed lines.
+    pub tax: f64,
+    pub discount: Option<Discount>,
+}
+
+impl LineItem {
+    /// Creates an untaxed, undiscounted line.
+    pub fn new(description: &str, unit_price: Owo, quantity: f64) -> Self {
+        Self {
+            description: description.to_string(),
+            unit_price,
+            quantity,
+            tax: 0.0,
+            discount: None,
+        }
+    }
+}
+
+/// The computed amounts for one line.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LineTotal {
+    /// `unit_price * quantity`, rounded.
+    pub amount: Owo,
+    pub discount: Owo,
+    /// `amount - discount`, the taxable amount.
+    pub net: Owo,
+    pub tax: Owo,
+}
+
+/// The computed amounts for a whole invoice.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InvoiceTotals {
+    /// One entry per line, in order.
+    pub lines: Vec<LineTotal>,
+    /// The total discount across all lines.
+    pub discount: Owo,
+    /// The sum of every line's net amount.
+    pub subtotal: Owo,
+    /// The sum of every line's tax.
+    pub tax: Owo,
+    /// `subtotal + tax`.
+    pub total: Owo,
+}
+
+/// A list of line items in one currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Invoice {
+    pub currency: Currency,
+    pub lines: Vec<LineItem>,
+    pub tax_rounding: TaxRounding,
+    pub mode: RoundingMode,
+}
+
+impl Invoice {
+    /// Creates an empty invoice in `currency`.
+    pub fn new(currency: Currency, tax_rounding: TaxRounding, mode: RoundingMode) -> Self {
+        Self {
+            currency,
+            lines: Vec::new(),
+            tax_rounding,
+            mode,
+        }
+    }
+
+    /// Appends a line, rejecting lines in another currency.
+    pub fn add_line(&mut self, line: LineItem) -> Result<(), OwoError> {
+        if line.unit_price.currency != self.currency {
+            return Err(OwoError::CurrencyMismatch(
+                self.currency.code.to_string(),
+                line.unit_price.currency.code.to_string(),
+            ));
+        }
+        self.lines.push(line);
+        Ok(())
+    }
+
+    /// Computes the line, subtotal, tax and grand totals
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::discount::Discount;
+    /// use cowry::invoice::{Invoice, LineItem, TaxRounding};
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut line = LineItem::new("Sticker", Owo::new(15,usd.clone()), 1.0);
+    /// line.tax = 10.0;
+    ///
+    /// let mut per_line = Invoice::new(usd.clone(), TaxRounding::PerLine, RoundingMode::Nearest);
+    /// let mut per_invoice = Invoice::new(usd.clone(), TaxRounding::PerInvoice, RoundingMode::Nearest);
+    /// for _ in 0..3 {
+    ///     per_line.add_line(line.clone()).unwrap();
+    ///     per_invoice.add_line(line.clone()).unwrap();
+    /// }
+    ///
+    /// // 10% of 15¢ is 1.5¢: 2¢ a line, or 4.5¢ ≈ 5¢ overall
+    /// assert_eq!(per_line.totals().unwrap().tax, Owo::new(6,usd.clone()));
+    /// assert_eq!(per_invoice.totals().unwrap().tax, Owo::new(5,usd.clone()));
+    ///
+    /// let lines = per_invoice.totals().unwrap().lines;
+    /// assert_eq!(lines.iter().map(|line| line.tax.amount).collect::<Vec<_>>(), vec![2, 2, 1]);
+    ///
+    /// let mut hours = LineItem::new("Consulting", Owo::new(12000,usd.clone()), 2.5);
+    /// hours.discount = Some(Discount::Percentage(10.0));
+    /// per_line.add_line(hours).unwrap();
+    ///
+    /// let totals = per_line.totals().unwrap();
+    /// assert_eq!(totals.lines[3].net, Owo::new(27000,usd.clone()));
+    /// assert_eq!(totals.subtotal, Owo::new(27045,usd.clone()));
+    /// assert_eq!(totals.total, Owo::new(27051,usd.clone()));
+    /// ```
+    pub fn totals(&self) -> Result<InvoiceTotals, OwoError> {
+        let mut amounts = Vec::with_capacity(self.lines.len());
+        for line in &self.lines {
+            let amount = line
+                .unit_price
+                .multiply_with_mode(line.quantity, self.mode)?;
+            let discount = match &line.discount {
+                Some(discount) => discount.amount_off(&amount, self.mode)?,
+                None => Owo::new(0, self.currency),
+            };
+            let net = amount.checked_sub(&discount)?;
+            amounts.push((amount, discount, net));
+        }
+
+        let taxes = match self.tax_rounding {
+            TaxRounding::PerLine => self
+                .lines
+                .iter()
+                .zip(&amounts)
+                .map(|(line, (_, _, net))| net.percentage_with_mode(line.tax, self.mode))
+                .collect::<Result<Vec<_>, _>>()?,
+            TaxRounding::PerInvoice => self.taxes_per_rate(&amounts)?,
+        };
+
+        let zero = Owo::new(0, self.currency);
+        let (mut discount, mut subtotal, mut tax) = (zero, zero, zero);
+        let mut lines = Vec::with_capacity(amounts.len());
+        for ((amount, line_discount, net), line_tax) in amounts.into_iter().zip(taxes) {
+            discount = discount.checked_add(&line_discount)?;
+            subtotal = subtotal.checked_add(&net)?;
+            tax = tax.checked_add(&line_tax)?;
+            lines.push(LineTotal {
+                amount,
+                discount: line_discount,
+                net,
+                tax: line_tax,
+            });
+        }
+        let total = subtotal.checked_add(&tax)?;
+        Ok(InvoiceTotals {
+            lines,
+            discount,
+            subtotal,
+            tax,
+            total,
+        })
+    }
+
+    // Helper rounding tax once per distinct rate and apportioning it to the lines
+    fn taxes_per_rate(&self, amounts: &[(Owo, Owo, Owo)]) -> Result<Vec<Owo>, OwoError> {
+        let mut taxes = vec![Owo::new(0, self.currency); self.lines.len()];
+        let mut rates: Vec<f64> = self.lines.iter().map(|line| line.tax).collect();
+        rates.sort_by(f64::total_cmp);
+        rates.dedup();
+        for rate in rates {
+            if !rate.is_finite() {
+                return Err(OwoError::InvalidScalar(rate));
+            }
+            let indices: Vec<usize> = (0..self.lines.len())
+                .filter(|&index| self.lines[index].tax == rate)
+                .collect();
+            let raws: Vec<f64> = indices
+                .iter()
+                .map(|&index| amounts[index].2.amount as f64 * rate / 100.0)
+                .collect();
+            let like = Owo::new(0, self.currency);
+            let total = owo_from_minor(&like, raws.iter().sum(), self.mode, "tax")?;
+            for (index, amount) in indices.into_iter().zip(apportion(total.amount, &raws)) {
+                taxes[index] = Owo::new(amount, self.currency);
+            }
+        }
+        Ok(taxes)
+    }
+}
@@ -0,0 +1,530 @@
+//! Double-entry bookkeeping: accounts, balanced journal entries and a ledger
+//! that posts them.
+//!
+//! Postings are signed, with debits positive and credits negative, so a
+//! balanced entry's postings sum to zero in every currency it touches.
+
+use crate::error::OwoError;
+use crate::non_negative::NonNegativeOwo;
+use crate::stats::totals_by_currency;
+use crate::{Currency, Owo};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The side of an account a posting lands on.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Debit,
+    Credit,
+}
+
+/// A debit of a non-negative amount, so the side is carried by the type
+/// rather than the sign.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::ledger::{Credit, Debit};
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let debit = Debit::new(Owo::new(5000,ngn.clone())).unwrap();
+/// let credit = Credit::new(Owo::new(5000,ngn.clone())).unwrap();
+///
+/// assert_eq!(debit.signed(), Owo::new(5000,ngn.clone()));
+/// assert_eq!(credit.signed(), Owo::new(-5000,ngn.clone()));
+///
+/// assert!(Credit::new(Owo::new(-5000,ngn)).is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct Debit(NonNegativeOwo);
+
+/// A credit of a non-negative amount, so the side is carried by the type
+/// rather than the sign.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[serde(transparent)]
+pub struct Credit(NonNegativeOwo);
+
+impl Debit {
+    /// Creates a debit, rejecting negative amounts.
+    pub fn new(amount: Owo) -> Result<Self, OwoError> {
+        Ok(Self(NonNegativeOwo::new(amount)?))
+    }
+
+    /// Returns the amount, always zero or more.
+    pub fn amount(&self) -> &Owo {
+        self.0.as_owo()
+    }
+
+    /// Returns the amount with the ledger's sign convention, positive.
+    pub fn signed(&self) -> Owo {
+        *self.0.as_owo()
+    }
+}
+
+impl Credit {
+    /// Creates a credit, rejecting negative amounts.
+    pub fn new(amount: Owo) -> Result<Self, OwoError> {
+        Ok(Self(NonNegativeOwo::new(amount)?))
+    }
+
+    /// Returns the amount, always zero or more.
+    pub fn amount(&self) -> &Owo {
+        self.0.as_owo()
+    }
+
+    /// Returns the amount with the ledger's sign convention, negative.
+    pub fn signed(&self) -> Owo {
+        -*self.0.as_owo()
+    }
+}
+
+/// The accounting classification of an account, which decides whether
+/// debits or credits increase its balance.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccountKind {
+    Asset,
+    Liability,
+    Equity,
+    Income,
+    Expense,
+}
+
+impl AccountKind {
+    /// Returns the side that increases this kind of account: debits for
+    /// assets and expenses, credits for liabilities, equity and income
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::ledger::{AccountKind, Side};
+    ///
+    /// assert_eq!(AccountKind::Asset.normal_side(), Side::Debit);
+    /// assert_eq!(AccountKind::Income.normal_side(), Side::Credit);
+    /// ```
+    pub fn normal_side(self) -> Side {
+        match self {
+            AccountKind::Asset | AccountKind::Expense => Side::Debit,
+            AccountKind::Liability | AccountKind::Equity | AccountKind::Income => Side::Credit,
+        }
+    }
+}
+
+/// An account that entries post to.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Account {
+    /// The unique code entries refer to, such as `"1000"` or `"cash"`.
+    pub code: String,
+    pub name: String,
+    pub kind: AccountKind,
+}
+
+/// One side of a journal entry.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Posting {
+    pub account: String,
+    /// Positive for a debit, negative for a credit.
+    pub amount: Owo,
+}
+
+impl Posting {
+    /// Returns the side the posting lands on, treating zero as a debit.
+    pub fn side(&self) -> Side {
+        if self.amount.amount < 0 {
+            Side::Credit
+        } else {
+            Side::Debit
+        }
+    }
+
+    /// Returns the posting as a [`Debit`], or `None` if it's a credit.
+    pub fn as_debit(&self) -> Option<Debit> {
+        Debit::new(self.amount).ok()
+    }
+
+    /// Returns the posting as a [`Credit`], or `None` if it's a debit
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::ledger::{JournalEntry, Side};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut entry = JournalEntry::new("Cash sale");
+    /// entry.debit("cash", Owo::new(50000,ngn.clone()));
+    /// entry.credit("sales", Owo::new(50000,ngn.clone())).unwrap();
+    ///
+    /// assert_eq!(entry.postings[0].side(), Side::Debit);
+    /// assert!(entry.postings[0].as_credit().is_none());
+    /// assert_eq!(entry.postings[1].as_credit().unwrap().amount(), &Owo::new(50000,ngn.clone()));
+    /// ```
+    pub fn as_credit(&self) -> Option<Credit> {
+        if self.amount.amount >= 0 {
+            return None;
+        }
+        let amount = self.amount.amount.checked_neg()?;
+        Credit::new(Owo::new(amount, self.amount.currency)).ok()
+    }
+}
+
+/// A set of postings recorded together, which must balance to be posted.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub description: String,
+    pub postings: Vec<Posting>,
+}
+
+impl JournalEntry {
+    /// Creates an entry with no postings.
+    pub fn new(description: &str) -> Self {
+        Self {
+            description: description.to_string(),
+            postings: Vec::new(),
+        }
+    }
+
+    /// Debits `amount` to `account`.
+    pub fn debit(&mut self, account: &str, amount: Owo) {
+        self.postings.push(Posting {
+            account: account.to_string(),
+            amount,
+        });
+    }
+
+    /// Credits `amount` to `account`.
+    pub fn credit(&mut self, account: &str, amount: Owo) -> Result<(), OwoError> {
+        let negated = amount
+            .amount
+            .checked_neg()
+            .ok_or_else(|| amount.overflow("credit", 0))?;
+        self.postings.push(Posting {
+            account: account.to_string(),
+            amount: Owo::new(negated, amount.currency),
+        });
+        Ok(())
+    }
+
+    /// Posts a typed [`Debit`] to `account`.
+    pub fn post_debit(&mut self, account: &str, debit: Debit) {
+        self.postings.push(Posting {
+            account: account.to_string(),
+            amount: debit.signed(),
+        });
+    }
+
+    /// Posts a typed [`Credit`] to `account`, which unlike
+    /// [`JournalEntry::credit`] can't fail
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::ledger::{Credit, Debit, JournalEntry};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let amount = Owo::new(50000,ngn.clone());
+    /// let mut entry = JournalEntry::new("Cash sale");
+    /// entry.post_debit("cash", Debit::new(amount).unwrap());
+    /// entry.post_credit("sales", Credit::new(amount).unwrap());
+    ///
+    /// assert!(entry.check_balanced().is_ok());
+    /// ```
+    pub fn post_credit(&mut self, account: &str, credit: Credit) {
+        self.postings.push(Posting {
+            account: account.to_string(),
+            amount: credit.signed(),
+        });
+    }
+
+    /// Checks that the postings sum to zero in every currency
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::ledger::JournalEntry;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut entry = JournalEntry::new("Cash sale");
+    /// entry.debit("cash", Owo::new(50000,ngn.clone()));
+    /// entry.credit("sales", Owo::new(45000,ngn.clone())).unwrap();
+    /// assert!(entry.check_balanced().is_err());
+    ///
+    /// entry.credit("vat", Owo::new(5000,ngn.clone())).unwrap();
+    /// assert!(entry.check_balanced().is_ok());
+    /// ```
+    pub fn check_balanced(&self) -> Result<(), OwoError> {
+        let totals = totals_by_currency(self.postings.iter().map(|posting| &posting.amount))?;
+        let mut unbalanced: Vec<&Owo> = totals.values().filter(|total| total.amount != 0).collect();
+        unbalanced.sort_by(|a, b| a.currency.code.cmp(b.currency.code));
+        match unbalanced.first() {
+            Some(total) => Err(OwoError::UnbalancedEntry(total.to_compact_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// One account's debit and credit totals in one currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TrialBalanceRow {
+    pub account: String,
+    pub debits: Owo,
+    /// Credits as a positive amount.
+    pub credits: Owo,
+}
+
+/// The debit and credit totals across all accounts in one currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TrialBalanceTotal {
+    pub debits: Owo,
+    pub credits: Owo,
+    /// `debits - credits`, zero when the currency balances.
+    pub difference: Owo,
+}
+
+/// Every account's debit and credit totals, with the grand totals per
+/// currency.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TrialBalance {
+    /// One row per account and currency, in account order.
+    pub rows: Vec<TrialBalanceRow>,
+    /// One total per currency, ordered by currency code.
+    pub totals: Vec<TrialBalanceTotal>,
+}
+
+impl TrialBalance {
+    /// Returns whether debits equal credits in every currency.
+    pub fn is_balanced(&self) -> bool {
+        self.imbalances().is_empty()
+    }
+
+    /// Returns the totals of the currencies where debits and credits differ.
+    pub fn imbalances(&self) -> Vec<&TrialBalanceTotal> {
+        self.totals
+            .iter()
+            .filter(|total| total.difference.amount != 0)
+            .collect()
+    }
+}
+
+/// A chart of accounts and the entries posted to them.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Ledger {
+    accounts: Vec<Account>,
+    entries: Vec<JournalEntry>,
+}
+
+impl Ledger {
+    /// Creates a ledger with no accounts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an account, rejecting codes already in use.
+    pub fn open_account(
+        &mut self,
+        code: &str,
+        name: &str,
+        kind: AccountKind,
+    ) -> Result<(), OwoError> {
+        if self.account(code).is_some() {
+            return Err(OwoError::DuplicateAccount(code.to_string()));
+        }
+        self.accounts.push(Account {
+            code: code.to_string(),
+            name: name.to_string(),
+            kind,
+        });
+        Ok(())
+    }
+
+    /// Returns the account with `code`, if it exists.
+    pub fn account(&self, code: &str) -> Option<&Account> {
+        self.accounts.iter().find(|account| account.code == code)
+    }
+
+    /// Returns every account, in the order they were opened.
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// Returns every posted entry, in the order they were posted.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Records `entry`, rejecting empty or unbalanced entries and postings to
+    /// unknown accounts
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::ledger::{AccountKind, JournalEntry, Ledger};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut ledger = Ledger::new();
+    /// ledger.open_account("cash", "Cash", AccountKind::Asset).unwrap();
+    /// ledger.open_account("sales", "Sales", AccountKind::Income).unwrap();
+    ///
+    /// let mut entry = JournalEntry::new("Cash sale");
+    /// entry.debit("cash", Owo::new(50000,ngn.clone()));
+    /// entry.credit("sales", Owo::new(50000,ngn.clone())).unwrap();
+    /// ledger.post(entry).unwrap();
+    ///
+    /// let mut typo = JournalEntry::new("Refund");
+    /// typo.debit("sale", Owo::new(100,ngn.clone()));
+    /// typo.credit("cash", Owo::new(100,ngn.clone())).unwrap();
+    /// assert!(ledger.post(typo).is_err());
+    /// assert_eq!(ledger.entries().len(), 1);
+    /// ```
+    pub fn post(&mut self, entry: JournalEntry) -> Result<(), OwoError> {
+        if entry.postings.is_empty() {
+            return Err(OwoError::EmptyCollection);
+        }
+        if let Some(posting) = entry
+            .postings
+            .iter()
+            .find(|posting| self.account(&posting.account).is_none())
+        {
+            return Err(OwoError::UnknownAccount(posting.account.clone()));
+        }
+        entry.check_balanced()?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Totals the debits and credits of every account
+    ///
+    /// Entries posted through [`Ledger::post`] always balance, but a ledger
+    /// deserialized from elsewhere may not; the returned report lists any
+    /// currency where debits and credits differ.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::ledger::{AccountKind, JournalEntry, Ledger};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut ledger = Ledger::new();
+    /// ledger.open_account("cash", "Cash", AccountKind::Asset).unwrap();
+    /// ledger.open_account("sales", "Sales", AccountKind::Income).unwrap();
+    ///
+    /// let mut entry = JournalEntry::new("Cash sale");
+    /// entry.debit("cash", Owo::new(50000,ngn.clone()));
+    /// entry.credit("sales", Owo::new(50000,ngn.clone())).unwrap();
+    /// ledger.post(entry).unwrap();
+    ///
+    /// let report = ledger.trial_balance().unwrap();
+    /// assert_eq!(report.rows[0].debits, Owo::new(50000,ngn.clone()));
+    /// assert_eq!(report.rows[1].credits, Owo::new(50000,ngn.clone()));
+    /// assert!(report.is_balanced());
+    ///
+    /// let json = r#"{"accounts":[{"code":"cash","name":"Cash","kind":"Asset"}],"entries":[{"description":"Typo","postings":[
+    ///     {"account":"cash","amount":{"amount":100,"currency":{"code":"NGN","symbol":"₦","precision":2}}}]}]}"#;
+    /// let broken: Ledger = serde_json::from_str(json).unwrap();
+    /// let report = broken.trial_balance().unwrap();
+    /// assert_eq!(report.imbalances()[0].difference, Owo::new(100,ngn.clone()));
+    /// ```
+    pub fn trial_balance(&self) -> Result<TrialBalance, OwoError> {
+        let mut rows: Vec<TrialBalanceRow> = Vec::new();
+        for account in &self.accounts {
+            let start = rows.len();
+            let postings = self
+                .entries
+                .iter()
+                .flat_map(|entry| &entry.postings)
+                .filter(|posting| posting.account == account.code);
+            for posting in postings {
+                let amount = &posting.amount;
+                let index = match rows[start..]
+                    .iter()
+                    .position(|row| row.debits.currency == amount.currency)
+                {
+                    Some(offset) => start + offset,
+                    None => {
+                        let zero = Owo::new(0, amount.currency);
+                        rows.push(TrialBalanceRow {
+                            account: account.code.clone(),
+                            debits: zero,
+                            credits: zero,
+                        });
+                        rows.len() - 1
+                    }
+                };
+                let row = &mut rows[index];
+                if amount.amount >= 0 {
+                    row.debits = row.debits.checked_add(amount)?;
+                } else {
+                    row.credits = row.credits.checked_sub(amount)?;
+                }
+            }
+            rows[start..].sort_by(|a, b| a.debits.currency.code.cmp(b.debits.currency.code));
+        }
+
+        let debits = totals_by_currency(rows.iter().map(|row| &row.debits))?;
+        let credits = totals_by_currency(rows.iter().map(|row| &row.credits))?;
+        let mut totals = debits
+            .into_iter()
+            .map(|(currency, debits)| {
+                let credits = credits[&currency];
+                let difference = debits.checked_sub(&credits)?;
+                Ok(TrialBalanceTotal {
+                    debits,
+                    credits,
+                    difference,
+                })
+            })
+            .collect::<Result<Vec<_>, OwoError>>()?;
+        totals.sort_by(|a, b| a.debits.currency.code.cmp(b.debits.currency.code));
+        Ok(TrialBalance { rows, totals })
+    }
+
+    /// Returns the balance of `account` in each currency posted to it,
+    /// positive when it sits on the account's normal side, so assets grow
+    /// with debits and income grows with credits
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::ledger::{AccountKind, JournalEntry, Ledger};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut ledger = Ledger::new();
+    /// ledger.open_account("cash", "Cash", AccountKind::Asset).unwrap();
+    /// ledger.open_account("sales", "Sales", AccountKind::Income).unwrap();
+    ///
+    /// for amount in [50000, 20000] {
+    ///     let mut entry = JournalEntry::new("Cash sale");
+    ///     entry.debit("cash", Owo::new(amount,ngn.clone()));
+    ///     entry.credit("sales", Owo::new(amount,ngn.clone())).unwrap();
+    ///     ledger.post(entry).unwrap();
+    /// }
+    ///
+    /// assert_eq!(ledger.balances("cash").unwrap()[&ngn], Owo::new(70000,ngn.clone()));
+    /// assert_eq!(ledger.balances("sales").unwrap()[&ngn], Owo::new(70000,ngn.clone()));
+    /// assert!(ledger.balances("bank").is_err());
+    /// ```
+    pub fn balances(&self, account: &str) -> Result<HashMap<Currency, Owo>, OwoError> {
+        let kind = self
+            .account(account)
+            .ok_or_else(|| OwoError::UnknownAccount(account.to_string()))?
+            .kind;
+        let debit_balances = totals_by_currency(
+            self.entries
+                .iter()
+                .flat_map(|entry| &entry.postings)
+                .filter(|posting| posting.account == account)
+                .map(|posting| &posting.amount),
+        )?;
+        if kind.normal_side() == Side::Debit {
+            return Ok(debit_balances);
+        }
+        debit_balances
+            .into_iter()
+            .map(|(currency, balance)| {
+                let amount = balance
+                    .amount
+                    .checked_neg()
+                    .ok_or_else(|| balance.overflow("balance", 0))?;
+                Ok((currency, Owo::new(amount, balance.currency)))
+            })
+            .collect()
+    }
+}
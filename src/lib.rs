@@ -2,18 +2,113 @@
 //!
 //! A financial math library with support for currencies, precise rounding, and
 //! batch operations over monetary values using `Owo`.
+//!
+//! ## Optional features
+//!
+//! - `decimal`: exact `rust_decimal::Decimal` variants of ratio calculations.
+//! - `schemars`: `JsonSchema` for `Owo`, `Currency` and `RoundingMode`, so
+//!   OpenAPI generators emit correct schemas for money fields.
+//! - `borsh`: `BorshSerialize`/`BorshDeserialize` for `Owo` and `Currency`.
+//! - `sqlx`: `FromRow`, `Type`, `Encode` and `Decode` for `Owo`; see `cowry::db::sqlx`.
+//! - `diesel`: `Queryable` and `FromSql` for `Owo`, and `CompactOwo` for
+//!   writing text columns; see `cowry::db::diesel`.
+//! - `bson`: MongoDB `Decimal128` amounts; see `cowry::db::bson`.
+//! - `wasm`: JavaScript bindings through `wasm-bindgen`; see `cowry::wasm`.
+//! - `python`: a PyO3 extension module; see `cowry::python`.
+//! - `arbitrary`: `Arbitrary` impls and `proptest` strategies; see `cowry::arbitrary`.
+//! - `chrono`: `chrono::NaiveDate` arguments for day-count calculations; see
+//!   `cowry::finance::day_count`.
+//! - `csv`: reading and writing `Owo` columns in CSV files; see `cowry::csv`.
+//! - `import`: OFX and QIF bank statement parsers; see `cowry::import`.
+//! - `report`: Markdown and HTML tables for invoices, statements and
+//!   per-currency summaries; see `cowry::report`.
+//! - `cli`: the `cowry` command-line tool for formatting, parsing,
+//!   converting, summing and allocating amounts.
+//!
+//! ```
+//! # #[cfg(feature = "schemars")] {
+//! use cowry::prelude::*;
+//!
+//! let schema = schemars::schema_for!(Owo);
+//! let json = serde_json::to_value(&schema).unwrap();
+//! assert_eq!(json["properties"]["amount"]["type"], "integer");
+//! # }
+//! ```
+//!
+//! ```
+//! # #[cfg(feature = "borsh")] {
+//! use cowry::prelude::*;
+//!
+//! let owo = Owo::new(500, Currency::new("NGN", "₦", 2));
+//! let bytes = borsh::to_vec(&owo).unwrap();
+//!
+//! assert_eq!(borsh::from_slice::<Owo>(&bytes).unwrap(), owo);
+//! # }
+//! ```
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+pub mod atomic;
+pub mod basis_points;
+mod binary;
+pub mod billing;
+pub mod budget;
+pub mod compact;
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod currency;
+pub mod db;
+pub mod denominations;
+pub mod discount;
+pub mod drawer;
 pub mod error; 
+pub mod estimate;
+pub mod exchange;
+pub mod exposure;
+pub mod finance;
+#[cfg(feature = "import")]
+pub mod import;
+pub mod interop;
+pub mod interpolate;
+pub mod invoice;
+pub mod ledger;
+#[doc(hidden)]
+pub mod macros;
+pub mod non_negative;
 pub mod owo;
+pub mod payroll;
+pub mod pricing;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod quantity;
+pub mod redenomination;
+#[cfg(feature = "report")]
+pub mod report;
+pub mod registry;
 pub mod rounding;
+pub mod serde;
+pub mod split;
+pub mod statement;
+pub mod stats;
+pub mod table;
+pub mod tax;
 pub mod traits; 
+pub mod units;
+pub mod wallet;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use crate::currency::Currency;
+pub use crate::exchange::RateTable;
 pub use crate::owo::Owo;
 pub use crate::traits::BatchOperations;
+pub use crate::traits::OwoIterExt;
+pub use crate::traits::SignPartition;
 pub use crate::rounding::RoundingMode;
 
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
 // Setup prelude module
 pub mod prelude {
     //! The Cowry Prelude
@@ -24,6 +119,8 @@ pub mod prelude {
 
     pub use crate::Currency;
     pub use crate::Owo;
+    pub use crate::RateTable;
     pub use crate::RoundingMode;
     pub use crate::BatchOperations;
+    pub use crate::OwoIterExt;
 }
@@ -0,0 +1,157 @@
+//! Construction macros and the const helpers they expand to.
+
+use crate::Currency;
+use crate::currency::is_valid_code;
+use crate::registry::{self, IsoCurrency};
+
+/// Defines a `Currency` at compile time, either from a code, symbol and
+/// precision or by ISO 4217 code
+///
+/// Usable in `const` and `static` items, so a supported currency set can be
+/// declared in one place. Codes must be upper-case ASCII letters or digits
+/// and precisions at most `Currency::MAX_PRECISION`; anything else, or an
+/// unknown ISO code, fails to build. Call [`Currency::register`] on custom
+/// currencies at startup so decoders accept amounts in them.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::currency;
+///
+/// const NGN: Currency = currency!(NGN);
+/// static POINTS: Currency = currency!("PTS", "★", 0);
+///
+/// assert_eq!(NGN, Currency::new("NGN", "₦", 2));
+/// assert_eq!(POINTS.precision, 0);
+/// ```
+///
+/// ```compile_fail
+/// const BAD: cowry::Currency = cowry::currency!("usd", "$", 2);
+/// ```
+///
+/// ```compile_fail
+/// const BAD: cowry::Currency = cowry::currency!("USD", "$", 19);
+/// ```
+#[macro_export]
+macro_rules! currency {
+    ($code:ident) => {{
+        const CURRENCY: $crate::Currency = $crate::macros::iso(stringify!($code)).to_currency();
+        CURRENCY
+    }};
+    ($code:literal, $symbol:literal, $precision:literal) => {{
+        const CURRENCY: $crate::Currency =
+            $crate::macros::checked_currency($code, $symbol, $precision);
+        CURRENCY
+    }};
+}
+
+/// Creates an `Owo` from a decimal amount in major units, or from minor units
+/// with the `minor` marker, in an ISO 4217 currency
+///
+/// The amount is scaled at compile time, so an unknown currency code or an
+/// amount with more decimal places than the currency allows fails to build.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::owo;
+///
+/// assert_eq!(owo!(12.99, USD), Owo::new(1299,Currency::new("USD", "$", 2)));
+/// assert_eq!(owo!(-0.5, EUR).get_amount(), -50);
+/// assert_eq!(owo!(1_500, JPY).get_amount(), 1500);
+/// assert_eq!(owo!(500 minor, NGN), Owo::new(500,Currency::new("NGN", "₦", 2)));
+///
+/// const PRICE: Owo = owo!(4.5, GBP);
+/// const FEE: Owo = owo!(250 minor, GBP);
+/// assert_eq!(PRICE.get_amount(), 450);
+/// assert_eq!(FEE.get_amount(), 250);
+/// ```
+///
+/// ```compile_fail
+/// // Yen has no minor unit
+/// let _ = cowry::owo!(12.99, JPY);
+/// ```
+///
+/// ```compile_fail
+/// // Not an ISO 4217 code
+/// let _ = cowry::owo!(500 minor, XYZ);
+/// ```
+#[macro_export]
+macro_rules! owo {
+    ($amount:literal minor, $code:ident) => {{
+        const CURRENCY: $crate::registry::IsoCurrency = $crate::macros::iso(stringify!($code));
+        $crate::Owo::new($amount, CURRENCY.to_currency())
+    }};
+    ($amount:literal, $code:ident) => {{
+        const CURRENCY: $crate::registry::IsoCurrency = $crate::macros::iso(stringify!($code));
+        const AMOUNT: i64 = $crate::macros::minor_units(stringify!($amount), CURRENCY.precision);
+        $crate::Owo::new(AMOUNT, CURRENCY.to_currency())
+    }};
+}
+
+#[doc(hidden)]
+pub const fn iso(code: &str) -> IsoCurrency {
+    match registry::find(code) {
+        Some(currency) => *currency,
+        None => panic!("unknown ISO 4217 currency code"),
+    }
+}
+
+#[doc(hidden)]
+pub const fn checked_currency(code: &'static str, symbol: &'static str, precision: u8) -> Currency {
+    if !is_valid_code(code) {
+        panic!("currency code must be upper-case ASCII letters or digits");
+    }
+    if symbol.is_empty() {
+        panic!("currency symbol must not be empty");
+    }
+    if precision > Currency::MAX_PRECISION {
+        panic!("currency precision is above Currency::MAX_PRECISION");
+    }
+    Currency::from_static(code, symbol, precision)
+}
+
+// Parses a decimal literal such as `-1_234.5` into minor units, panicking
+// (a compile error in const context) on anything that doesn't fit
+#[doc(hidden)]
+pub const fn minor_units(literal: &str, precision: u8) -> i64 {
+    let bytes = literal.as_bytes();
+    let mut index = 0;
+    let mut negative = false;
+    let mut amount: i64 = 0;
+    let mut decimals: u8 = 0;
+    let mut fraction = false;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        index += 1;
+        match byte {
+            b' ' | b'_' => {}
+            b'-' if !negative && amount == 0 && !fraction => negative = true,
+            b'.' if !fraction => fraction = true,
+            b'0'..=b'9' => {
+                if fraction {
+                    decimals += 1;
+                    if decimals > precision {
+                        panic!("amount has more decimal places than the currency allows");
+                    }
+                }
+                amount = match amount.checked_mul(10) {
+                    Some(scaled) => match scaled.checked_add((byte - b'0') as i64) {
+                        Some(amount) => amount,
+                        None => panic!("amount overflows i64 minor units"),
+                    },
+                    None => panic!("amount overflows i64 minor units"),
+                };
+            }
+            _ => panic!("amount must be a plain decimal literal"),
+        }
+    }
+    while decimals < precision {
+        amount = match amount.checked_mul(10) {
+            Some(amount) => amount,
+            None => panic!("amount overflows i64 minor units"),
+        };
+        decimals += 1;
+    }
+    if negative { -amount } else { amount }
+}
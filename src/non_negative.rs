@@ -0,0 +1,145 @@
+//! Money that can never be negative, for balances such as gift cards or
+//! inventory valuations where a negative amount is an invalid state.
+
+use crate::error::OwoError;
+use crate::{Currency, Owo};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An `Owo` whose amount is always zero or more.
+///
+/// Every constructor checks the sign, subtraction fails rather than going
+/// below zero, and deserializing a negative amount is an error.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::non_negative::NonNegativeOwo;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let card: NonNegativeOwo = serde_json::from_str(r#"{"amount":5000,"currency":{"code":"NGN","symbol":"₦","precision":2}}"#).unwrap();
+/// assert_eq!(card.amount(), 5000);
+///
+/// let negative = r#"{"amount":-1,"currency":{"code":"NGN","symbol":"₦","precision":2}}"#;
+/// assert!(serde_json::from_str::<NonNegativeOwo>(negative).is_err());
+/// assert!(NonNegativeOwo::new(Owo::new(-1,ngn)).is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[serde(try_from = "Owo", into = "Owo")]
+pub struct NonNegativeOwo(Owo);
+
+impl NonNegativeOwo {
+    /// Wraps `owo`, rejecting negative amounts.
+    pub fn new(owo: Owo) -> Result<Self, OwoError> {
+        if owo.amount < 0 {
+            return Err(OwoError::NegativeAmount(owo.to_compact_string()));
+        }
+        Ok(Self(owo))
+    }
+
+    /// Returns zero in `currency`.
+    pub const fn zero(currency: Currency) -> Self {
+        Self(Owo::new(0, currency))
+    }
+
+    /// Returns the amount in minor units.
+    pub fn amount(&self) -> u64 {
+        self.0.amount.unsigned_abs()
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.0.currency
+    }
+
+    /// Returns the wrapped `Owo`.
+    pub fn as_owo(&self) -> &Owo {
+        &self.0
+    }
+
+    /// Adds `rhs`, returning an error on mixed currencies or overflow.
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, OwoError> {
+        Ok(Self(self.0.checked_add(&rhs.0)?))
+    }
+
+    /// Subtracts `rhs`, returning an error on mixed currencies or if `rhs`
+    /// is more than `self`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::non_negative::NonNegativeOwo;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let balance = NonNegativeOwo::new(Owo::new(2500,usd.clone())).unwrap();
+    /// let coffee = NonNegativeOwo::new(Owo::new(450,usd.clone())).unwrap();
+    ///
+    /// let left = balance.checked_sub(&coffee).unwrap();
+    /// assert_eq!(left.as_owo(), &Owo::new(2050,usd.clone()));
+    ///
+    /// let err = coffee.checked_sub(&balance).unwrap_err();
+    /// assert_eq!(err.to_string(), "Insufficient funds: USD 4.50 available, USD 25.00 requested");
+    /// ```
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, OwoError> {
+        self.0.check_currency(&rhs.0)?;
+        if rhs.0.amount > self.0.amount {
+            return Err(OwoError::InsufficientFunds {
+                available: self.0.to_compact_string(),
+                requested: rhs.0.to_compact_string(),
+            });
+        }
+        Ok(Self(self.0.checked_sub(&rhs.0)?))
+    }
+
+    /// Subtracts `rhs`, stopping at zero, and returning an error only on
+    /// mixed currencies
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::non_negative::NonNegativeOwo;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let balance = NonNegativeOwo::new(Owo::new(300,usd.clone())).unwrap();
+    /// let charge = NonNegativeOwo::new(Owo::new(450,usd.clone())).unwrap();
+    ///
+    /// assert_eq!(balance.saturating_sub(&charge).unwrap(), NonNegativeOwo::zero(usd));
+    /// ```
+    pub fn saturating_sub(&self, rhs: &Self) -> Result<Self, OwoError> {
+        self.0.check_currency(&rhs.0)?;
+        Ok(Self(Owo::new(
+            (self.0.amount - rhs.0.amount).max(0),
+            self.0.currency,
+        )))
+    }
+
+    /// Multiplies by a whole-number `factor`, returning an error on
+    /// overflow.
+    pub fn checked_mul(&self, factor: u32) -> Result<Self, OwoError> {
+        let amount = self
+            .0
+            .amount
+            .checked_mul(i64::from(factor))
+            .ok_or_else(|| self.0.overflow("mul", factor))?;
+        Ok(Self(Owo::new(amount, self.0.currency)))
+    }
+}
+
+impl TryFrom<Owo> for NonNegativeOwo {
+    type Error = OwoError;
+
+    fn try_from(owo: Owo) -> Result<Self, Self::Error> {
+        Self::new(owo)
+    }
+}
+
+impl From<NonNegativeOwo> for Owo {
+    fn from(value: NonNegativeOwo) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for NonNegativeOwo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -1,18 +1,97 @@
 use crate::error::OwoError;
-use crate::traits::BatchOperations;
-use crate::{Currency, RoundingMode};
+use crate::rounding::round_div;
+use crate::traits::{BatchOperations, OwoIterExt, SignPartition};
+use crate::{Currency, RateTable, RoundingMode, registry};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
 
 /// A Money type that uses minor units (e.g. cents, kobo).
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// `Owo` is `Copy`, so it can be passed around by value like a number.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct Owo {
     pub amount: i64,
     pub currency: Currency,
 }
 
+/// Builds an [`Owo`] from major and minor parts, so call sites don't scale
+/// amounts to minor units by hand.
+#[derive(Debug, Clone, Default)]
+pub struct OwoBuilder {
+    major: i64,
+    minor: i64,
+    negative: bool,
+    currency: Option<Currency>,
+}
+
+impl OwoBuilder {
+    /// Sets the whole units; a negative value makes the whole amount negative.
+    pub fn major(mut self, major: i64) -> Self {
+        self.major = major;
+        self
+    }
+
+    /// Sets the minor units below one major unit, e.g. `50` for the cents in
+    /// $12.50.
+    pub fn minor(mut self, minor: i64) -> Self {
+        self.minor = minor;
+        self
+    }
+
+    /// Makes the amount negative, for amounts under one major unit.
+    pub fn negative(mut self) -> Self {
+        self.negative = true;
+        self
+    }
+
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Builds the amount, requiring a currency and a minor part below one
+    /// major unit.
+    pub fn build(self) -> Result<Owo, OwoError> {
+        let currency = self.currency.ok_or(OwoError::MissingField("currency"))?;
+        if currency.precision > Currency::MAX_PRECISION {
+            return Err(OwoError::InvalidPrecision(currency.precision));
+        }
+        let factor = currency.scale_factor();
+        if !(0..factor).contains(&self.minor) {
+            return Err(OwoError::ParseError {
+                input: self.minor.to_string(),
+                reason: format!("minor part must be in 0..{factor}"),
+            });
+        }
+        let overflow = || OwoError::Overflow {
+            op: "build",
+            lhs: self.major.to_string(),
+            rhs: self.minor.to_string(),
+        };
+        let magnitude = self
+            .major
+            .unsigned_abs()
+            .checked_mul(factor as u64)
+            .and_then(|scaled| scaled.checked_add(self.minor as u64))
+            .ok_or_else(overflow)?;
+        let amount = if self.negative || self.major < 0 {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+        .ok_or_else(overflow)?;
+        Ok(Owo::new(amount, currency))
+    }
+}
+
 impl Owo {
     /// Create a new `Owo`.
     ///
@@ -37,25 +116,126 @@ impl Owo {
     /// assert_eq!(owo_jpy.format(),"¥200");
     /// assert_eq!(owo_btc.format(),"₿0.00000200");
     /// ```
-    pub fn new(amount: i64, currency: Currency) -> Owo {
+    pub const fn new(amount: i64, currency: Currency) -> Owo {
         Owo { amount, currency }
     }
 
+    /// Starts building an amount from major and minor parts, validated on
+    /// [`OwoBuilder::build`].
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// let price = Owo::builder().major(12).minor(50).currency(usd.clone()).build().unwrap();
+    /// assert_eq!(price, Owo::new(1250,usd.clone()));
+    ///
+    /// let refund = Owo::builder().major(-3).minor(5).currency(usd.clone()).build().unwrap();
+    /// assert_eq!(refund.get_amount(), -305);
+    ///
+    /// let tip = Owo::builder().minor(75).negative().currency(usd.clone()).build().unwrap();
+    /// assert_eq!(tip.get_amount(), -75);
+    ///
+    /// assert!(Owo::builder().major(1).minor(100).currency(usd.clone()).build().is_err());
+    /// assert!(Owo::builder().major(1).build().is_err());
+    /// ```
+    pub fn builder() -> OwoBuilder {
+        OwoBuilder::default()
+    }
+
     // Helper for rounding based on precision
     fn round_amount(&self, raw: f64) -> i64 {
         self.round_amount_with_mode(raw, RoundingMode::Nearest)
     }
 
     // Helper for rounding based on precision with rounding mode
-    fn round_amount_with_mode(&self, raw: f64, mode: RoundingMode) -> i64 {
-        let factor = 10i64.pow(self.currency.precision as u32) as f64;
+    pub(crate) fn round_amount_with_mode(&self, raw: f64, mode: RoundingMode) -> i64 {
+        self.scale_and_round(raw, mode) as i64
+    }
+
+    // Helper for rounding based on precision with rounding mode, returning
+    // `None` instead of saturating when the result doesn't fit in an i64
+    pub(crate) fn checked_round_amount_with_mode(
+        &self,
+        raw: f64,
+        mode: RoundingMode,
+    ) -> Option<i64> {
+        let rounded = self.scale_and_round(raw, mode);
+        if !rounded.is_finite() || rounded < i64::MIN as f64 || rounded >= i64::MAX as f64 {
+            return None;
+        }
+        Some(rounded as i64)
+    }
+
+    // Helper building an overflow error for `op` applied to `self` and `rhs`
+    pub(crate) fn overflow(&self, op: &'static str, rhs: impl fmt::Display) -> OwoError {
+        OwoError::Overflow {
+            op,
+            lhs: self.amount.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+
+    // Helper scaling a major-unit value to minor units and rounding it
+    fn scale_and_round(&self, raw: f64, mode: RoundingMode) -> f64 {
+        let factor = self.currency.scale_factor_f64();
         let scaled = raw * factor;
-        let rounded = match mode {
+        match mode {
             RoundingMode::Nearest => scaled.round(),
             RoundingMode::Floor => scaled.floor(),
             RoundingMode::Ceil => scaled.ceil(),
-        };
-        rounded as i64
+        }
+    }
+
+    // Helper erroring when `rhs` is in a different currency
+    pub(crate) fn check_currency(&self, rhs: &Self) -> Result<(), OwoError> {
+        if self.currency != rhs.currency {
+            return Err(OwoError::CurrencyMismatch(
+                self.currency.code.to_string(),
+                rhs.currency.code.to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // Helper rejecting NaN and infinite scalars
+    fn check_scalar(scalar: f64) -> Result<f64, OwoError> {
+        if scalar.is_finite() {
+            Ok(scalar)
+        } else {
+            Err(OwoError::InvalidScalar(scalar))
+        }
+    }
+
+    // Helper computing the rounded amount of `self * scalar`, rejecting
+    // non-finite scalars and results that overflow
+    fn multiplied_amount(&self, scalar: f64, mode: RoundingMode) -> Result<i64, OwoError> {
+        let scalar = Self::check_scalar(scalar)?;
+        let raw = (self.amount as f64 / self.currency.scale_factor_f64()) * scalar;
+        self.checked_round_amount_with_mode(raw, mode)
+            .ok_or_else(|| self.overflow("multiply", scalar))
+    }
+
+    // Helper computing the rounded amount of `self / scalar`, rejecting zero
+    // and non-finite scalars and results that overflow
+    fn divided_amount(&self, scalar: f64, mode: RoundingMode) -> Result<i64, OwoError> {
+        let scalar = Self::check_scalar(scalar)?;
+        if scalar == 0.0 {
+            return Err(OwoError::DivisionByZero);
+        }
+        let raw = (self.amount as f64 / self.currency.scale_factor_f64()) / scalar;
+        self.checked_round_amount_with_mode(raw, mode)
+            .ok_or_else(|| self.overflow("divide", scalar))
+    }
+
+    // Helper computing the rounded amount of `percent`% of `self`, rejecting
+    // non-finite percentages and results that overflow
+    fn percentage_amount(&self, percent: f64, mode: RoundingMode) -> Result<i64, OwoError> {
+        let percent = Self::check_scalar(percent)?;
+        let raw = (self.amount as f64 / self.currency.scale_factor_f64()) * (percent / 100.0);
+        self.checked_round_amount_with_mode(raw, mode)
+            .ok_or_else(|| self.overflow("percentage", percent))
     }
 
     /// Format the money into a display string.
@@ -70,15 +250,129 @@ impl Owo {
     /// assert_eq!(owo.format(),"₦5.00");
     /// ```
     pub fn format(&self) -> String {
+        let mut out = String::new();
+        self.format_into(&mut out);
+        out
+    }
+
+    /// Appends the display string to `out`, so many values can be
+    /// formatted into one reused buffer.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut row = String::from("total,");
+    ///
+    /// Owo::new(500,ngn).format_into(&mut row);
+    /// assert_eq!(row, "total,₦5.00");
+    /// ```
+    pub fn format_into(&self, out: &mut String) {
+        use fmt::Write;
         let precision = self.currency.precision as usize;
-        let divisor = 10i64.pow(precision as u32);
+        let divisor = self.currency.scale_factor();
         let whole = self.amount / divisor;
-        let fraction = (self.amount.abs() % divisor) as usize;
-        let format_precision = match precision {
-            0 => format!(""),
-            1.. => format!(".{:0width$}", fraction, width = precision),
+        let fraction = self.amount.abs() % divisor;
+        // Writing to a String can't fail
+        let _ = match precision {
+            0 => write!(out, "{}{}", self.currency.symbol, whole),
+            1.. => write!(
+                out,
+                "{}{}.{:0width$}",
+                self.currency.symbol,
+                whole,
+                fraction,
+                width = precision
+            ),
         };
-        format!("{}{}{}", self.currency.symbol, whole, format_precision)
+    }
+
+    /// Format the money as a plain decimal string in major units, without
+    /// symbol or grouping.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let jpy = Currency::new("JPY", "¥", 0);
+    ///
+    /// assert_eq!(Owo::new(599,usd.clone()).to_decimal_string(),"5.99");
+    /// assert_eq!(Owo::new(-5,usd.clone()).to_decimal_string(),"-0.05");
+    /// assert_eq!(Owo::new(1200,jpy).to_decimal_string(),"1200");
+    /// ```
+    pub fn to_decimal_string(&self) -> String {
+        let precision = self.currency.precision as usize;
+        let divisor = self.currency.scale_factor() as u64;
+        let abs = self.amount.unsigned_abs();
+        let sign = if self.amount < 0 { "-" } else { "" };
+        match precision {
+            0 => format!("{}{}", sign, abs),
+            1.. => format!(
+                "{}{}.{:0width$}",
+                sign,
+                abs / divisor,
+                abs % divisor,
+                width = precision
+            ),
+        }
+    }
+
+    /// Parses a plain decimal string in major units, such as `"5.99"`,
+    /// rejecting more fraction digits than the currency's precision allows.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// assert_eq!(Owo::from_decimal_str("5.99",usd.clone()).unwrap().get_amount(),599);
+    /// assert_eq!(Owo::from_decimal_str("-0.5",usd.clone()).unwrap().get_amount(),-50);
+    /// assert_eq!(Owo::from_decimal_str("12",usd.clone()).unwrap().get_amount(),1200);
+    /// assert!(Owo::from_decimal_str("5.999",usd.clone()).is_err());
+    /// assert!(Owo::from_decimal_str("five",usd.clone()).is_err());
+    /// ```
+    pub fn from_decimal_str(input: &str, currency: Currency) -> Result<Owo, OwoError> {
+        let amount = parse_decimal(input, currency.precision)?;
+        Ok(Owo { amount, currency })
+    }
+
+    /// Format the money as a single compact string of currency code and
+    /// decimal amount, e.g. `"USD 5.99"`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// assert_eq!(Owo::new(123450,ngn).to_compact_string(),"NGN 1234.50");
+    /// ```
+    pub fn to_compact_string(&self) -> String {
+        format!("{} {}", self.currency.code, self.to_decimal_string())
+    }
+
+    /// Parses a compact string such as `"USD 5.99"`, resolving the currency
+    /// from the ISO 4217 registry. Also available through `str::parse`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let owo = Owo::from_compact_str("NGN 1234.50").unwrap();
+    ///
+    /// assert_eq!(owo.get_amount(),123450);
+    /// assert_eq!(owo.get_currency(),"NGN");
+    ///
+    /// let parsed: Owo = "USD 5.99".parse().unwrap();
+    /// assert_eq!(parsed.get_amount(),599);
+    ///
+    /// assert!(Owo::from_compact_str("5.99").is_err());
+    /// assert!(Owo::from_compact_str("XYZ 5.99").is_err());
+    /// ```
+    pub fn from_compact_str(input: &str) -> Result<Owo, OwoError> {
+        let (code, amount) = input.split_once(' ').ok_or_else(|| OwoError::ParseError {
+            input: input.to_string(),
+            reason: "expected a currency code and an amount separated by a space".to_string(),
+        })?;
+        Owo::from_decimal_str(amount, registry::currency(code)?)
     }
 
     /// Returns the raw amount in minor units.
@@ -94,6 +388,34 @@ impl Owo {
         self.amount
     }
 
+    /// Returns a new `Owo` in the same currency with `amount` minor units.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(500,ngn.clone());
+    /// assert_eq!(owo.with_amount(1234), Owo::new(1234,ngn));
+    /// ```
+    pub fn with_amount(&self, amount: i64) -> Owo {
+        Owo::new(amount, self.currency)
+    }
+
+    /// Returns a new `Owo` in the same currency with `f` applied to the
+    /// amount in minor units.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(500,ngn.clone());
+    /// assert_eq!(owo.map_amount(|a| a * 2), Owo::new(1000,ngn.clone()));
+    /// assert_eq!(owo.map_amount(i64::abs), owo);
+    /// ```
+    pub fn map_amount(&self, f: impl FnOnce(i64) -> i64) -> Owo {
+        self.with_amount(f(self.amount))
+    }
+
     /// Returns the currency code (e.g., "NGN")
     ///
     /// #Example
@@ -104,7 +426,7 @@ impl Owo {
     /// assert_eq!(owo.get_currency(),"NGN");
     /// ```
     pub fn get_currency(&self) -> &str {
-        self.currency.code.as_str()
+        self.currency.code
     }
 
     /// Returns the precision (e.g., 2 for NGN)
@@ -133,11 +455,37 @@ impl Owo {
     /// assert_eq!(owo.amount, 500);
     /// assert_eq!(owo.currency.code, "EUR");
     /// assert_eq!(owo.currency.precision, 2);
+    ///
+    /// let too_precise = r#"{"amount": 1,"currency": { "code": "EUR","symbol": "€","precision": 40  }}"#;
+    /// assert!(Owo::from_json(too_precise).is_err());
     /// ```
     pub fn from_json(json_str: &str) -> Result<Owo, serde_json::Error> {
         serde_json::from_str(json_str)
     }
 
+    /// Deserialize from JSON string, rejecting currencies that aren't in
+    /// `custom` or the ISO 4217 registry, or whose symbol or precision
+    /// differ from the registered definition
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let json_str = r#"{"amount": 500,"currency": { "code": "EUR","symbol": "€","precision": 2  }}"#;
+    /// assert_eq!(Owo::from_json_strict(json_str, &[]).unwrap().currency.code, "EUR");
+    ///
+    /// let forged = r#"{"amount": 500,"currency": { "code": "EUR","symbol": "€","precision": 0  }}"#;
+    /// assert!(Owo::from_json_strict(forged, &[]).is_err());
+    ///
+    /// let points = Currency::new("PTS", "★", 0);
+    /// let json_str = r#"{"amount": 500,"currency": { "code": "PTS","symbol": "★","precision": 0  }}"#;
+    /// assert!(Owo::from_json_strict(json_str, &[]).is_err());
+    /// assert_eq!(Owo::from_json_strict(json_str, &[points]).unwrap(), Owo::new(500,points));
+    /// ```
+    pub fn from_json_strict(json_str: &str, custom: &[Currency]) -> Result<Owo, OwoError> {
+        let repr: crate::serde::strict::Repr = serde_json::from_str(json_str)?;
+        repr.resolve(custom)
+    }
+
     /// Serialize to JSON string
     ///
     /// #Example
@@ -164,6 +512,7 @@ impl Owo {
     ///
     /// assert!(owo1.eq(&owo2));
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn eq(&self, rhs: &Self) -> bool {
         self.currency == rhs.currency && self.amount == rhs.amount
     }
@@ -198,6 +547,30 @@ impl Owo {
         self.currency == rhs.currency && self.amount > rhs.amount
     }
 
+    /// Checks if `self` and `rhs` differ by at most `tolerance`, returning an
+    /// error if the currencies differ or the tolerance is negative
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let ours = Owo::new(10000,usd.clone());
+    /// let settled = Owo::new(9998,usd.clone());
+    ///
+    /// assert!(ours.approx_eq(&settled, Owo::new(2,usd.clone())).unwrap());
+    /// assert!(!ours.approx_eq(&settled, Owo::new(1,usd.clone())).unwrap());
+    /// assert!(ours.approx_eq(&settled, Owo::new(-2,usd)).is_err());
+    /// ```
+    pub fn approx_eq(&self, rhs: &Self, tolerance: Owo) -> Result<bool, OwoError> {
+        self.check_currency(rhs)?;
+        self.check_currency(&tolerance)?;
+        if tolerance.amount < 0 {
+            return Err(OwoError::NegativeAmount(tolerance.to_compact_string()));
+        }
+        let difference = (i128::from(self.amount) - i128::from(rhs.amount)).abs();
+        Ok(difference <= i128::from(tolerance.amount))
+    }
+
     /// Rounds the amount to the specified precision of the currency.
     ///
     /// #Example
@@ -210,220 +583,681 @@ impl Owo {
     /// assert_eq!(owo.get_amount(), 1247);
     /// ```
     pub fn round_to_precision(&mut self) {
-        let raw = self.amount as f64 / 10f64.powi(self.currency.precision as i32);
+        let raw = self.amount as f64 / self.currency.scale_factor_f64();
         self.amount = self.round_amount(raw);
     }
 
-    /// Multiplies the amount by a scalar
+    /// Adds `rhs` to `self`, returning an error instead of panicking on a
+    /// currency mismatch or overflow
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
-    /// let owo = Owo::new(1230,ngn);
+    /// let usd = Currency::new("USD", "$", 2);
     ///
-    /// assert_eq!(owo.multiply(1.5).get_amount(),1845);
+    /// let sum = Owo::new(500,ngn.clone()).checked_add(&Owo::new(250,ngn.clone())).unwrap();
+    /// assert_eq!(sum.get_amount(), 750);
+    ///
+    /// assert!(Owo::new(500,ngn.clone()).checked_add(&Owo::new(250,usd)).is_err());
+    ///
+    /// let err = Owo::new(i64::MAX,ngn.clone()).checked_add(&Owo::new(1,ngn)).unwrap_err();
+    /// assert_eq!(err.to_string(), "Arithmetic overflow in add: 9223372036854775807 and 1");
     /// ```
-    pub fn multiply(&self, scalar: f64) -> Owo {
-        self.multiply_with_mode(scalar, RoundingMode::Nearest)
+    pub fn checked_add(&self, rhs: &Self) -> Result<Owo, OwoError> {
+        self.check_currency(rhs)?;
+        let amount = self
+            .amount
+            .checked_add(rhs.amount)
+            .ok_or_else(|| self.overflow("add", rhs.amount))?;
+        Ok(Owo {
+            amount,
+            currency: self.currency,
+        })
     }
 
-    /// Divides the amount by a scalar
+    /// Subtracts `rhs` from `self`, returning an error instead of panicking on
+    /// a currency mismatch or overflow
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
-    /// let owo = Owo::new(1000,ngn);
     ///
-    /// assert_eq!(owo.divide(4.5).get_amount(),222);
+    /// let diff = Owo::new(500,ngn.clone()).checked_sub(&Owo::new(750,ngn.clone())).unwrap();
+    /// assert_eq!(diff.get_amount(), -250);
+    ///
+    /// assert!(Owo::new(i64::MIN,ngn.clone()).checked_sub(&Owo::new(1,ngn)).is_err());
     /// ```
-    pub fn divide(&self, scalar: f64) -> Owo {
-        self.divide_with_mode(scalar, RoundingMode::Nearest)
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Owo, OwoError> {
+        self.check_currency(rhs)?;
+        let amount = self
+            .amount
+            .checked_sub(rhs.amount)
+            .ok_or_else(|| self.overflow("sub", rhs.amount))?;
+        Ok(Owo {
+            amount,
+            currency: self.currency,
+        })
     }
 
-    /// Returns a Owo representing a given percentage of the amount by a scalar
+    /// Multiplies the amount by an integer, returning an error instead of
+    /// panicking on overflow
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
-    /// let owo = Owo::new(1000,ngn);
     ///
-    /// assert_eq!(owo.percentage(0.5).get_amount(),5);
+    /// assert_eq!(Owo::new(500,ngn.clone()).checked_mul(3).unwrap(), Owo::new(1500,ngn.clone()));
+    /// assert!(Owo::new(i64::MAX,ngn.clone()).checked_mul(2).is_err());
     /// ```
-    pub fn percentage(&self, percent: f64) -> Owo {
-        self.percentage_with_mode(percent, RoundingMode::Nearest)
+    pub fn checked_mul(&self, n: i64) -> Result<Owo, OwoError> {
+        let amount = self
+            .amount
+            .checked_mul(n)
+            .ok_or_else(|| self.overflow("mul", n))?;
+        Ok(Owo {
+            amount,
+            currency: self.currency,
+        })
     }
 
-    /// Multiplies the amount by a scalar with rounding mode
+    /// Negates the amount, returning an error for `i64::MIN`, whose
+    /// negation doesn't fit in an `i64`
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
-    /// let owo = Owo::new(105,ngn);  // ₦1.05
-    /// // 1.05 * 2.5 = 2.625 → 2.63 (half up)
-    ///  let rounding_mode_nearest_even_half_up = owo.multiply_with_mode(2.5, RoundingMode::Nearest);
     ///
-    /// // 1.05 * 2.5 = 2.625 → floor = 2.62
-    /// let rounding_mode_floor_positive = owo.multiply_with_mode(2.5, RoundingMode::Floor);
+    /// assert_eq!(Owo::new(500,ngn.clone()).checked_neg().unwrap(), Owo::new(-500,ngn.clone()));
+    /// assert!(Owo::new(i64::MIN,ngn.clone()).checked_neg().is_err());
+    /// ```
+    pub fn checked_neg(&self) -> Result<Owo, OwoError> {
+        let amount = self
+            .amount
+            .checked_neg()
+            .ok_or_else(|| self.overflow("neg", -1))?;
+        Ok(Owo {
+            amount,
+            currency: self.currency,
+        })
+    }
+
+    /// Returns the absolute amount, with an error for `i64::MIN`, whose
+    /// absolute value doesn't fit in an `i64`
     ///
-    ///  // 1.05 * 2.5 = 2.625 → ceil = 2.63
-    /// let rounding_mode_ceil_positive = owo.multiply_with_mode(2.5, RoundingMode::Ceil);
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
     ///
-    /// // 1.05 * -2.5 = -2.625 → floor = -2.63
-    /// let rounding_mode_floor_negative = owo.multiply_with_mode(-2.5, RoundingMode::Floor);
+    /// assert_eq!(Owo::new(-500,ngn.clone()).checked_abs().unwrap(), Owo::new(500,ngn.clone()));
+    /// assert!(Owo::new(i64::MIN,ngn.clone()).checked_abs().is_err());
+    /// ```
+    pub fn checked_abs(&self) -> Result<Owo, OwoError> {
+        let amount = self
+            .amount
+            .checked_abs()
+            .ok_or_else(|| self.overflow("abs", -1))?;
+        Ok(Owo {
+            amount,
+            currency: self.currency,
+        })
+    }
+
+    /// Splits the amount into `n` equal parts, returning one part and the
+    /// remainder left over, so no money is lost to truncation
     ///
-    /// // 1.05 * -2.5 = -2.625 → ceil = -2.62
-    /// let rounding_mode_ceil_negative = owo.multiply_with_mode(-2.5, RoundingMode::Ceil);
+    /// Panics if `n` is zero, or on `i64::MIN` split by `-1`; see
+    /// [`Owo::checked_div_rem`] for a non-panicking version.
     ///
-    /// assert_eq!(rounding_mode_nearest_even_half_up.get_amount(), 263); //₦2.63
-    /// assert_eq!(rounding_mode_floor_positive.get_amount(), 262);
-    /// assert_eq!(rounding_mode_ceil_positive.get_amount(), 263);
-    /// assert_eq!(rounding_mode_floor_negative.get_amount(), -263);
-    /// assert_eq!(rounding_mode_ceil_negative.get_amount(), -262);
+    /// #Example
     /// ```
-    pub fn multiply_with_mode(&self, scalar: f64, mode: RoundingMode) -> Owo {
-        let raw = (self.amount as f64 / 10f64.powi(self.currency.precision as i32)) * scalar;
-
-        Owo {
-            amount: self.round_amount_with_mode(raw, mode),
-            currency: self.currency.clone(),
-        }
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(1000,ngn.clone());
+    ///
+    /// let (part, remainder) = owo.div_rem(3);
+    ///
+    /// assert_eq!(part, Owo::new(333,ngn.clone()));
+    /// assert_eq!(remainder, Owo::new(1,ngn.clone()));
+    /// assert_eq!(owo % 3, remainder);
+    /// ```
+    pub fn div_rem(&self, n: i64) -> (Owo, Owo) {
+        self.checked_div_rem(n)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
-    /// Divides the amount by a scalar with rounding mode
+    /// Divides the amount by an integer, truncating toward zero like the `/`
+    /// operator
+    ///
+    /// Returns `OwoError::DivisionByZero` if `n` is zero, or an overflow
+    /// error for `i64::MIN / -1`.
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
-    /// let owo = Owo::new(105,ngn);  // ₦1.05
-    /// // 1.05 / 2.8 = 0.375 → 0.38
-    ///  let rounding_mode_nearest_even_half_up = owo.divide_with_mode(2.8, RoundingMode::Nearest);
-    ///
-    /// // 1.05 / 2.8 = 0.375 → floor = 0.37
-    /// let rounding_mode_floor_positive = owo.divide_with_mode(2.8, RoundingMode::Floor);
+    /// let owo = Owo::new(1000,ngn.clone());
     ///
-    ///  // 1.05 / 2.8 = 0.375 → ceil = 0.38
-    /// let rounding_mode_ceil_positive = owo.divide_with_mode(2.8, RoundingMode::Ceil);
+    /// assert_eq!(owo.checked_div(3).unwrap(), owo / 3);
+    /// assert!(owo.checked_div(0).is_err());
+    /// assert!(Owo::new(i64::MIN,ngn.clone()).checked_div(-1).is_err());
+    /// ```
+    pub fn checked_div(&self, n: i64) -> Result<Owo, OwoError> {
+        Ok(self.checked_div_rem(n)?.0)
+    }
+
+    /// Returns the remainder of dividing the amount by an integer, like the
+    /// `%` operator
     ///
-    /// // 1.05 / -2.8 = -0.375 → floor = -0.38
-    /// let rounding_mode_floor_negative = owo.divide_with_mode(-2.8, RoundingMode::Floor);
+    /// Returns `OwoError::DivisionByZero` if `n` is zero, or an overflow
+    /// error for `i64::MIN % -1`.
     ///
-    /// // 1.05 / -2.8 = -0.375 → ceil = -0.37
-    /// let rounding_mode_ceil_negative = owo.divide_with_mode(-2.8, RoundingMode::Ceil);
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(1000,ngn.clone());
     ///
-    /// assert_eq!(rounding_mode_nearest_even_half_up.get_amount(), 38); //₦0.38
-    /// assert_eq!(rounding_mode_floor_positive.get_amount(), 37);
-    /// assert_eq!(rounding_mode_ceil_positive.get_amount(), 38);
-    /// assert_eq!(rounding_mode_floor_negative.get_amount(), -38);
-    /// assert_eq!(rounding_mode_ceil_negative.get_amount(), -37);
+    /// assert_eq!(owo.checked_rem(3).unwrap(), Owo::new(1,ngn.clone()));
+    /// assert!(owo.checked_rem(0).is_err());
     /// ```
-    pub fn divide_with_mode(&self, scalar: f64, mode: RoundingMode) -> Owo {
-        let raw = (self.amount as f64 / 10f64.powi(self.currency.precision as i32)) / scalar;
-        Owo {
-            amount: self.round_amount_with_mode(raw, mode),
-            currency: self.currency.clone(),
-        }
+    pub fn checked_rem(&self, n: i64) -> Result<Owo, OwoError> {
+        Ok(self.checked_div_rem(n)?.1)
     }
 
-    /// Returns a Owo representing a given percentage of the amount by a scalar with rounding mode
+    /// Splits the amount into `n` equal parts like [`Owo::div_rem`],
+    /// returning an error instead of panicking
+    ///
+    /// Returns `OwoError::DivisionByZero` if `n` is zero, or an overflow
+    /// error for `i64::MIN` split by `-1`.
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
-    /// let owo = Owo::new(105,ngn);  // ₦1.05
-    /// // 28% of 1.05 = 2.94 → 3
-    ///  let rounding_mode_nearest_even_half_up = owo.percentage_with_mode(2.8, RoundingMode::Nearest);
+    /// let owo = Owo::new(1000,ngn.clone());
     ///
-    /// // 28% of 1.05 = 2.94 → floor = 2
-    /// let rounding_mode_floor_positive = owo.percentage_with_mode(2.8, RoundingMode::Floor);
+    /// assert_eq!(owo.checked_div_rem(3).unwrap(), owo.div_rem(3));
+    /// assert!(owo.checked_div_rem(0).is_err());
+    /// ```
+    pub fn checked_div_rem(&self, n: i64) -> Result<(Owo, Owo), OwoError> {
+        if n == 0 {
+            return Err(OwoError::DivisionByZero);
+        }
+        let quotient = self
+            .amount
+            .checked_div(n)
+            .ok_or_else(|| self.overflow("div", n))?;
+        let remainder = self
+            .amount
+            .checked_rem(n)
+            .ok_or_else(|| self.overflow("rem", n))?;
+        Ok((
+            Owo {
+                amount: quotient,
+                currency: self.currency,
+            },
+            Owo {
+                amount: remainder,
+                currency: self.currency,
+            },
+        ))
+    }
+
+    /// Splits the amount into parts proportional to `ratios`, handing the
+    /// leftover minor units to the parts with the largest remainders so the
+    /// parts always sum back to the original amount
     ///
-    ///  // 28% of 1.05 = 2.94 → ceil = 3
-    /// let rounding_mode_ceil_positive = owo.percentage_with_mode(2.8, RoundingMode::Ceil);
+    /// Returns an error if `ratios` is empty or every ratio is zero.
     ///
-    /// // -28% of 1.05 = -2.94 → floor = -3
-    /// let rounding_mode_floor_negative = owo.percentage_with_mode(-2.8, RoundingMode::Floor);
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(1000,ngn.clone());
     ///
-    /// // -28% of 1.05 = -2.94 → ceil = -2
-    /// let rounding_mode_ceil_negative = owo.percentage_with_mode(-2.8, RoundingMode::Ceil);
+    /// let parts = owo.allocate(&[1, 1, 1]).unwrap();
+    /// assert_eq!(parts, vec![Owo::new(334,ngn.clone()),Owo::new(333,ngn.clone()),Owo::new(333,ngn.clone())]);
     ///
-    /// assert_eq!(rounding_mode_nearest_even_half_up.get_amount(), 3); //₦3.00
-    /// assert_eq!(rounding_mode_floor_positive.get_amount(), 2);
-    /// assert_eq!(rounding_mode_ceil_positive.get_amount(), 3);
-    /// assert_eq!(rounding_mode_floor_negative.get_amount(), -3);
-    /// assert_eq!(rounding_mode_ceil_negative.get_amount(), -2);
+    /// let parts = owo.allocate(&[70, 20, 10]).unwrap();
+    /// assert_eq!(parts, vec![Owo::new(700,ngn.clone()),Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone())]);
+    ///
+    /// assert!(owo.allocate(&[]).is_err());
+    /// assert!(owo.allocate(&[0, 0]).is_err());
     /// ```
-    pub fn percentage_with_mode(&self, percent: f64, mode: RoundingMode) -> Owo {
-        let raw =
-            (self.amount as f64 / 10f64.powi(self.currency.precision as i32)) * (percent / 100.0);
-        Owo {
-            amount: self.round_amount_with_mode(raw, mode),
-            currency: self.currency.clone(),
+    pub fn allocate(&self, ratios: &[u64]) -> Result<Vec<Owo>, OwoError> {
+        if ratios.is_empty() {
+            return Err(OwoError::EmptyCollection);
+        }
+        let total: i128 = ratios.iter().map(|&ratio| i128::from(ratio)).sum();
+        if total == 0 {
+            return Err(OwoError::DivisionByZero);
         }
-    }
-}
 
-// Addition
-impl Add for Owo {
-    type Output = Self;
+        let amount = i128::from(self.amount);
+        let mut shares: Vec<i128> = Vec::with_capacity(ratios.len());
+        let mut remainders: Vec<(usize, i128)> = Vec::with_capacity(ratios.len());
+        for (index, &ratio) in ratios.iter().enumerate() {
+            let weighted = amount * i128::from(ratio);
+            shares.push(weighted / total);
+            remainders.push((index, (weighted % total).abs()));
+        }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.currency, rhs.currency, "Currency mismatch in Add");
-        Self {
-            amount: self.amount + rhs.amount,
-            currency: self.currency,
+        // Hand out the leftover units one at a time, largest remainder first,
+        // keeping the original order among ties
+        let leftover = amount - shares.iter().sum::<i128>();
+        remainders.sort_by_key(|&(_, remainder)| std::cmp::Reverse(remainder));
+        for &(index, _) in remainders.iter().take(leftover.unsigned_abs() as usize) {
+            shares[index] += leftover.signum();
         }
-    }
-}
 
-// Subtraction
-impl Sub for Owo {
-    type Output = Self;
+        // Every share lies between zero and the original amount, so it fits
+        Ok(shares
+            .into_iter()
+            .map(|share| Owo {
+                amount: share as i64,
+                currency: self.currency,
+            })
+            .collect())
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.currency, rhs.currency, "Currency mismatch in Sub");
-        Self {
-            amount: self.amount - rhs.amount,
-            currency: self.currency,
+    /// Returns `self / other` as a plain number, e.g. `0.25` when `self` is a
+    /// quarter of `other`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let raised = Owo::new(2500,ngn.clone());
+    /// let target = Owo::new(10000,ngn.clone());
+    ///
+    /// assert_eq!(raised.ratio_of(&target).unwrap(), 0.25);
+    /// assert!(raised.ratio_of(&Owo::new(0,ngn.clone())).is_err());
+    /// assert!(raised.ratio_of(&Owo::new(100,Currency::new("USD", "$", 2))).is_err());
+    /// ```
+    pub fn ratio_of(&self, other: &Owo) -> Result<f64, OwoError> {
+        self.check_currency(other)?;
+        if other.amount == 0 {
+            return Err(OwoError::DivisionByZero);
         }
+        Ok(self.amount as f64 / other.amount as f64)
     }
-}
 
-// Multiplication by scalar
+    /// Returns what percent `self` is of `other`, the inverse of `percentage`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let discount = Owo::new(750,ngn.clone());
+    /// let price = Owo::new(10000,ngn.clone());
+    ///
+    /// assert_eq!(discount.percentage_of(&price).unwrap(), 7.5);
+    /// assert_eq!(price.percentage(7.5).unwrap(), discount);
+    /// ```
+    pub fn percentage_of(&self, other: &Owo) -> Result<f64, OwoError> {
+        Ok(self.ratio_of(other)? * 100.0)
+    }
+
+    /// Returns `self / other` as an exact `Decimal`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::Decimal;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let part = Owo::new(110,ngn.clone());
+    /// let whole = Owo::new(1000,ngn.clone());
+    ///
+    /// // 0.1 + 0.01 is not exactly 0.11 in f64, but it is as a Decimal
+    /// assert_eq!(part.ratio_of_decimal(&whole).unwrap(), Decimal::new(11, 2));
+    /// ```
+    #[cfg(feature = "decimal")]
+    pub fn ratio_of_decimal(&self, other: &Owo) -> Result<rust_decimal::Decimal, OwoError> {
+        self.check_currency(other)?;
+        rust_decimal::Decimal::from(self.amount)
+            .checked_div(rust_decimal::Decimal::from(other.amount))
+            .ok_or(OwoError::DivisionByZero)
+    }
+
+    /// Multiplies the amount by a scalar
+    ///
+    /// Returns `OwoError::InvalidScalar` if `scalar` is NaN or infinite, or
+    /// `OwoError::Overflow` if the result doesn't fit in an `i64`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(1230,ngn);
+    ///
+    /// assert_eq!(owo.multiply(1.5).unwrap().get_amount(),1845);
+    /// assert!(owo.multiply(f64::NAN).is_err());
+    /// ```
+    pub fn multiply(&self, scalar: f64) -> Result<Owo, OwoError> {
+        self.multiply_with_mode(scalar, RoundingMode::Nearest)
+    }
+
+    /// Divides the amount by a scalar
+    ///
+    /// Returns `OwoError::DivisionByZero` if `scalar` is zero,
+    /// `OwoError::InvalidScalar` if it is NaN or infinite, or
+    /// `OwoError::Overflow` if the result doesn't fit in an `i64`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(1000,ngn);
+    ///
+    /// assert_eq!(owo.divide(4.5).unwrap().get_amount(),222);
+    /// assert!(owo.divide(0.0).is_err());
+    /// assert!(owo.divide(f64::INFINITY).is_err());
+    /// ```
+    pub fn divide(&self, scalar: f64) -> Result<Owo, OwoError> {
+        self.divide_with_mode(scalar, RoundingMode::Nearest)
+    }
+
+    /// Returns a Owo representing a given percentage of the amount by a scalar
+    ///
+    /// Returns `OwoError::InvalidScalar` if `percent` is NaN or infinite, or
+    /// `OwoError::Overflow` if the result doesn't fit in an `i64`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(1000,ngn);
+    ///
+    /// assert_eq!(owo.percentage(0.5).unwrap().get_amount(),5);
+    /// assert!(owo.percentage(f64::NAN).is_err());
+    /// ```
+    pub fn percentage(&self, percent: f64) -> Result<Owo, OwoError> {
+        self.percentage_with_mode(percent, RoundingMode::Nearest)
+    }
+
+    /// Multiplies the amount by a scalar with rounding mode
+    ///
+    /// Returns `OwoError::InvalidScalar` if `scalar` is NaN or infinite, or
+    /// `OwoError::Overflow` if the result doesn't fit in an `i64`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(105,ngn);  // ₦1.05
+    /// // 1.05 * 2.5 = 2.625 → 2.63 (half up)
+    ///  let rounding_mode_nearest_even_half_up = owo.multiply_with_mode(2.5, RoundingMode::Nearest).unwrap();
+    ///
+    /// // 1.05 * 2.5 = 2.625 → floor = 2.62
+    /// let rounding_mode_floor_positive = owo.multiply_with_mode(2.5, RoundingMode::Floor).unwrap();
+    ///
+    ///  // 1.05 * 2.5 = 2.625 → ceil = 2.63
+    /// let rounding_mode_ceil_positive = owo.multiply_with_mode(2.5, RoundingMode::Ceil).unwrap();
+    ///
+    /// // 1.05 * -2.5 = -2.625 → floor = -2.63
+    /// let rounding_mode_floor_negative = owo.multiply_with_mode(-2.5, RoundingMode::Floor).unwrap();
+    ///
+    /// // 1.05 * -2.5 = -2.625 → ceil = -2.62
+    /// let rounding_mode_ceil_negative = owo.multiply_with_mode(-2.5, RoundingMode::Ceil).unwrap();
+    ///
+    /// assert_eq!(rounding_mode_nearest_even_half_up.get_amount(), 263); //₦2.63
+    /// assert_eq!(rounding_mode_floor_positive.get_amount(), 262);
+    /// assert_eq!(rounding_mode_ceil_positive.get_amount(), 263);
+    /// assert_eq!(rounding_mode_floor_negative.get_amount(), -263);
+    /// assert_eq!(rounding_mode_ceil_negative.get_amount(), -262);
+    /// assert!(owo.multiply_with_mode(f64::INFINITY, RoundingMode::Floor).is_err());
+    /// assert!(owo.multiply_with_mode(1e30, RoundingMode::Floor).is_err());
+    /// ```
+    pub fn multiply_with_mode(&self, scalar: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        Ok(Owo {
+            amount: self.multiplied_amount(scalar, mode)?,
+            currency: self.currency,
+        })
+    }
+
+    /// Divides the amount by a scalar with rounding mode
+    ///
+    /// Returns `OwoError::DivisionByZero` if `scalar` is zero,
+    /// `OwoError::InvalidScalar` if it is NaN or infinite, or
+    /// `OwoError::Overflow` if the result doesn't fit in an `i64`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(105,ngn);  // ₦1.05
+    /// // 1.05 / 2.8 = 0.375 → 0.38
+    ///  let rounding_mode_nearest_even_half_up = owo.divide_with_mode(2.8, RoundingMode::Nearest).unwrap();
+    ///
+    /// // 1.05 / 2.8 = 0.375 → floor = 0.37
+    /// let rounding_mode_floor_positive = owo.divide_with_mode(2.8, RoundingMode::Floor).unwrap();
+    ///
+    ///  // 1.05 / 2.8 = 0.375 → ceil = 0.38
+    /// let rounding_mode_ceil_positive = owo.divide_with_mode(2.8, RoundingMode::Ceil).unwrap();
+    ///
+    /// // 1.05 / -2.8 = -0.375 → floor = -0.38
+    /// let rounding_mode_floor_negative = owo.divide_with_mode(-2.8, RoundingMode::Floor).unwrap();
+    ///
+    /// // 1.05 / -2.8 = -0.375 → ceil = -0.37
+    /// let rounding_mode_ceil_negative = owo.divide_with_mode(-2.8, RoundingMode::Ceil).unwrap();
+    ///
+    /// assert_eq!(rounding_mode_nearest_even_half_up.get_amount(), 38); //₦0.38
+    /// assert_eq!(rounding_mode_floor_positive.get_amount(), 37);
+    /// assert_eq!(rounding_mode_ceil_positive.get_amount(), 38);
+    /// assert_eq!(rounding_mode_floor_negative.get_amount(), -38);
+    /// assert_eq!(rounding_mode_ceil_negative.get_amount(), -37);
+    /// assert!(owo.divide_with_mode(0.0, RoundingMode::Floor).is_err());
+    /// assert!(owo.divide_with_mode(1e-300, RoundingMode::Floor).is_err());
+    /// ```
+    pub fn divide_with_mode(&self, scalar: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        Ok(Owo {
+            amount: self.divided_amount(scalar, mode)?,
+            currency: self.currency,
+        })
+    }
+
+    /// Returns a Owo representing a given percentage of the amount by a scalar with rounding mode
+    ///
+    /// Returns `OwoError::InvalidScalar` if `percent` is NaN or infinite, or
+    /// `OwoError::Overflow` if the result doesn't fit in an `i64`.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let owo = Owo::new(105,ngn);  // ₦1.05
+    /// // 28% of 1.05 = 2.94 → 3
+    ///  let rounding_mode_nearest_even_half_up = owo.percentage_with_mode(2.8, RoundingMode::Nearest).unwrap();
+    ///
+    /// // 28% of 1.05 = 2.94 → floor = 2
+    /// let rounding_mode_floor_positive = owo.percentage_with_mode(2.8, RoundingMode::Floor).unwrap();
+    ///
+    ///  // 28% of 1.05 = 2.94 → ceil = 3
+    /// let rounding_mode_ceil_positive = owo.percentage_with_mode(2.8, RoundingMode::Ceil).unwrap();
+    ///
+    /// // -28% of 1.05 = -2.94 → floor = -3
+    /// let rounding_mode_floor_negative = owo.percentage_with_mode(-2.8, RoundingMode::Floor).unwrap();
+    ///
+    /// // -28% of 1.05 = -2.94 → ceil = -2
+    /// let rounding_mode_ceil_negative = owo.percentage_with_mode(-2.8, RoundingMode::Ceil).unwrap();
+    ///
+    /// assert_eq!(rounding_mode_nearest_even_half_up.get_amount(), 3); //₦3.00
+    /// assert_eq!(rounding_mode_floor_positive.get_amount(), 2);
+    /// assert_eq!(rounding_mode_ceil_positive.get_amount(), 3);
+    /// assert_eq!(rounding_mode_floor_negative.get_amount(), -3);
+    /// assert_eq!(rounding_mode_ceil_negative.get_amount(), -2);
+    /// assert!(owo.percentage_with_mode(f64::NAN, RoundingMode::Floor).is_err());
+    /// ```
+    pub fn percentage_with_mode(&self, percent: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        Ok(Owo {
+            amount: self.percentage_amount(percent, mode)?,
+            currency: self.currency,
+        })
+    }
+
+    /// Converts the amount into `target` using the rates in `rates`, rounding
+    /// to the target currency's precision with the given rounding mode
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let jpy = Currency::new("JPY", "¥", 0);
+    ///
+    /// let mut rates = RateTable::new();
+    /// rates.set_rate("USD", "NGN", 1500.0).unwrap();
+    /// rates.set_rate("USD", "JPY", 150.5).unwrap();
+    ///
+    /// let owo = Owo::new(1001, usd); // $10.01
+    ///
+    /// assert_eq!(owo.convert(&rates, &ngn, RoundingMode::Nearest).unwrap().get_amount(), 1501500);
+    /// // 10.01 * 150.5 = 1506.505 → floor = 1506
+    /// assert_eq!(owo.convert(&rates, &jpy, RoundingMode::Floor).unwrap().get_amount(), 1506);
+    /// assert!(owo.convert(&RateTable::new(), &ngn, RoundingMode::Nearest).is_err());
+    ///
+    /// rates.set_rate("USD", "NGN", 1e300).unwrap();
+    /// assert!(owo.convert(&rates, &ngn, RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn convert(
+        &self,
+        rates: &RateTable,
+        target: &Currency,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let rate = rates
+            .get_rate(self.currency.code, target.code)
+            .ok_or_else(|| {
+                OwoError::MissingRate(self.currency.code.to_string(), target.code.to_string())
+            })?;
+        self.convert_at_rate(rate, target, mode)
+    }
+
+    // Helper converting the amount into `target` at `rate`, rounding to the
+    // target currency's precision
+    pub(crate) fn convert_at_rate(
+        &self,
+        rate: f64,
+        target: &Currency,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let raw = (self.amount as f64 / self.currency.scale_factor_f64()) * rate;
+        let in_target = Owo::new(0, *target);
+        Ok(Owo {
+            amount: in_target
+                .checked_round_amount_with_mode(raw, mode)
+                .ok_or_else(|| self.overflow("convert", rate))?,
+            currency: *target,
+        })
+    }
+
+    /// Compares `self` with `other` after converting `other` into `self`'s
+    /// currency at `rate`, where one major unit of `other`'s currency buys
+    /// `rate` major units of `self`'s
+    ///
+    /// Returns the ordering and `self - other` in `self`'s currency, both
+    /// computed from `other` rounded to the nearest minor unit.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let eur = Currency::new("EUR", "€", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let invoice = Owo::new(100000,eur.clone());
+    /// let quote = Owo::new(105000,usd);
+    ///
+    /// // $1,050 at 0.92 EUR per USD is €966
+    /// let (ordering, difference) = invoice.cmp_with_rate(&quote, 0.92).unwrap();
+    /// assert_eq!(ordering, Ordering::Greater);
+    /// assert_eq!(difference, Owo::new(3400,eur));
+    ///
+    /// assert!(invoice.cmp_with_rate(&quote, f64::NAN).is_err());
+    /// ```
+    pub fn cmp_with_rate(&self, other: &Owo, rate: f64) -> Result<(Ordering, Owo), OwoError> {
+        let rate = Self::check_scalar(rate)?;
+        let raw = (other.amount as f64 / other.currency.scale_factor_f64()) * rate;
+        let converted = Owo {
+            amount: self
+                .checked_round_amount_with_mode(raw, RoundingMode::Nearest)
+                .ok_or_else(|| other.overflow("cmp_with_rate", rate))?,
+            currency: self.currency,
+        };
+        let difference = self.checked_sub(&converted)?;
+        Ok((self.amount.cmp(&converted.amount), difference))
+    }
+}
+
+// The operators route through the checked methods and panic with their
+// error, so overflow panics in release builds too instead of wrapping
+
+// Addition; panics on a currency mismatch or overflow, see `Owo::checked_add`
+impl Add for Owo {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+// Subtraction; panics on a currency mismatch or overflow, see
+// `Owo::checked_sub`
+impl Sub for Owo {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+// Multiplication by scalar; panics on overflow, see `Owo::checked_mul`
 impl Mul<i64> for Owo {
     type Output = Self;
 
     fn mul(self, rhs: i64) -> Self::Output {
-        Self {
-            amount: self.amount * rhs,
-            currency: self.currency,
-        }
+        self.checked_mul(rhs).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
-// Division by scalar
+// Division by scalar; panics on zero like integer division, see
+// `Owo::checked_div`
 impl Div<i64> for Owo {
     type Output = Self;
 
     fn div(self, rhs: i64) -> Self::Output {
-        Self {
-            amount: self.amount / rhs,
-            currency: self.currency,
-        }
+        self.checked_div(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+// Remainder of division by scalar; panics on zero like integer division, see
+// `Owo::checked_rem`
+impl Rem<i64> for Owo {
+    type Output = Self;
+
+    fn rem(self, rhs: i64) -> Self::Output {
+        self.checked_rem(rhs).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
+// Negation; panics on `i64::MIN`, see `Owo::checked_neg`
 impl Neg for Owo {
     type Output = Owo;
     fn neg(self) -> Owo {
-        Owo {
-            amount: -self.amount,
-            currency: self.currency,
-        }
+        self.checked_neg().unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl FromStr for Owo {
+    type Err = OwoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Owo::from_compact_str(s)
     }
 }
 
@@ -449,8 +1283,7 @@ impl PartialOrd for Owo {
     }
 }
 
-
-impl BatchOperations for Vec<Owo> {
+impl BatchOperations for [Owo] {
     /// Returns a collection of Owo representing the amount multiply by a scalar
     ///
     /// #Example
@@ -461,10 +1294,11 @@ impl BatchOperations for Vec<Owo> {
     /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
     ///
     /// //multiply every item by 1.5
-    /// assert_eq!(items.multiply_all(1.5),vec![Owo::new(1500,ngn.clone()),Owo::new(750,ngn.clone()),Owo::new(300,ngn.clone())]);
+    /// assert_eq!(items.multiply_all(1.5).unwrap(),vec![Owo::new(1500,ngn.clone()),Owo::new(750,ngn.clone()),Owo::new(300,ngn.clone())]);
+    /// assert!(items.multiply_all(f64::NAN).is_err());
     /// ```
-    fn multiply_all(&self, scalar: f64) -> Vec<Owo> {
-        self.iter().map(|c| c.multiply(scalar)).collect()
+    fn multiply_all(&self, scalar: f64) -> Result<Vec<Owo>, OwoError> {
+        self.multiply_all_with_mode(scalar, RoundingMode::Nearest)
     }
 
     /// Returns a collection of Owo representing the amount divided by a scalar
@@ -477,10 +1311,11 @@ impl BatchOperations for Vec<Owo> {
     /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
     ///
     /// //divide every item by 5
-    /// assert_eq!(items.divide_all(5.0),vec![Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(40,ngn.clone())]);
+    /// assert_eq!(items.divide_all(5.0).unwrap(),vec![Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(40,ngn.clone())]);
+    /// assert!(items.divide_all(0.0).is_err());
     /// ```
-    fn divide_all(&self, scalar: f64) -> Vec<Owo> {
-        self.iter().map(|c| c.divide(scalar)).collect()
+    fn divide_all(&self, scalar: f64) -> Result<Vec<Owo>, OwoError> {
+        self.divide_all_with_mode(scalar, RoundingMode::Nearest)
     }
 
     /// Returns a collection of Owo representing a given percentage of the amount
@@ -493,13 +1328,14 @@ impl BatchOperations for Vec<Owo> {
     /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
     ///
     /// // 50 % of every item
-    /// assert_eq!(items.percentage_all(50.0),vec![Owo::new(500,ngn.clone()),Owo::new(250,ngn.clone()),Owo::new(100,ngn.clone())]);
+    /// assert_eq!(items.percentage_all(50.0).unwrap(),vec![Owo::new(500,ngn.clone()),Owo::new(250,ngn.clone()),Owo::new(100,ngn.clone())]);
     /// ```
-    fn percentage_all(&self, percent: f64) -> Vec<Owo> {
-        self.iter().map(|c| c.percentage(percent)).collect()
+    fn percentage_all(&self, percent: f64) -> Result<Vec<Owo>, OwoError> {
+        self.percentage_all_with_mode(percent, RoundingMode::Nearest)
     }
 
-    /// Returns a collection of Owo representing the amount multiply by a scalar
+    /// Returns a collection of Owo representing the amount multiply by a scalar with rounding mode,
+    /// or the first error tagged with the index of the item that caused it
     ///
     /// #Example
     /// ```
@@ -509,15 +1345,19 @@ impl BatchOperations for Vec<Owo> {
     /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
     ///
     /// //multiply every item by 1.5
-    /// assert_eq!(items.multiply_all_with_mode(1.5,RoundingMode::Ceil),vec![Owo::new(1500,ngn.clone()),Owo::new(750,ngn.clone()),Owo::new(300,ngn.clone())]);
+    /// assert_eq!(items.multiply_all_with_mode(1.5,RoundingMode::Ceil).unwrap(),vec![Owo::new(1500,ngn.clone()),Owo::new(750,ngn.clone()),Owo::new(300,ngn.clone())]);
+    /// assert!(items.multiply_all_with_mode(1e300,RoundingMode::Ceil).is_err());
     /// ```
-    fn multiply_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Vec<Owo> {
-        self.iter()
-            .map(|c| c.multiply_with_mode(scalar, mode))
-            .collect()
+    fn multiply_all_with_mode(
+        &self,
+        scalar: f64,
+        mode: RoundingMode,
+    ) -> Result<Vec<Owo>, OwoError> {
+        try_map_indexed(self, |c| c.multiply_with_mode(scalar, mode))
     }
 
-    /// Returns a collection of Owo representing the amount divided by a scalar with rounding mode
+    /// Returns a collection of Owo representing the amount divided by a scalar with rounding mode,
+    /// or the first error tagged with the index of the item that caused it
     ///
     /// #Example
     /// ```
@@ -527,29 +1367,545 @@ impl BatchOperations for Vec<Owo> {
     /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
     ///
     /// //divide every item by 5
-    /// assert_eq!(items.divide_all_with_mode(5.0,RoundingMode::Ceil),vec![Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(40,ngn.clone())]);
+    /// assert_eq!(items.divide_all_with_mode(5.0,RoundingMode::Ceil).unwrap(),vec![Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(40,ngn.clone())]);
+    /// ```
+    fn divide_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Result<Vec<Owo>, OwoError> {
+        try_map_indexed(self, |c| c.divide_with_mode(scalar, mode))
+    }
+
+    /// Returns a collection of Owo representing a given percentage of the amount with rounding mode,
+    /// or the first error tagged with the index of the item that caused it
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    ///
+    /// // 50 % of every item
+    /// assert_eq!(items.percentage_all_with_mode(50.0,RoundingMode::Ceil).unwrap(),vec![Owo::new(500,ngn.clone()),Owo::new(250,ngn.clone()),Owo::new(100,ngn.clone())]);
+    /// assert!(items.percentage_all_with_mode(f64::INFINITY,RoundingMode::Ceil).is_err());
+    /// ```
+    fn percentage_all_with_mode(
+        &self,
+        percent: f64,
+        mode: RoundingMode,
+    ) -> Result<Vec<Owo>, OwoError> {
+        try_map_indexed(self, |c| c.percentage_with_mode(percent, mode))
+    }
+
+    /// Multiplies every item by a scalar in place, without allocating a new collection
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let mut items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// items.multiply_all_mut(1.5).unwrap();
+    ///
+    /// assert_eq!(items,vec![Owo::new(1500,ngn.clone()),Owo::new(750,ngn.clone()),Owo::new(300,ngn.clone())]);
+    /// ```
+    fn multiply_all_mut(&mut self, scalar: f64) -> Result<(), OwoError> {
+        self.multiply_all_with_mode_mut(scalar, RoundingMode::Nearest)
+    }
+
+    /// Multiplies every item by a scalar in place with rounding mode, without allocating a new collection
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let mut items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// items.multiply_all_with_mode_mut(1.5,RoundingMode::Ceil).unwrap();
+    ///
+    /// assert_eq!(items,vec![Owo::new(1500,ngn.clone()),Owo::new(750,ngn.clone()),Owo::new(300,ngn.clone())]);
+    /// ```
+    fn multiply_all_with_mode_mut(
+        &mut self,
+        scalar: f64,
+        mode: RoundingMode,
+    ) -> Result<(), OwoError> {
+        try_update_indexed(self, |c| c.multiplied_amount(scalar, mode))
+    }
+
+    /// Divides every item by a scalar in place, without allocating a new collection
+    ///
+    /// Returns `OwoError::DivisionByZero`, leaving the items untouched, if
+    /// `scalar` is zero.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let mut items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// items.divide_all_mut(5.0).unwrap();
+    ///
+    /// assert_eq!(items,vec![Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(40,ngn.clone())]);
+    /// assert!(items.divide_all_mut(0.0).is_err());
+    /// assert_eq!(items[0], Owo::new(200,ngn.clone()));
+    /// ```
+    fn divide_all_mut(&mut self, scalar: f64) -> Result<(), OwoError> {
+        self.divide_all_with_mode_mut(scalar, RoundingMode::Nearest)
+    }
+
+    /// Divides every item by a scalar in place with rounding mode, without allocating a new collection
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let mut items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// items.divide_all_with_mode_mut(5.0,RoundingMode::Ceil).unwrap();
+    ///
+    /// assert_eq!(items,vec![Owo::new(200,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(40,ngn.clone())]);
+    /// ```
+    fn divide_all_with_mode_mut(
+        &mut self,
+        scalar: f64,
+        mode: RoundingMode,
+    ) -> Result<(), OwoError> {
+        try_update_indexed(self, |c| c.divided_amount(scalar, mode))
+    }
+
+    /// Replaces every item with a given percentage of its amount in place, without allocating a new collection
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let mut items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// items.percentage_all_mut(50.0).unwrap();
+    ///
+    /// assert_eq!(items,vec![Owo::new(500,ngn.clone()),Owo::new(250,ngn.clone()),Owo::new(100,ngn.clone())]);
+    /// ```
+    fn percentage_all_mut(&mut self, percent: f64) -> Result<(), OwoError> {
+        self.percentage_all_with_mode_mut(percent, RoundingMode::Nearest)
+    }
+
+    /// Replaces every item with a given percentage of its amount in place with rounding mode, without allocating a new collection
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let mut items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// items.percentage_all_with_mode_mut(50.0,RoundingMode::Ceil).unwrap();
+    ///
+    /// assert_eq!(items,vec![Owo::new(500,ngn.clone()),Owo::new(250,ngn.clone()),Owo::new(100,ngn.clone())]);
+    /// ```
+    fn percentage_all_with_mode_mut(
+        &mut self,
+        percent: f64,
+        mode: RoundingMode,
+    ) -> Result<(), OwoError> {
+        try_update_indexed(self, |c| c.percentage_amount(percent, mode))
+    }
+
+    /// Converts every item into `target`, returning a result per item so a
+    /// missing rate for one currency doesn't discard the rest of the batch
+    ///
+    /// #Example
     /// ```
-    fn divide_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Vec<Owo> {
+    /// # use cowry::prelude::*;
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let eur = Currency::new("EUR", "€", 2);
+    /// let gbp = Currency::new("GBP", "£", 2);
+    ///
+    /// let mut rates = RateTable::new();
+    /// rates.set_rate("EUR", "USD", 1.1).unwrap();
+    ///
+    /// let items = vec![Owo::new(1000,usd.clone()),Owo::new(1000,eur.clone()),Owo::new(1000,gbp.clone())];
+    /// let converted = items.convert_all(&rates, &usd, RoundingMode::Nearest);
+    ///
+    /// assert_eq!(converted[0].as_ref().unwrap(), &Owo::new(1000,usd.clone()));
+    /// assert_eq!(converted[1].as_ref().unwrap(), &Owo::new(1100,usd.clone()));
+    /// assert!(converted[2].is_err());
+    /// ```
+    fn convert_all(
+        &self,
+        rates: &RateTable,
+        target: &Currency,
+        mode: RoundingMode,
+    ) -> Vec<Result<Owo, OwoError>> {
         self.iter()
-            .map(|c| c.divide_with_mode(scalar, mode))
+            .map(|c| c.convert(rates, target, mode))
             .collect()
     }
 
-    /// Returns a collection of Owo representing a given percentage of the amount with rounding mode
+    /// Returns the sum of every item, erroring on an empty collection, mixed
+    /// currencies or overflow
     ///
     /// #Example
     /// ```
     /// # use cowry::prelude::*;
     /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
     ///
     /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// assert_eq!(items.total().unwrap(), Owo::new(1700,ngn.clone()));
     ///
-    /// // 50 % of every item
-    /// assert_eq!(items.percentage_all_with_mode(50.0,RoundingMode::Ceil),vec![Owo::new(500,ngn.clone()),Owo::new(250,ngn.clone()),Owo::new(100,ngn.clone())]);
+    /// let mixed = vec![Owo::new(1000,ngn.clone()),Owo::new(500,usd.clone())];
+    /// assert!(mixed.total().is_err());
+    ///
+    /// let overflowing = vec![Owo::new(i64::MAX,ngn.clone()),Owo::new(1,ngn.clone())];
+    /// assert!(overflowing.total().is_err());
+    /// ```
+    fn total(&self) -> Result<Owo, OwoError> {
+        let (first, rest) = self.split_first().ok_or(OwoError::EmptyCollection)?;
+        rest.iter().try_fold(*first, |acc, c| acc.checked_add(c))
+    }
+
+    /// Returns the running total after each item, erroring on mixed currencies
+    /// or overflow
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(-300,ngn.clone()),Owo::new(200,ngn.clone())];
+    ///
+    /// assert_eq!(items.cumulative_sum().unwrap(),vec![Owo::new(1000,ngn.clone()),Owo::new(700,ngn.clone()),Owo::new(900,ngn.clone())]);
+    /// assert!(Vec::<Owo>::new().cumulative_sum().unwrap().is_empty());
+    /// ```
+    fn cumulative_sum(&self) -> Result<Vec<Owo>, OwoError> {
+        let mut running: Vec<Owo> = Vec::with_capacity(self.len());
+        for c in self {
+            let next = match running.last() {
+                Some(total) => total.checked_add(c)?,
+                None => *c,
+            };
+            running.push(next);
+        }
+        Ok(running)
+    }
+
+    /// Splits the items into credits (zero or positive) and debits (negative)
+    /// along with the total of each side
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(-300,ngn.clone()),Owo::new(200,ngn.clone())];
+    /// let split = items.partition_by_sign().unwrap();
+    ///
+    /// assert_eq!(split.credits, vec![Owo::new(1000,ngn.clone()),Owo::new(200,ngn.clone())]);
+    /// assert_eq!(split.debits, vec![Owo::new(-300,ngn.clone())]);
+    /// assert_eq!(split.credit_total, Owo::new(1200,ngn.clone()));
+    /// assert_eq!(split.debit_total, Owo::new(-300,ngn.clone()));
+    /// ```
+    fn partition_by_sign(&self) -> Result<SignPartition, OwoError> {
+        let first = common_currency(self)?;
+        let zero = Owo::new(0, first.currency);
+        let (credits, debits): (Vec<Owo>, Vec<Owo>) =
+            self.iter().cloned().partition(|c| c.amount >= 0);
+        let credit_total = credits.iter().try_fold(zero, |acc, c| acc.checked_add(c))?;
+        let debit_total = debits.iter().try_fold(zero, |acc, c| acc.checked_add(c))?;
+        Ok(SignPartition {
+            credits,
+            debits,
+            credit_total,
+            debit_total,
+        })
+    }
+
+    /// Formats every item into one string, joined by `separator`, writing
+    /// into a single buffer instead of allocating a string per item
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let items = vec![Owo::new(500,ngn.clone()),Owo::new(1250,ngn.clone())];
+    ///
+    /// assert_eq!(items.format_all("\n"), "₦5.00\n₦12.50");
+    /// ```
+    fn format_all(&self, separator: &str) -> String {
+        let mut out = String::with_capacity(self.len() * 12);
+        for (index, owo) in self.iter().enumerate() {
+            if index > 0 {
+                out.push_str(separator);
+            }
+            owo.format_into(&mut out);
+        }
+        out
+    }
+
+    /// Returns the items whose currency code is `code`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(300,usd.clone()),Owo::new(500,ngn.clone())];
+    ///
+    /// assert_eq!(items.filter_by_currency("USD"), vec![Owo::new(300,usd.clone())]);
+    /// assert_eq!(items.filter_by_currency("NGN").total().unwrap(), Owo::new(1500,ngn.clone()));
     /// ```
-    fn percentage_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Vec<Owo> {
+    fn filter_by_currency(&self, code: &str) -> Vec<Owo> {
         self.iter()
-            .map(|c| c.percentage_with_mode(scalar, mode))
+            .filter(|c| c.currency.code == code)
+            .cloned()
             .collect()
     }
+
+    /// Splits the items into those whose currency code is `code` and the rest
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(300,usd.clone()),Owo::new(500,ngn.clone())];
+    /// let (usd_items, rest) = items.partition_by_currency("USD");
+    ///
+    /// assert_eq!(usd_items, vec![Owo::new(300,usd.clone())]);
+    /// assert_eq!(rest, vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone())]);
+    /// ```
+    fn partition_by_currency(&self, code: &str) -> (Vec<Owo>, Vec<Owo>) {
+        self.iter().cloned().partition(|c| c.currency.code == code)
+    }
+
+    /// Returns the mean of every item, rounded with the given rounding mode
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone()),Owo::new(200,ngn.clone())];
+    ///
+    /// // 1700 / 3 = 566.67
+    /// assert_eq!(items.average_with_mode(RoundingMode::Nearest).unwrap(), Owo::new(567,ngn.clone()));
+    /// assert_eq!(items.average_with_mode(RoundingMode::Floor).unwrap(), Owo::new(566,ngn.clone()));
+    /// assert!(Vec::<Owo>::new().average_with_mode(RoundingMode::Nearest).is_err());
+    ///
+    /// // Exact beyond the 2^53 limit of f64
+    /// let large = vec![Owo::new(i64::MAX,ngn.clone()),Owo::new(i64::MAX - 2,ngn.clone())];
+    /// assert_eq!(large.average_with_mode(RoundingMode::Floor).unwrap(), Owo::new(i64::MAX - 1,ngn.clone()));
+    /// ```
+    fn average_with_mode(&self, mode: RoundingMode) -> Result<Owo, OwoError> {
+        let first = common_currency(self)?;
+        let sum: i128 = self.iter().map(|c| c.amount as i128).sum();
+        // The mean lies between the smallest and largest item, so it fits
+        let mean = round_div(sum, self.len() as i128, mode);
+        Ok(Owo {
+            amount: mean as i64,
+            currency: first.currency,
+        })
+    }
+
+    /// Returns the smallest item
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(200,ngn.clone()),Owo::new(500,ngn.clone())];
+    /// assert_eq!(items.min().unwrap(), &Owo::new(200,ngn.clone()));
+    ///
+    /// let mixed = vec![Owo::new(1000,ngn.clone()),Owo::new(500,usd.clone())];
+    /// assert!(mixed.min().is_err());
+    /// ```
+    fn min(&self) -> Result<&Owo, OwoError> {
+        extreme_by(self, |candidate, best| candidate.amount < best.amount)
+    }
+
+    /// Returns the largest item
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(200,ngn.clone()),Owo::new(500,ngn.clone())];
+    /// assert_eq!(items.max().unwrap(), &Owo::new(1000,ngn.clone()));
+    /// assert!(Vec::<Owo>::new().max().is_err());
+    /// ```
+    fn max(&self) -> Result<&Owo, OwoError> {
+        extreme_by(self, |candidate, best| candidate.amount > best.amount)
+    }
+}
+
+impl<I: Iterator<Item = Owo>> OwoIterExt for I {
+    /// Sums the iterator, erroring on an empty iterator, mixed currencies or
+    /// overflow
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone())];
+    /// assert_eq!(items.into_iter().try_sum().unwrap(), Owo::new(1500,ngn.clone()));
+    /// assert!(std::iter::empty::<Owo>().try_sum().is_err());
+    /// ```
+    fn try_sum(mut self) -> Result<Owo, OwoError> {
+        let first = self.next().ok_or(OwoError::EmptyCollection)?;
+        self.try_fold(first, |acc, c| acc.checked_add(&c))
+    }
+
+    /// Applies `f` to the minor-unit amount of every item, keeping its currency
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone())];
+    /// let doubled: Vec<Owo> = items.into_iter().map_amounts(|a| a * 2).collect();
+    ///
+    /// assert_eq!(doubled, vec![Owo::new(2000,ngn.clone()),Owo::new(1000,ngn.clone())]);
+    /// ```
+    fn map_amounts<F: FnMut(i64) -> i64>(self, mut f: F) -> impl Iterator<Item = Owo> {
+        self.map(move |c| Owo {
+            amount: f(c.amount),
+            currency: c.currency,
+        })
+    }
+
+    /// Keeps only the items whose currency code is `code`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(300,usd.clone()),Owo::new(500,ngn.clone())];
+    /// let total = items.into_iter().filter_currency("NGN").try_sum().unwrap();
+    ///
+    /// assert_eq!(total, Owo::new(1500,ngn.clone()));
+    /// ```
+    fn filter_currency(self, code: &str) -> impl Iterator<Item = Owo> {
+        self.filter(move |c| c.currency.code == code)
+    }
+
+    /// Returns the item with the largest amount, erroring on an empty iterator
+    /// or mixed currencies
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    ///
+    /// let items = vec![Owo::new(1000,ngn.clone()),Owo::new(3000,ngn.clone()),Owo::new(500,ngn.clone())];
+    /// assert_eq!(items.into_iter().max_amount().unwrap(), Owo::new(3000,ngn.clone()));
+    /// ```
+    fn max_amount(mut self) -> Result<Owo, OwoError> {
+        let first = self.next().ok_or(OwoError::EmptyCollection)?;
+        self.try_fold(first, |best, c| {
+            if c.currency != best.currency {
+                return Err(OwoError::CurrencyMismatch(
+                    best.currency.code.to_string(),
+                    c.currency.code.to_string(),
+                ));
+            }
+            Ok(if c.amount > best.amount { c } else { best })
+        })
+    }
+}
+
+// Helper applying a fallible operation to every item, tagging the first
+// error with the index of the item that caused it
+fn try_map_indexed(
+    items: &[Owo],
+    f: impl Fn(&Owo) -> Result<Owo, OwoError>,
+) -> Result<Vec<Owo>, OwoError> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, c)| {
+            f(c).map_err(|source| OwoError::AtIndex {
+                index,
+                source: Box::new(source),
+            })
+        })
+        .collect()
+}
+
+// Helper replacing every item's amount with `f`, leaving the items untouched
+// unless every item succeeds, and tagging the first error with its index
+fn try_update_indexed(
+    items: &mut [Owo],
+    f: impl Fn(&Owo) -> Result<i64, OwoError>,
+) -> Result<(), OwoError> {
+    for (index, c) in items.iter().enumerate() {
+        f(c).map_err(|source| OwoError::AtIndex {
+            index,
+            source: Box::new(source),
+        })?;
+    }
+    for c in items.iter_mut() {
+        c.amount = f(c)?;
+    }
+    Ok(())
+}
+
+// Helper parsing a plain decimal string into minor units at `precision`
+pub(crate) fn parse_decimal(input: &str, precision: u8) -> Result<i64, OwoError> {
+    let parse_error = |reason: &str| OwoError::ParseError {
+        input: input.to_string(),
+        reason: reason.to_string(),
+    };
+    let (negative, unsigned) = match input.as_bytes().first() {
+        Some(b'-') => (true, &input[1..]),
+        Some(b'+') => (false, &input[1..]),
+        _ => (false, input),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return Err(parse_error("expected a decimal number"));
+    }
+    if unsigned.ends_with('.') {
+        return Err(parse_error("missing digits after the decimal point"));
+    }
+    if fraction.len() > precision as usize {
+        return Err(parse_error(&format!(
+            "more than {} fraction digits",
+            precision
+        )));
+    }
+
+    let digits = format!("{}{:0<width$}", whole, fraction, width = precision as usize);
+    let magnitude: i128 = digits
+        .parse()
+        .map_err(|_| parse_error("amount out of range"))?;
+    let amount = if negative { -magnitude } else { magnitude };
+    i64::try_from(amount).map_err(|_| parse_error("amount out of range"))
+}
+
+// Helper returning the first item, erroring if the slice is empty or mixes currencies
+pub(crate) fn common_currency(items: &[Owo]) -> Result<&Owo, OwoError> {
+    let first = items.first().ok_or(OwoError::EmptyCollection)?;
+    match items.iter().find(|c| c.currency != first.currency) {
+        Some(other) => Err(OwoError::CurrencyMismatch(
+            first.currency.code.to_string(),
+            other.currency.code.to_string(),
+        )),
+        None => Ok(first),
+    }
+}
+
+// Helper for min/max: picks the item `better` prefers, requiring a single currency
+fn extreme_by(items: &[Owo], better: impl Fn(&Owo, &Owo) -> bool) -> Result<&Owo, OwoError> {
+    let first = common_currency(items)?;
+    Ok(items
+        .iter()
+        .fold(first, |best, c| if better(c, best) { c } else { best }))
 }
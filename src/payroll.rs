@@ -0,0 +1,194 @@
+//! Gross-to-net payroll: ordered deductions applied to a gross wage.
+//!
+//! Each deduction is worked out on what the deductions before it leave, so
+//! pre-tax deductions such as pensions go first and income tax bands after
+//! them. Percentages are in percent, as with `Owo::percentage`.
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// One band of a progressive schedule: `rate` percent on the part of the
+/// base up to `up_to`, or on everything above the previous band if `None`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Band {
+    pub up_to: Option<Owo>,
+    pub rate: f64,
+}
+
+/// A deduction taken from pay.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Deduction {
+    /// A percentage of the base.
+    Percentage(f64),
+    /// A fixed amount.
+    Fixed(Owo),
+    /// A percentage of the base, but never more than `cap`.
+    Capped { percent: f64, cap: Owo },
+    /// Progressive bands, lowest first; income above the last bounded band
+    /// is not charged.
+    Banded(Vec<Band>),
+}
+
+/// One deduction on a payslip.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeductionLine {
+    pub name: String,
+    pub amount: Owo,
+}
+
+/// A gross wage, its itemized deductions and the net pay they leave.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Payslip {
+    pub gross: Owo,
+    pub deductions: Vec<DeductionLine>,
+    /// The sum of the deduction lines.
+    pub total_deductions: Owo,
+    /// Always `gross - total_deductions`.
+    pub net: Owo,
+}
+
+/// An ordered list of named deductions.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Payroll {
+    deductions: Vec<(String, Deduction)>,
+}
+
+impl Deduction {
+    /// Returns this deduction on `base`, rounded once with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::payroll::{Band, Deduction};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let bands = Deduction::Banded(vec![
+    ///     Band { up_to: Some(Owo::new(30000000,ngn.clone())), rate: 7.0 },
+    ///     Band { up_to: Some(Owo::new(60000000,ngn.clone())), rate: 11.0 },
+    ///     Band { up_to: None, rate: 15.0 },
+    /// ]);
+    ///
+    /// // 7% of ₦300,000, 11% of ₦300,000 and 15% of ₦100,000
+    /// let tax = bands.amount(&Owo::new(70000000,ngn.clone()), RoundingMode::Nearest).unwrap();
+    /// assert_eq!(tax, Owo::new(6900000,ngn.clone()));
+    /// ```
+    pub fn amount(&self, base: &Owo, mode: RoundingMode) -> Result<Owo, OwoError> {
+        match self {
+            Deduction::Percentage(percent) => base.percentage_with_mode(*percent, mode),
+            Deduction::Fixed(amount) => {
+                base.check_currency(amount)?;
+                Ok(*amount)
+            }
+            Deduction::Capped { percent, cap } => {
+                base.check_currency(cap)?;
+                let amount = base.percentage_with_mode(*percent, mode)?;
+                Ok(if amount.amount > cap.amount {
+                    *cap
+                } else {
+                    amount
+                })
+            }
+            Deduction::Banded(bands) => {
+                // Sum the charge band by band, then round the total once
+                let mut floor = 0i64;
+                let mut raw = 0.0;
+                for band in bands {
+                    if !band.rate.is_finite() {
+                        return Err(OwoError::InvalidScalar(band.rate));
+                    }
+                    let ceiling = match &band.up_to {
+                        Some(up_to) => {
+                            base.check_currency(up_to)?;
+                            if up_to.amount <= floor {
+                                return Err(OwoError::InvalidTier(format!(
+                                    "band up to {} does not rise above the one before it",
+                                    up_to.to_compact_string()
+                                )));
+                            }
+                            up_to.amount
+                        }
+                        None => i64::MAX,
+                    };
+                    let portion = base.amount.min(ceiling) - floor;
+                    if portion > 0 {
+                        raw += portion as f64 * band.rate / 100.0;
+                    }
+                    if band.up_to.is_none() {
+                        break;
+                    }
+                    floor = ceiling;
+                }
+                let minor = Owo::new(1, base.currency);
+                minor.multiply_with_mode(raw, mode)
+            }
+        }
+    }
+}
+
+impl Payroll {
+    /// Creates a payroll with no deductions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a deduction, worked out on what the earlier ones leave.
+    pub fn add_deduction(&mut self, name: &str, deduction: Deduction) {
+        self.deductions.push((name.to_string(), deduction));
+    }
+
+    pub fn deductions(&self) -> &[(String, Deduction)] {
+        &self.deductions
+    }
+
+    /// Runs `gross` through the deductions in order, never taking net pay
+    /// below zero
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::payroll::{Band, Deduction, Payroll};
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut payroll = Payroll::new();
+    /// payroll.add_deduction("pension", Deduction::Percentage(8.0));
+    /// payroll.add_deduction("income tax", Deduction::Banded(vec![
+    ///     Band { up_to: Some(Owo::new(100000,usd.clone())), rate: 0.0 },
+    ///     Band { up_to: None, rate: 20.0 },
+    /// ]));
+    /// payroll.add_deduction("union dues", Deduction::Fixed(Owo::new(1500,usd.clone())));
+    ///
+    /// let slip = payroll.run(&Owo::new(500000,usd.clone()), RoundingMode::Nearest).unwrap();
+    ///
+    /// // Pension $400.00; tax 20% of the $3,600.00 above $1,000.00 of the $4,600.00 left
+    /// assert_eq!(slip.deductions[0].amount, Owo::new(40000,usd.clone()));
+    /// assert_eq!(slip.deductions[1].amount, Owo::new(72000,usd.clone()));
+    /// assert_eq!(slip.total_deductions, Owo::new(113500,usd.clone()));
+    /// assert_eq!(slip.net, Owo::new(386500,usd.clone()));
+    /// ```
+    pub fn run(&self, gross: &Owo, mode: RoundingMode) -> Result<Payslip, OwoError> {
+        let mut remaining = *gross;
+        let mut deductions = Vec::with_capacity(self.deductions.len());
+        for (index, (name, deduction)) in self.deductions.iter().enumerate() {
+            let mut amount =
+                deduction
+                    .amount(&remaining, mode)
+                    .map_err(|source| OwoError::AtIndex {
+                        index,
+                        source: Box::new(source),
+                    })?;
+            amount.amount = amount.amount.clamp(0, remaining.amount.max(0));
+            remaining = remaining.checked_sub(&amount)?;
+            deductions.push(DeductionLine {
+                name: name.clone(),
+                amount,
+            });
+        }
+        Ok(Payslip {
+            gross: *gross,
+            deductions,
+            total_deductions: gross.checked_sub(&remaining)?,
+            net: remaining,
+        })
+    }
+}
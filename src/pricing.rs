@@ -0,0 +1,260 @@
+//! Pricing helpers: markup and margin on a cost, and tiered pricing for
+//! usage-based billing such as "first 1,000 units at ₦10, next 9,000 at ₦8,
+//! the rest at ₦5".
+//!
+//! Markup is profit as a percentage of cost; margin is profit as a
+//! percentage of price. A ₦100 cost sold at ₦125 is a 25% markup but a 20%
+//! margin. Tier unit prices are whole minor units, so tier totals are exact
+//! integer products with no rounding.
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+impl Owo {
+    /// Returns the price that puts a `percent` markup on this cost, rounded
+    /// with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let cost = Owo::new(10000,ngn.clone());
+    ///
+    /// assert_eq!(cost.with_markup(25.0, RoundingMode::Nearest).unwrap(), Owo::new(12500,ngn.clone()));
+    /// ```
+    pub fn with_markup(&self, percent: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        self.multiply_with_mode(1.0 + percent / 100.0, mode)
+    }
+
+    /// Returns the price that leaves a `percent` margin on this cost, rounded
+    /// with `mode`
+    ///
+    /// Returns an error for margins of 100% or more, which no price reaches.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let cost = Owo::new(10000,ngn.clone());
+    ///
+    /// assert_eq!(cost.with_margin(20.0, RoundingMode::Nearest).unwrap(), Owo::new(12500,ngn.clone()));
+    /// assert!(cost.with_margin(100.0, RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn with_margin(&self, percent: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+        if percent >= 100.0 {
+            return Err(OwoError::InvalidScalar(percent));
+        }
+        self.divide_with_mode(1.0 - percent / 100.0, mode)
+    }
+}
+
+/// Returns the markup of `price` over `cost`, as a percentage of cost
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::pricing::markup_of;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let markup = markup_of(&Owo::new(10000,ngn.clone()), &Owo::new(12500,ngn.clone())).unwrap();
+/// assert!((markup - 25.0).abs() < 1e-9);
+///
+/// assert!(markup_of(&Owo::new(0,ngn.clone()), &Owo::new(12500,ngn.clone())).is_err());
+/// ```
+pub fn markup_of(cost: &Owo, price: &Owo) -> Result<f64, OwoError> {
+    Ok((price.ratio_of(cost)? - 1.0) * 100.0)
+}
+
+/// Returns the margin `price` leaves over `cost`, as a percentage of price
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::pricing::margin_of;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let margin = margin_of(&Owo::new(10000,ngn.clone()), &Owo::new(12500,ngn.clone())).unwrap();
+/// assert!((margin - 20.0).abs() < 1e-9);
+/// ```
+pub fn margin_of(cost: &Owo, price: &Owo) -> Result<f64, OwoError> {
+    Ok((1.0 - cost.ratio_of(price)?) * 100.0)
+}
+
+/// How tier prices apply to a quantity.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TierMode {
+    /// Each unit is charged at the price of the tier it falls in.
+    #[default]
+    Graduated,
+    /// Every unit is charged at the price of the tier the total falls in.
+    Volume,
+}
+
+/// One pricing tier, covering units up to and including `up_to`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Tier {
+    /// The last unit covered, or `None` for every unit after the previous tier.
+    pub up_to: Option<u64>,
+    pub unit_price: Owo,
+}
+
+/// The charge for the units billed in one tier.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TierCharge {
+    /// The index of the tier in the schedule.
+    pub tier: usize,
+    pub quantity: u64,
+    pub unit_price: Owo,
+    /// `quantity * unit_price`.
+    pub amount: Owo,
+}
+
+/// A priced quantity with its per-tier breakdown.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TieredTotal {
+    /// The tiers that billed any units, in order.
+    pub charges: Vec<TierCharge>,
+    /// The sum of every charge.
+    pub total: Owo,
+}
+
+/// A schedule of pricing tiers in one currency.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TieredPricing {
+    tiers: Vec<Tier>,
+    mode: TierMode,
+}
+
+impl TieredPricing {
+    /// Creates an empty schedule applied by `mode`.
+    pub fn new(mode: TierMode) -> Self {
+        Self {
+            tiers: Vec::new(),
+            mode,
+        }
+    }
+
+    /// Appends a tier, which must end after the previous one and share its
+    /// currency. Nothing may follow an open-ended tier.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::pricing::{TierMode, TieredPricing};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut pricing = TieredPricing::new(TierMode::Graduated);
+    ///
+    /// assert!(pricing.add_tier(Some(1000), Owo::new(1000,ngn.clone())).is_ok());
+    /// assert!(pricing.add_tier(Some(500), Owo::new(800,ngn.clone())).is_err());
+    /// assert!(pricing.add_tier(None, Owo::new(500,ngn.clone())).is_ok());
+    /// assert!(pricing.add_tier(Some(20000), Owo::new(100,ngn.clone())).is_err());
+    /// ```
+    pub fn add_tier(&mut self, up_to: Option<u64>, unit_price: Owo) -> Result<(), OwoError> {
+        if let Some(last) = self.tiers.last() {
+            last.unit_price.check_currency(&unit_price)?;
+            match (last.up_to, up_to) {
+                (None, _) => {
+                    return Err(OwoError::InvalidTier(
+                        "no tier can follow an open-ended tier".into(),
+                    ));
+                }
+                (Some(previous), Some(end)) if end <= previous => {
+                    return Err(OwoError::InvalidTier(format!(
+                        "tier ending at {end} does not follow tier ending at {previous}"
+                    )));
+                }
+                _ => {}
+            }
+        }
+        self.tiers.push(Tier { up_to, unit_price });
+        Ok(())
+    }
+
+    /// Returns the tiers in order.
+    pub fn tiers(&self) -> &[Tier] {
+        &self.tiers
+    }
+
+    /// Prices `quantity` units, returning the charge for each tier used
+    ///
+    /// Returns an error if there are no tiers or the quantity runs past the
+    /// last one.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::pricing::{TierMode, TieredPricing};
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let mut pricing = TieredPricing::new(TierMode::Graduated);
+    /// pricing.add_tier(Some(1000), Owo::new(1000,ngn.clone())).unwrap();
+    /// pricing.add_tier(Some(10000), Owo::new(800,ngn.clone())).unwrap();
+    /// pricing.add_tier(None, Owo::new(500,ngn.clone())).unwrap();
+    ///
+    /// let bill = pricing.price(12000).unwrap();
+    /// let quantities: Vec<u64> = bill.charges.iter().map(|charge| charge.quantity).collect();
+    /// assert_eq!(quantities, vec![1000, 9000, 2000]);
+    /// assert_eq!(bill.total, Owo::new(9200000,ngn.clone()));
+    ///
+    /// let mut volume = TieredPricing::new(TierMode::Volume);
+    /// for tier in pricing.tiers() {
+    ///     volume.add_tier(tier.up_to, tier.unit_price.clone()).unwrap();
+    /// }
+    /// assert_eq!(volume.price(12000).unwrap().total, Owo::new(6000000,ngn.clone()));
+    /// ```
+    pub fn price(&self, quantity: u64) -> Result<TieredTotal, OwoError> {
+        let first = self
+            .tiers
+            .first()
+            .ok_or_else(|| OwoError::InvalidTier("no tiers defined".into()))?;
+        let mut charges = Vec::new();
+        let mut billed = 0;
+        for (index, tier) in self.tiers.iter().enumerate() {
+            if billed == quantity {
+                break;
+            }
+            let end = tier.up_to.unwrap_or(u64::MAX).min(quantity);
+            match self.mode {
+                TierMode::Graduated => {
+                    if end > billed {
+                        charges.push(charge(index, tier, end - billed)?);
+                    }
+                    billed = end;
+                }
+                TierMode::Volume if tier.up_to.is_none_or(|up_to| quantity <= up_to) => {
+                    charges.push(charge(index, tier, quantity)?);
+                    billed = quantity;
+                }
+                TierMode::Volume => {}
+            }
+        }
+        if billed < quantity {
+            return Err(OwoError::InvalidTier(format!(
+                "quantity {quantity} runs past the last tier"
+            )));
+        }
+
+        let mut total = Owo::new(0, first.unit_price.currency);
+        for item in &charges {
+            total = total.checked_add(&item.amount)?;
+        }
+        Ok(TieredTotal { charges, total })
+    }
+}
+
+// Helper charging `quantity` units at `tier`'s price
+fn charge(index: usize, tier: &Tier, quantity: u64) -> Result<TierCharge, OwoError> {
+    let unit_price = &tier.unit_price;
+    let amount = i64::try_from(quantity)
+        .ok()
+        .and_then(|quantity| unit_price.amount.checked_mul(quantity))
+        .ok_or_else(|| unit_price.overflow("price", quantity))?;
+    Ok(TierCharge {
+        tier: index,
+        quantity,
+        unit_price: *unit_price,
+        amount: Owo::new(amount, unit_price.currency),
+    })
+}
@@ -0,0 +1,199 @@
+//! Python bindings through PyO3, so data and finance pipelines reuse the
+//! exact same rounding and allocation rules as the Rust services.
+//!
+//! The classes are exported to Python as `Owo`, `Currency` and
+//! `RoundingMode` in a module named `cowry`. Errors surface as
+//! `ValueError`, `ZeroDivisionError` or `OverflowError`. Build the extension
+//! with `cargo rustc --lib --crate-type cdylib --features python,pyo3/extension-module`.
+//!
+//! ```python
+//! from cowry import Owo, Currency, RoundingMode
+//!
+//! price = Owo(1050, Currency.from_code("USD"))
+//! price.multiply(3, RoundingMode.Nearest).format()  # "$31.50"
+//! [str(part) for part in Owo.parse("NGN 10.00").allocate([1, 1, 1])]
+//! # ["NGN 3.34", "NGN 3.33", "NGN 3.33"]
+//! ```
+
+use crate::error::OwoError;
+use crate::{Currency, Owo, RoundingMode, registry};
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+
+impl From<OwoError> for PyErr {
+    fn from(error: OwoError) -> Self {
+        match error {
+            OwoError::DivisionByZero => PyZeroDivisionError::new_err(error.to_string()),
+            OwoError::Overflow { .. } => PyOverflowError::new_err(error.to_string()),
+            _ => PyValueError::new_err(error.to_string()),
+        }
+    }
+}
+
+/// A currency definition, exported to Python as `Currency`.
+#[pyclass(name = "Currency", module = "cowry", frozen)]
+#[derive(Clone)]
+pub struct PyCurrency(Currency);
+
+#[pymethods]
+impl PyCurrency {
+    /// Creates a currency definition from the ISO 4217 registry or a
+    /// currency added with `Currency.register`, rejecting unknown codes and
+    /// symbols.
+    #[new]
+    fn new(code: &str, symbol: &str, precision: u8) -> PyResult<Self> {
+        Ok(PyCurrency(Currency::resolve(code, symbol, precision)?))
+    }
+
+    /// Registers a custom currency definition for the life of the process,
+    /// rejecting unsupported precisions. Only register currencies the
+    /// application defines, never ones taken from user input.
+    #[staticmethod]
+    fn register(code: &str, symbol: &str, precision: u8) -> PyResult<Self> {
+        Ok(PyCurrency(Currency::try_new(code, symbol, precision)?))
+    }
+
+    /// Looks up an ISO 4217 currency by code.
+    #[staticmethod]
+    fn from_code(code: &str) -> PyResult<Self> {
+        Ok(PyCurrency(registry::currency(code)?))
+    }
+
+    #[getter]
+    fn code(&self) -> &str {
+        self.0.code
+    }
+
+    #[getter]
+    fn symbol(&self) -> &str {
+        self.0.symbol
+    }
+
+    #[getter]
+    fn precision(&self) -> u8 {
+        self.0.precision
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Currency({:?}, {:?}, {})",
+            self.0.code, self.0.symbol, self.0.precision
+        )
+    }
+}
+
+/// An amount of money, exported to Python as `Owo`.
+#[pyclass(name = "Owo", module = "cowry", frozen)]
+#[derive(Clone)]
+pub struct PyOwo(Owo);
+
+#[pymethods]
+impl PyOwo {
+    /// Creates an amount from minor units.
+    #[new]
+    fn new(amount: i64, currency: &PyCurrency) -> Self {
+        PyOwo(Owo::new(amount, currency.0))
+    }
+
+    /// Parses a compact string such as `"USD 5.99"`.
+    #[staticmethod]
+    fn parse(input: &str) -> PyResult<Self> {
+        Ok(PyOwo(Owo::from_compact_str(input)?))
+    }
+
+    /// Parses a plain decimal string such as `"5.99"` in the given currency.
+    #[staticmethod]
+    fn from_decimal(input: &str, currency: &PyCurrency) -> PyResult<Self> {
+        Ok(PyOwo(Owo::from_decimal_str(input, currency.0)?))
+    }
+
+    #[getter]
+    fn amount(&self) -> i64 {
+        self.0.amount
+    }
+
+    #[getter]
+    fn currency(&self) -> PyCurrency {
+        PyCurrency(self.0.currency)
+    }
+
+    fn format(&self) -> String {
+        self.0.format()
+    }
+
+    fn to_decimal_string(&self) -> String {
+        self.0.to_decimal_string()
+    }
+
+    #[pyo3(signature = (scalar, mode = RoundingMode::Nearest))]
+    fn multiply(&self, scalar: f64, mode: RoundingMode) -> PyResult<Self> {
+        Ok(PyOwo(self.0.multiply_with_mode(scalar, mode)?))
+    }
+
+    #[pyo3(signature = (scalar, mode = RoundingMode::Nearest))]
+    fn divide(&self, scalar: f64, mode: RoundingMode) -> PyResult<Self> {
+        Ok(PyOwo(self.0.divide_with_mode(scalar, mode)?))
+    }
+
+    #[pyo3(signature = (percent, mode = RoundingMode::Nearest))]
+    fn percentage(&self, percent: f64, mode: RoundingMode) -> PyResult<Self> {
+        Ok(PyOwo(self.0.percentage_with_mode(percent, mode)?))
+    }
+
+    /// Splits the amount by `ratios`, with parts always summing to the total.
+    fn allocate(&self, ratios: Vec<u64>) -> PyResult<Vec<Self>> {
+        Ok(self.0.allocate(&ratios)?.into_iter().map(PyOwo).collect())
+    }
+
+    fn __add__(&self, other: &Self) -> PyResult<Self> {
+        Ok(PyOwo(self.0.checked_add(&other.0)?))
+    }
+
+    fn __sub__(&self, other: &Self) -> PyResult<Self> {
+        Ok(PyOwo(self.0.checked_sub(&other.0)?))
+    }
+
+    fn __neg__(&self) -> PyResult<Self> {
+        let amount = self
+            .0
+            .amount
+            .checked_neg()
+            .ok_or_else(|| self.0.overflow("neg", 0))?;
+        Ok(PyOwo(Owo::new(amount, self.0.currency)))
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => Ok(self.0 == other.0),
+            CompareOp::Ne => Ok(self.0 != other.0),
+            _ if self.0.currency != other.0.currency => Err(OwoError::CurrencyMismatch(
+                self.0.currency.code.to_string(),
+                other.0.currency.code.to_string(),
+            )
+            .into()),
+            _ => Ok(op.matches(self.0.amount.cmp(&other.0.amount))),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_compact_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Owo({:?})", self.0.to_compact_string())
+    }
+}
+
+/// The `cowry` Python module.
+#[pymodule]
+fn cowry(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCurrency>()?;
+    module.add_class::<PyOwo>()?;
+    module.add_class::<RoundingMode>()?;
+    Ok(())
+}
@@ -0,0 +1,172 @@
+//! Exact decimal quantities such as 2.57 kg or 1.5 hours, and line totals
+//! priced from them in a single rounding step.
+//!
+//! `line_total` multiplies the unit price by the quantity in integers and
+//! rounds once, so `2.57 × ₦850.00` is exactly ₦2,184.50 rather than the
+//! result of rounding an intermediate product.
+
+use crate::error::OwoError;
+use crate::owo::parse_decimal;
+use crate::rounding::round_div;
+use crate::{Currency, Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A decimal quantity stored exactly as `mantissa / 10^scale`.
+///
+/// Deserializing goes through [`Quantity::new`], so a scale above
+/// `Currency::MAX_PRECISION` is rejected.
+///
+/// #Example
+/// ```
+/// # use cowry::quantity::Quantity;
+/// let kilos: Quantity = serde_json::from_str(r#"{"mantissa":257,"scale":2}"#).unwrap();
+/// assert_eq!(kilos, Quantity::new(257, 2).unwrap());
+///
+/// assert!(serde_json::from_str::<Quantity>(r#"{"mantissa":1,"scale":200}"#).is_err());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "QuantityParts")]
+pub struct Quantity {
+    mantissa: i64,
+    scale: u8,
+}
+
+// Helper for deserializing a `Quantity` before its scale is checked
+#[derive(Deserialize)]
+struct QuantityParts {
+    mantissa: i64,
+    scale: u8,
+}
+
+impl TryFrom<QuantityParts> for Quantity {
+    type Error = OwoError;
+
+    fn try_from(parts: QuantityParts) -> Result<Self, Self::Error> {
+        Self::new(parts.mantissa, parts.scale)
+    }
+}
+
+impl Quantity {
+    /// Creates `mantissa / 10^scale`, so `Quantity::new(257, 2)` is 2.57.
+    pub fn new(mantissa: i64, scale: u8) -> Result<Self, OwoError> {
+        if scale > Currency::MAX_PRECISION {
+            return Err(OwoError::InvalidPrecision(scale));
+        }
+        Ok(Self { mantissa, scale })
+    }
+
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Returns the quantity as a float, for display or approximate use.
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa as f64 / 10f64.powi(i32::from(self.scale))
+    }
+}
+
+impl From<i64> for Quantity {
+    fn from(whole: i64) -> Self {
+        Self {
+            mantissa: whole,
+            scale: 0,
+        }
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = OwoError;
+
+    /// Parses a plain decimal such as `"2.57"`, keeping every digit.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let digits = input
+            .split_once('.')
+            .map_or(0, |(_, fraction)| fraction.len());
+        let scale = u8::try_from(digits)
+            .ok()
+            .filter(|&scale| scale <= Currency::MAX_PRECISION)
+            .ok_or_else(|| OwoError::ParseError {
+                input: input.to_string(),
+                reason: "more than 18 fraction digits".to_string(),
+            })?;
+        Ok(Self {
+            mantissa: parse_decimal(input, scale)?,
+            scale,
+        })
+    }
+}
+
+impl TryFrom<f64> for Quantity {
+    type Error = OwoError;
+
+    /// Converts a float through its shortest decimal form, so `2.57` is
+    /// exactly 2.57 rather than the nearest binary fraction.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(OwoError::InvalidScalar(value));
+        }
+        value.to_string().parse()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl TryFrom<rust_decimal::Decimal> for Quantity {
+    type Error = OwoError;
+
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        let value = value.normalize();
+        let mantissa = i64::try_from(value.mantissa()).map_err(|_| OwoError::ParseError {
+            input: value.to_string(),
+            reason: "quantity out of range".to_string(),
+        })?;
+        Self::new(mantissa, value.scale() as u8)
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = Owo::new(self.mantissa, Currency::from_static("", "", self.scale));
+        write!(f, "{}", unit.to_decimal_string())
+    }
+}
+
+/// Returns `unit_price × quantity`, computed exactly and rounded once with
+/// `mode`
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::quantity::{Quantity, line_total};
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let per_kg = Owo::new(85000,ngn.clone());
+///
+/// let weight: Quantity = "2.57".parse().unwrap();
+/// assert_eq!(line_total(&per_kg, weight, RoundingMode::Nearest).unwrap(), Owo::new(218450,ngn.clone()));
+///
+/// // 0.333 h at ₦100.00/h is ₦33.30
+/// let hours = Quantity::try_from(0.333).unwrap();
+/// assert_eq!(line_total(&Owo::new(10000,ngn.clone()), hours, RoundingMode::Nearest).unwrap().get_amount(), 3330);
+///
+/// // 1.005 units at ₦1.00 is 100.5 kobo
+/// let units: Quantity = "1.005".parse().unwrap();
+/// assert_eq!(line_total(&Owo::new(100,ngn.clone()), units, RoundingMode::Floor).unwrap().get_amount(), 100);
+/// assert_eq!(line_total(&Owo::new(100,ngn.clone()), units, RoundingMode::Ceil).unwrap().get_amount(), 101);
+/// ```
+pub fn line_total(
+    unit_price: &Owo,
+    quantity: Quantity,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let exact = i128::from(unit_price.amount) * i128::from(quantity.mantissa);
+    let scale = 10i128.pow(u32::from(quantity.scale));
+    let amount = round_div(exact, scale, mode);
+    let amount = i64::try_from(amount).map_err(|_| unit_price.overflow("line_total", quantity))?;
+    Ok(Owo::new(amount, unit_price.currency))
+}
@@ -0,0 +1,133 @@
+//! Currency redenomination, when a country rebases its currency so that one
+//! new unit replaces a fixed number of old ones.
+//!
+//! A single amount is converted exactly and rounded once with the caller's
+//! `RoundingMode`. A collection is converted so its total is preserved: the
+//! converted amounts always sum to the converted (and once-rounded) total of
+//! the originals, with the rounding difference given to the amounts that
+//! lost the most to rounding.
+
+use crate::error::OwoError;
+use crate::rounding::round_div;
+use crate::{Currency, Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// A rebasing from `old` to `new` currency at `factor` old units per new
+/// unit, such as 1 GHS = 10,000 GHC.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Redenomination {
+    pub old: Currency,
+    pub new: Currency,
+    /// The number of old major units in one new major unit.
+    pub factor: u64,
+}
+
+impl Redenomination {
+    /// Creates a redenomination, rejecting a factor of zero.
+    pub fn new(old: Currency, new: Currency, factor: u64) -> Result<Self, OwoError> {
+        if factor == 0 {
+            return Err(OwoError::InvalidScalar(0.0));
+        }
+        Ok(Self { old, new, factor })
+    }
+
+    /// Converts one amount in the old currency, rounding once with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::redenomination::Redenomination;
+    ///
+    /// let ghc = Currency::new("GHC", "₵", 2);
+    /// let ghs = Currency::new("GHS", "GH₵", 2);
+    /// let rebase = Redenomination::new(ghc.clone(), ghs.clone(), 10_000).unwrap();
+    ///
+    /// // ₵1,234,567.00 is GH₵123.4567
+    /// let converted = rebase.convert(&Owo::new(123456700,ghc.clone()), RoundingMode::Nearest).unwrap();
+    /// assert_eq!(converted, Owo::new(12346,ghs.clone()));
+    ///
+    /// assert!(rebase.convert(&Owo::new(100,ghs), RoundingMode::Nearest).is_err());
+    /// ```
+    pub fn convert(&self, amount: &Owo, mode: RoundingMode) -> Result<Owo, OwoError> {
+        let (numerator, denominator) = self.exact(amount)?;
+        self.wrap_new(round_div(numerator, denominator, mode), amount)
+    }
+
+    /// Converts every amount in the old currency so the results sum exactly
+    /// to the converted total, which is rounded once with `mode`
+    ///
+    /// Each amount is first rounded down; the units still needed to reach
+    /// the total go one each to the amounts with the largest remainders,
+    /// earlier amounts first on ties.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::redenomination::Redenomination;
+    ///
+    /// let old = Currency::new("OLD", "O", 0);
+    /// let new = Currency::new("NEW", "N", 2);
+    /// let rebase = Redenomination::new(old.clone(), new.clone(), 1_000).unwrap();
+    ///
+    /// // Three balances of 5 old units: 0.5¢ each, 1.5¢ in total
+    /// let balances = vec![Owo::new(5,old.clone()); 3];
+    /// let converted = rebase.convert_all(&balances, RoundingMode::Nearest).unwrap();
+    ///
+    /// let amounts: Vec<i64> = converted.iter().map(|owo| owo.get_amount()).collect();
+    /// assert_eq!(amounts, vec![1, 1, 0]);
+    ///
+    /// // Rounding each one alone would have created a cent
+    /// assert_eq!(rebase.convert(&balances[0], RoundingMode::Nearest).unwrap().get_amount(), 1);
+    /// ```
+    pub fn convert_all(&self, amounts: &[Owo], mode: RoundingMode) -> Result<Vec<Owo>, OwoError> {
+        let mut floors = Vec::with_capacity(amounts.len());
+        let mut remainders = Vec::with_capacity(amounts.len());
+        let mut sum: i128 = 0;
+        let mut denominator = 1;
+        for (index, amount) in amounts.iter().enumerate() {
+            let (numerator, den) = self.exact(amount).map_err(|source| OwoError::AtIndex {
+                index,
+                source: Box::new(source),
+            })?;
+            denominator = den;
+            floors.push(numerator.div_euclid(den));
+            remainders.push((index, numerator.rem_euclid(den)));
+            sum = sum
+                .checked_add(numerator)
+                .ok_or_else(|| amount.overflow("redenominate", self.factor))?;
+        }
+
+        let total = round_div(sum, denominator, mode);
+        let leftover = total - floors.iter().sum::<i128>();
+        remainders.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        for (index, _) in remainders.iter().take(leftover as usize) {
+            floors[*index] += 1;
+        }
+        floors
+            .into_iter()
+            .zip(amounts)
+            .map(|(amount, original)| self.wrap_new(amount, original))
+            .collect()
+    }
+
+    // Helper returning `amount` in new minor units as an exact fraction
+    fn exact(&self, amount: &Owo) -> Result<(i128, i128), OwoError> {
+        if amount.currency != self.old {
+            return Err(OwoError::CurrencyMismatch(
+                self.old.code.to_string(),
+                amount.currency.code.to_string(),
+            ));
+        }
+        let numerator = i128::from(amount.amount) * i128::from(self.new.scale_factor());
+        let denominator = i128::from(self.old.scale_factor()) * i128::from(self.factor);
+        Ok((numerator, denominator))
+    }
+
+    // Helper wrapping new minor units as an `Owo`, reporting overflow
+    // against the original amount
+    fn wrap_new(&self, amount: i128, original: &Owo) -> Result<Owo, OwoError> {
+        let amount =
+            i64::try_from(amount).map_err(|_| original.overflow("redenominate", self.factor))?;
+        Ok(Owo::new(amount, self.new))
+    }
+}
@@ -0,0 +1,304 @@
+//! ISO 4217 currency definitions, looked up by alphabetic or numeric code.
+
+pub mod historic;
+
+use crate::Currency;
+use crate::error::OwoError;
+
+/// An ISO 4217 currency entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsoCurrency {
+    pub code: &'static str,
+    pub numeric: u16,
+    pub symbol: &'static str,
+    pub precision: u8,
+}
+
+impl IsoCurrency {
+    /// Builds the `Currency` definition for this entry without allocating.
+    pub const fn to_currency(&self) -> Currency {
+        Currency::from_static(self.code, self.symbol, self.precision)
+    }
+}
+
+macro_rules! iso {
+    ($code:literal, $numeric:literal, $symbol:literal, $precision:literal) => {
+        IsoCurrency {
+            code: $code,
+            numeric: $numeric,
+            symbol: $symbol,
+            precision: $precision,
+        }
+    };
+}
+
+/// Active ISO 4217 currencies, sorted by alphabetic code.
+pub static ISO_CURRENCIES: &[IsoCurrency] = &[
+    iso!("AED", 784, "د.إ", 2),
+    iso!("AFN", 971, "؋", 2),
+    iso!("ALL", 8, "L", 2),
+    iso!("AMD", 51, "֏", 2),
+    iso!("ANG", 532, "ƒ", 2),
+    iso!("AOA", 973, "Kz", 2),
+    iso!("ARS", 32, "$", 2),
+    iso!("AUD", 36, "A$", 2),
+    iso!("AWG", 533, "ƒ", 2),
+    iso!("AZN", 944, "₼", 2),
+    iso!("BAM", 977, "KM", 2),
+    iso!("BBD", 52, "Bds$", 2),
+    iso!("BDT", 50, "৳", 2),
+    iso!("BGN", 975, "лв", 2),
+    iso!("BHD", 48, ".د.ب", 3),
+    iso!("BIF", 108, "FBu", 0),
+    iso!("BMD", 60, "$", 2),
+    iso!("BND", 96, "B$", 2),
+    iso!("BOB", 68, "Bs.", 2),
+    iso!("BRL", 986, "R$", 2),
+    iso!("BSD", 44, "B$", 2),
+    iso!("BTN", 64, "Nu.", 2),
+    iso!("BWP", 72, "P", 2),
+    iso!("BYN", 933, "Br", 2),
+    iso!("BZD", 84, "BZ$", 2),
+    iso!("CAD", 124, "C$", 2),
+    iso!("CDF", 976, "FC", 2),
+    iso!("CHF", 756, "CHF", 2),
+    iso!("CLP", 152, "$", 0),
+    iso!("CNY", 156, "¥", 2),
+    iso!("COP", 170, "$", 2),
+    iso!("CRC", 188, "₡", 2),
+    iso!("CUP", 192, "$", 2),
+    iso!("CVE", 132, "Esc", 2),
+    iso!("CZK", 203, "Kč", 2),
+    iso!("DJF", 262, "Fdj", 0),
+    iso!("DKK", 208, "kr", 2),
+    iso!("DOP", 214, "RD$", 2),
+    iso!("DZD", 12, "دج", 2),
+    iso!("EGP", 818, "E£", 2),
+    iso!("ERN", 232, "Nfk", 2),
+    iso!("ETB", 230, "Br", 2),
+    iso!("EUR", 978, "€", 2),
+    iso!("FJD", 242, "FJ$", 2),
+    iso!("FKP", 238, "£", 2),
+    iso!("GBP", 826, "£", 2),
+    iso!("GEL", 981, "₾", 2),
+    iso!("GHS", 936, "₵", 2),
+    iso!("GIP", 292, "£", 2),
+    iso!("GMD", 270, "D", 2),
+    iso!("GNF", 324, "FG", 0),
+    iso!("GTQ", 320, "Q", 2),
+    iso!("GYD", 328, "G$", 2),
+    iso!("HKD", 344, "HK$", 2),
+    iso!("HNL", 340, "L", 2),
+    iso!("HTG", 332, "G", 2),
+    iso!("HUF", 348, "Ft", 2),
+    iso!("IDR", 360, "Rp", 2),
+    iso!("ILS", 376, "₪", 2),
+    iso!("INR", 356, "₹", 2),
+    iso!("IQD", 368, "ع.د", 3),
+    iso!("IRR", 364, "﷼", 2),
+    iso!("ISK", 352, "kr", 0),
+    iso!("JMD", 388, "J$", 2),
+    iso!("JOD", 400, "JD", 3),
+    iso!("JPY", 392, "¥", 0),
+    iso!("KES", 404, "KSh", 2),
+    iso!("KGS", 417, "с", 2),
+    iso!("KHR", 116, "៛", 2),
+    iso!("KMF", 174, "CF", 0),
+    iso!("KPW", 408, "₩", 2),
+    iso!("KRW", 410, "₩", 0),
+    iso!("KWD", 414, "KD", 3),
+    iso!("KYD", 136, "CI$", 2),
+    iso!("KZT", 398, "₸", 2),
+    iso!("LAK", 418, "₭", 2),
+    iso!("LBP", 422, "L£", 2),
+    iso!("LKR", 144, "Rs", 2),
+    iso!("LRD", 430, "L$", 2),
+    iso!("LSL", 426, "L", 2),
+    iso!("LYD", 434, "LD", 3),
+    iso!("MAD", 504, "DH", 2),
+    iso!("MDL", 498, "L", 2),
+    iso!("MGA", 969, "Ar", 2),
+    iso!("MKD", 807, "ден", 2),
+    iso!("MMK", 104, "K", 2),
+    iso!("MNT", 496, "₮", 2),
+    iso!("MOP", 446, "MOP$", 2),
+    iso!("MRU", 929, "UM", 2),
+    iso!("MUR", 480, "₨", 2),
+    iso!("MVR", 462, "Rf", 2),
+    iso!("MWK", 454, "MK", 2),
+    iso!("MXN", 484, "Mex$", 2),
+    iso!("MYR", 458, "RM", 2),
+    iso!("MZN", 943, "MT", 2),
+    iso!("NAD", 516, "N$", 2),
+    iso!("NGN", 566, "₦", 2),
+    iso!("NIO", 558, "C$", 2),
+    iso!("NOK", 578, "kr", 2),
+    iso!("NPR", 524, "Rs", 2),
+    iso!("NZD", 554, "NZ$", 2),
+    iso!("OMR", 512, "ر.ع.", 3),
+    iso!("PAB", 590, "B/.", 2),
+    iso!("PEN", 604, "S/", 2),
+    iso!("PGK", 598, "K", 2),
+    iso!("PHP", 608, "₱", 2),
+    iso!("PKR", 586, "Rs", 2),
+    iso!("PLN", 985, "zł", 2),
+    iso!("PYG", 600, "₲", 0),
+    iso!("QAR", 634, "QR", 2),
+    iso!("RON", 946, "lei", 2),
+    iso!("RSD", 941, "din", 2),
+    iso!("RUB", 643, "₽", 2),
+    iso!("RWF", 646, "FRw", 0),
+    iso!("SAR", 682, "SR", 2),
+    iso!("SBD", 90, "SI$", 2),
+    iso!("SCR", 690, "SR", 2),
+    iso!("SDG", 938, "£SD", 2),
+    iso!("SEK", 752, "kr", 2),
+    iso!("SGD", 702, "S$", 2),
+    iso!("SHP", 654, "£", 2),
+    iso!("SLE", 925, "Le", 2),
+    iso!("SOS", 706, "Sh", 2),
+    iso!("SRD", 968, "$", 2),
+    iso!("SSP", 728, "£", 2),
+    iso!("STN", 930, "Db", 2),
+    iso!("SVC", 222, "₡", 2),
+    iso!("SYP", 760, "£S", 2),
+    iso!("SZL", 748, "E", 2),
+    iso!("THB", 764, "฿", 2),
+    iso!("TJS", 972, "SM", 2),
+    iso!("TMT", 934, "m", 2),
+    iso!("TND", 788, "DT", 3),
+    iso!("TOP", 776, "T$", 2),
+    iso!("TRY", 949, "₺", 2),
+    iso!("TTD", 780, "TT$", 2),
+    iso!("TWD", 901, "NT$", 2),
+    iso!("TZS", 834, "TSh", 2),
+    iso!("UAH", 980, "₴", 2),
+    iso!("UGX", 800, "USh", 0),
+    iso!("USD", 840, "$", 2),
+    iso!("UYU", 858, "$U", 2),
+    iso!("UZS", 860, "soʻm", 2),
+    iso!("VES", 928, "Bs.S", 2),
+    iso!("VND", 704, "₫", 0),
+    iso!("VUV", 548, "VT", 0),
+    iso!("WST", 882, "WS$", 2),
+    iso!("XAF", 950, "FCFA", 0),
+    iso!("XCD", 951, "EC$", 2),
+    iso!("XOF", 952, "CFA", 0),
+    iso!("XPF", 953, "₣", 0),
+    iso!("YER", 886, "﷼", 2),
+    iso!("ZAR", 710, "R", 2),
+    iso!("ZMW", 967, "ZK", 2),
+    iso!("ZWG", 924, "ZiG", 2),
+];
+
+/// Finds an ISO 4217 currency by its alphabetic code, ignoring ASCII case.
+///
+/// #Example
+/// ```
+/// use cowry::registry;
+///
+/// let ngn = registry::find("NGN").unwrap();
+/// assert_eq!(ngn.numeric, 566);
+/// assert_eq!(ngn.symbol, "₦");
+///
+/// assert_eq!(registry::find("jpy").unwrap().precision, 0);
+/// assert!(registry::find("XYZ").is_none());
+/// ```
+pub const fn find(code: &str) -> Option<&'static IsoCurrency> {
+    // A manual loop so the lookup also works in const contexts
+    let mut index = 0;
+    while index < ISO_CURRENCIES.len() {
+        let candidate = &ISO_CURRENCIES[index];
+        if candidate.code.as_bytes().eq_ignore_ascii_case(code.as_bytes()) {
+            return Some(candidate);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Finds an ISO 4217 currency by its numeric code.
+///
+/// #Example
+/// ```
+/// use cowry::registry;
+///
+/// assert_eq!(registry::find_numeric(840).unwrap().code, "USD");
+/// assert!(registry::find_numeric(999).is_none());
+/// ```
+pub fn find_numeric(numeric: u16) -> Option<&'static IsoCurrency> {
+    ISO_CURRENCIES.iter().find(|c| c.numeric == numeric)
+}
+
+/// Returns the `Currency` for an ISO 4217 alphabetic code.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::registry;
+///
+/// assert_eq!(registry::currency("EUR").unwrap(), Currency::new("EUR", "€", 2));
+/// assert!(registry::currency("XYZ").is_err());
+/// ```
+pub fn currency(code: &str) -> Result<Currency, OwoError> {
+    find(code)
+        .map(IsoCurrency::to_currency)
+        .ok_or_else(|| OwoError::UnknownCurrency(code.to_string()))
+}
+
+/// Checks a currency definition received from outside, such as an API
+/// request, against `custom` currencies and then the ISO 4217 registry,
+/// returning the registered `Currency`.
+///
+/// The code must match exactly, and the symbol and precision must equal the
+/// registered ones. Nothing is interned for a rejected definition.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::registry;
+///
+/// assert_eq!(registry::validate("USD", "$", 2, &[]).unwrap(), Currency::new("USD", "$", 2));
+///
+/// assert!(registry::validate("XYZ", "X", 2, &[]).is_err());
+/// assert!(registry::validate("usd", "$", 2, &[]).is_err());
+/// assert!(registry::validate("USD", "US$", 2, &[]).is_err());
+/// assert!(registry::validate("JPY", "¥", 2, &[]).is_err());
+///
+/// let points = Currency::new("PTS", "★", 0);
+/// assert_eq!(registry::validate("PTS", "★", 0, &[points]).unwrap(), points);
+/// ```
+pub fn validate(
+    code: &str,
+    symbol: &str,
+    precision: u8,
+    custom: &[Currency],
+) -> Result<Currency, OwoError> {
+    let registered = custom
+        .iter()
+        .copied()
+        .find(|currency| currency.code == code)
+        .or_else(|| {
+            find(code)
+                .filter(|iso| iso.code == code)
+                .map(IsoCurrency::to_currency)
+        })
+        .ok_or_else(|| OwoError::UnknownCurrency(code.to_string()))?;
+    let mismatch = |reason: String| OwoError::CurrencyDefinitionMismatch {
+        code: code.to_string(),
+        reason,
+    };
+    if registered.symbol != symbol {
+        return Err(mismatch(format!(
+            "symbol {symbol:?}, expected {:?}",
+            registered.symbol
+        )));
+    }
+    if registered.precision != precision {
+        return Err(mismatch(format!(
+            "precision {precision}, expected {}",
+            registered.precision
+        )));
+    }
+    Ok(registered)
+}
@@ -0,0 +1,198 @@
+//! Withdrawn ISO 4217 currencies, such as the legacy eurozone currencies
+//! and the successive Zimbabwe dollars, so archival data can still be read
+//! and optionally migrated to the currency in use today.
+
+use crate::error::OwoError;
+use crate::finance::day_count::CalendarDate;
+use crate::registry::{self, IsoCurrency};
+use crate::rounding::round_div;
+use crate::{Currency, Owo, RoundingMode};
+
+/// The currency that replaced a withdrawn one, at a fixed rate of
+/// `units / 10^scale` old major units per new major unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Replacement {
+    pub code: &'static str,
+    pub units: u64,
+    pub scale: u8,
+}
+
+/// A withdrawn ISO 4217 currency entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoricCurrency {
+    pub currency: IsoCurrency,
+    /// The date its replacement took over; amounts dated before it are in
+    /// this currency.
+    pub valid_until: CalendarDate,
+    pub replaced_by: Replacement,
+}
+
+macro_rules! historic {
+    (
+        $code:literal, $numeric:literal, $symbol:literal, $precision:literal,
+        ($year:literal, $month:literal, $day:literal),
+        $replacement:literal, $units:literal, $scale:literal
+    ) => {
+        HistoricCurrency {
+            currency: IsoCurrency {
+                code: $code,
+                numeric: $numeric,
+                symbol: $symbol,
+                precision: $precision,
+            },
+            valid_until: CalendarDate::new($year, $month, $day),
+            replaced_by: Replacement {
+                code: $replacement,
+                units: $units,
+                scale: $scale,
+            },
+        }
+    };
+}
+
+/// Withdrawn ISO 4217 currencies, sorted by alphabetic code.
+///
+/// Eurozone currencies use the irrevocable euro conversion rates and the
+/// date each country adopted the euro.
+pub static HISTORIC_CURRENCIES: &[HistoricCurrency] = &[
+    historic!("ATS", 40, "S", 2, (1999, 1, 1), "EUR", 137603, 4),
+    historic!("AZM", 31, "m", 2, (2006, 1, 1), "AZN", 5000, 0),
+    historic!("BEF", 56, "BF", 0, (1999, 1, 1), "EUR", 403399, 4),
+    historic!("BYR", 974, "Br", 0, (2016, 7, 1), "BYN", 10000, 0),
+    historic!("CYP", 196, "£", 2, (2008, 1, 1), "EUR", 585274, 6),
+    historic!("DEM", 276, "DM", 2, (1999, 1, 1), "EUR", 195583, 5),
+    historic!("EEK", 233, "kr", 2, (2011, 1, 1), "EUR", 156466, 4),
+    historic!("ESP", 724, "Pta", 0, (1999, 1, 1), "EUR", 166386, 3),
+    historic!("FIM", 246, "mk", 2, (1999, 1, 1), "EUR", 594573, 5),
+    historic!("FRF", 250, "F", 2, (1999, 1, 1), "EUR", 655957, 5),
+    historic!("GHC", 288, "₵", 2, (2007, 7, 1), "GHS", 10000, 0),
+    historic!("GRD", 300, "₯", 0, (2001, 1, 1), "EUR", 340750, 3),
+    historic!("HRK", 191, "kn", 2, (2023, 1, 1), "EUR", 753450, 5),
+    historic!("IEP", 372, "£", 2, (1999, 1, 1), "EUR", 787564, 6),
+    historic!("ITL", 380, "₤", 0, (1999, 1, 1), "EUR", 193627, 2),
+    historic!("LTL", 440, "Lt", 2, (2015, 1, 1), "EUR", 345280, 5),
+    historic!("LUF", 442, "F", 0, (1999, 1, 1), "EUR", 403399, 4),
+    historic!("LVL", 428, "Ls", 2, (2014, 1, 1), "EUR", 702804, 6),
+    historic!("MRO", 478, "UM", 2, (2018, 1, 1), "MRU", 10, 0),
+    historic!("MTL", 470, "Lm", 2, (2008, 1, 1), "EUR", 429300, 6),
+    historic!("MZM", 508, "MT", 2, (2006, 7, 1), "MZN", 1000, 0),
+    historic!("NLG", 528, "ƒ", 2, (1999, 1, 1), "EUR", 220371, 5),
+    historic!("PTE", 620, "Esc", 0, (1999, 1, 1), "EUR", 200482, 3),
+    historic!("ROL", 642, "L", 2, (2005, 7, 1), "RON", 10000, 0),
+    historic!("RUR", 810, "р.", 2, (1998, 1, 1), "RUB", 1000, 0),
+    historic!("SIT", 705, "SIT", 2, (2007, 1, 1), "EUR", 239640, 3),
+    historic!("SKK", 703, "Sk", 2, (2009, 1, 1), "EUR", 301260, 4),
+    historic!("SLL", 694, "Le", 2, (2022, 7, 1), "SLE", 1000, 0),
+    historic!("STD", 678, "Db", 2, (2018, 1, 1), "STN", 1000, 0),
+    historic!("TMM", 795, "m", 2, (2009, 1, 1), "TMT", 5000, 0),
+    historic!("TRL", 792, "₤", 0, (2005, 1, 1), "TRY", 1000000, 0),
+    historic!("VEB", 862, "Bs", 2, (2008, 1, 1), "VEF", 1000, 0),
+    historic!("VEF", 937, "Bs.F", 2, (2018, 8, 20), "VES", 100000, 0),
+    historic!("ZMK", 894, "ZK", 2, (2013, 1, 1), "ZMW", 1000, 0),
+    historic!("ZWD", 716, "$", 2, (2006, 8, 1), "ZWN", 1000, 0),
+    historic!("ZWL", 932, "$", 2, (2024, 4, 5), "ZWG", 24987242, 4),
+    historic!("ZWN", 942, "$", 2, (2008, 8, 1), "ZWR", 10000000000, 0),
+    historic!("ZWR", 935, "$", 2, (2009, 2, 2), "ZWL", 1000000000000, 0),
+];
+
+impl HistoricCurrency {
+    /// Checks whether amounts dated `date` are in this currency.
+    pub fn is_valid_on(&self, date: CalendarDate) -> bool {
+        date < self.valid_until
+    }
+
+    /// Converts an amount in this currency to the active currency that
+    /// ultimately replaced it, following any chain of replacements exactly
+    /// and rounding once with `mode`
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::registry::historic;
+    ///
+    /// let dem = historic::find("DEM").unwrap();
+    /// let price = Owo::new(100000,dem.currency.to_currency());
+    ///
+    /// // DM1,000 at 1.95583 DEM per EUR is €511.29
+    /// let migrated = dem.migrate(&price, RoundingMode::Nearest).unwrap();
+    /// assert_eq!(migrated, Owo::new(51129,Currency::new("EUR", "€", 2)));
+    ///
+    /// // ZWN → ZWR → ZWL → ZWG
+    /// let zwn = historic::find("ZWN").unwrap();
+    /// let savings = Owo::new(i64::MAX,zwn.currency.to_currency());
+    /// assert_eq!(zwn.migrate(&savings, RoundingMode::Nearest).unwrap().get_currency(), "ZWG");
+    /// ```
+    pub fn migrate(&self, amount: &Owo, mode: RoundingMode) -> Result<Owo, OwoError> {
+        if amount.currency.code != self.currency.code {
+            return Err(OwoError::CurrencyMismatch(
+                self.currency.code.to_string(),
+                amount.currency.code.to_string(),
+            ));
+        }
+        let overflow = || amount.overflow("migrate", self.replaced_by.code);
+        let mut numerator = i128::from(amount.amount);
+        let mut denominator = i128::from(self.currency.to_currency().scale_factor());
+        let mut step = self;
+        loop {
+            let replacement = step.replaced_by;
+            numerator = numerator
+                .checked_mul(10i128.pow(u32::from(replacement.scale)))
+                .ok_or_else(overflow)?;
+            denominator = denominator
+                .checked_mul(i128::from(replacement.units))
+                .ok_or_else(overflow)?;
+            match find(replacement.code) {
+                Some(next) => step = next,
+                None => {
+                    let target = registry::currency(replacement.code)?;
+                    numerator = numerator
+                        .checked_mul(i128::from(target.scale_factor()))
+                        .ok_or_else(overflow)?;
+                    let minor = round_div(numerator, denominator, mode);
+                    let minor = i64::try_from(minor).map_err(|_| overflow())?;
+                    return Ok(Owo::new(minor, target));
+                }
+            }
+        }
+    }
+}
+
+/// Finds a withdrawn ISO 4217 currency by its alphabetic code, ignoring
+/// ASCII case.
+///
+/// #Example
+/// ```
+/// use cowry::registry::historic;
+/// use cowry::finance::day_count::CalendarDate;
+///
+/// let frf = historic::find("frf").unwrap();
+/// assert_eq!(frf.replaced_by.code, "EUR");
+/// assert!(frf.is_valid_on(CalendarDate::new(1998, 12, 31)));
+/// assert!(!frf.is_valid_on(CalendarDate::new(1999, 1, 1)));
+///
+/// assert!(historic::find("EUR").is_none());
+/// ```
+pub fn find(code: &str) -> Option<&'static HistoricCurrency> {
+    HISTORIC_CURRENCIES
+        .iter()
+        .find(|historic| historic.currency.code.eq_ignore_ascii_case(code))
+}
+
+/// Returns the `Currency` for an active or withdrawn ISO 4217 code, for
+/// reading archival data.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::registry::historic;
+///
+/// assert_eq!(historic::currency("ITL").unwrap(), Currency::new("ITL", "₤", 0));
+/// assert_eq!(historic::currency("EUR").unwrap(), Currency::new("EUR", "€", 2));
+/// assert!(historic::currency("XYZ").is_err());
+/// ```
+pub fn currency(code: &str) -> Result<Currency, OwoError> {
+    match find(code) {
+        Some(historic) => Ok(historic.currency.to_currency()),
+        None => registry::currency(code),
+    }
+}
@@ -0,0 +1,268 @@
+//! Markdown and HTML tables for invoices, statements and per-currency
+//! summaries, so small tools can show money without a templating layer.
+//!
+//! Amounts are rendered with `Owo::format`, the same text as `Display`, in
+//! right-aligned columns. Descriptions are escaped for the output format.
+
+use crate::Owo;
+use crate::error::OwoError;
+use crate::exposure::ExposureReport;
+use crate::invoice::Invoice;
+use crate::statement::Statement;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// The markup a report is rendered in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    /// A GitHub-flavoured Markdown table.
+    #[default]
+    Markdown,
+    /// An HTML `<table>` with `<thead>`, `<tbody>` and `<tfoot>`.
+    Html,
+}
+
+/// Renders an invoice's lines and totals as a table
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::invoice::{Invoice, LineItem, TaxRounding};
+/// use cowry::report::{ReportFormat, render_invoice};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let mut invoice = Invoice::new(usd.clone(), TaxRounding::PerLine, RoundingMode::Nearest);
+/// let mut line = LineItem::new("Consulting", Owo::new(12000,usd.clone()), 2.5);
+/// line.tax = 10.0;
+/// invoice.add_line(line).unwrap();
+///
+/// assert_eq!(render_invoice(&invoice, ReportFormat::Markdown).unwrap(), "\
+/// | Description | Quantity | Unit price | Discount | Tax | Amount |
+/// | :--- | ---: | ---: | ---: | ---: | ---: |
+/// | Consulting | 2.5 | $120.00 | $0.00 | $30.00 | $300.00 |
+/// | **Subtotal** | | | | | **$300.00** |
+/// | **Discount** | | | | | **$0.00** |
+/// | **Tax** | | | | | **$30.00** |
+/// | **Total** | | | | | **$330.00** |
+/// ");
+///
+/// let html = render_invoice(&invoice, ReportFormat::Html).unwrap();
+/// assert!(html.contains(r#"<tr><th colspan="5">Total</th><td style="text-align: right">$330.00</td></tr>"#));
+/// ```
+pub fn render_invoice(invoice: &Invoice, format: ReportFormat) -> Result<String, OwoError> {
+    let totals = invoice.totals()?;
+    let rows = invoice
+        .lines
+        .iter()
+        .zip(&totals.lines)
+        .map(|(item, line)| {
+            vec![
+                item.description.clone(),
+                item.quantity.to_string(),
+                item.unit_price.format(),
+                line.discount.format(),
+                line.tax.format(),
+                line.net.format(),
+            ]
+        })
+        .collect();
+    let footer = [
+        ("Subtotal", &totals.subtotal),
+        ("Discount", &totals.discount),
+        ("Tax", &totals.tax),
+        ("Total", &totals.total),
+    ];
+    Ok(render_table(
+        &[
+            "Description",
+            "Quantity",
+            "Unit price",
+            "Discount",
+            "Tax",
+            "Amount",
+        ],
+        rows,
+        &footer,
+        format,
+    ))
+}
+
+/// Renders a statement's transactions with the running balance
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::report::{ReportFormat, render_statement};
+/// use cowry::statement::Statement;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let statement = Statement::generate(
+///     Owo::new(10000,ngn.clone()),
+///     vec![("Salary", Owo::new(500000,ngn.clone())), ("<Rent>", Owo::new(-300000,ngn.clone()))],
+/// )
+/// .unwrap();
+///
+/// assert_eq!(render_statement(&statement, ReportFormat::Html), "\
+/// <table>
+/// <thead>
+/// <tr><th>Description</th><th style=\"text-align: right\">Amount</th><th style=\"text-align: right\">Balance</th></tr>
+/// </thead>
+/// <tbody>
+/// <tr><td>Opening balance</td><td style=\"text-align: right\"></td><td style=\"text-align: right\">₦100.00</td></tr>
+/// <tr><td>Salary</td><td style=\"text-align: right\">₦5000.00</td><td style=\"text-align: right\">₦5100.00</td></tr>
+/// <tr><td>&lt;Rent&gt;</td><td style=\"text-align: right\">₦-3000.00</td><td style=\"text-align: right\">₦2100.00</td></tr>
+/// </tbody>
+/// <tfoot>
+/// <tr><th colspan=\"2\">Closing balance</th><td style=\"text-align: right\">₦2100.00</td></tr>
+/// </tfoot>
+/// </table>
+/// ");
+/// ```
+pub fn render_statement(statement: &Statement, format: ReportFormat) -> String {
+    let mut rows = vec![vec![
+        "Opening balance".to_string(),
+        String::new(),
+        statement.opening.format(),
+    ]];
+    rows.extend(statement.lines.iter().map(|line| {
+        vec![
+            line.description.clone(),
+            line.amount.format(),
+            line.balance.format(),
+        ]
+    }));
+    render_table(
+        &["Description", "Amount", "Balance"],
+        rows,
+        &[("Closing balance", &statement.closing)],
+        format,
+    )
+}
+
+/// Renders the money in and out and the net total per currency, sorted by
+/// currency code
+///
+/// Returns an error if two definitions share a currency code, or a total
+/// overflows.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::report::{ReportFormat, render_summary};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let eur = Currency::new("EUR", "€", 2);
+/// let items = vec![Owo::new(10000,usd.clone()),Owo::new(-2500,usd),Owo::new(4000,eur)];
+///
+/// assert_eq!(render_summary(&items, ReportFormat::Markdown).unwrap(), "\
+/// | Currency | In | Out | Net |
+/// | :--- | ---: | ---: | ---: |
+/// | EUR | €40.00 | €0.00 | €40.00 |
+/// | USD | $100.00 | $-25.00 | $75.00 |
+/// ");
+/// ```
+pub fn render_summary<'a>(
+    items: impl IntoIterator<Item = &'a Owo>,
+    format: ReportFormat,
+) -> Result<String, OwoError> {
+    let report = ExposureReport::new(items)?;
+    let rows = report
+        .exposures
+        .iter()
+        .map(|exposure| {
+            vec![
+                exposure.net.currency.code.to_string(),
+                exposure.long.format(),
+                exposure.short.format(),
+                exposure.net.format(),
+            ]
+        })
+        .collect();
+    Ok(render_table(
+        &["Currency", "In", "Out", "Net"],
+        rows,
+        &[],
+        format,
+    ))
+}
+
+// Helper rendering a table whose first column is text and the rest are
+// right-aligned amounts, with labelled totals in the footer
+fn render_table(
+    headers: &[&str],
+    rows: Vec<Vec<String>>,
+    footer: &[(&str, &Owo)],
+    format: ReportFormat,
+) -> String {
+    let mut out = String::new();
+    // Writing to a String can't fail
+    match format {
+        ReportFormat::Markdown => {
+            let line = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+            let headers: Vec<String> = headers.iter().map(|h| escape_markdown(h)).collect();
+            out.push_str(&line(&headers));
+            let mut rule = vec![":---".to_string()];
+            rule.resize(headers.len(), "---:".to_string());
+            out.push_str(&line(&rule));
+            for row in rows {
+                let mut cells = row;
+                cells[0] = escape_markdown(&cells[0]);
+                out.push_str(&line(&cells));
+            }
+            let gap = "| ".repeat(headers.len().saturating_sub(2));
+            for (label, total) in footer {
+                let _ = writeln!(out, "| **{}** {}| **{}** |", label, gap, total.format());
+            }
+        }
+        ReportFormat::Html => {
+            let right = r#" style="text-align: right""#;
+            let cell = |tag: &str, index: usize, text: &str| {
+                let align = if index == 0 { "" } else { right };
+                format!("<{tag}{align}>{}</{tag}>", escape_html(text))
+            };
+            out.push_str("<table>\n<thead>\n<tr>");
+            for (index, header) in headers.iter().enumerate() {
+                out.push_str(&cell("th", index, header));
+            }
+            out.push_str("</tr>\n</thead>\n<tbody>\n");
+            for row in rows {
+                out.push_str("<tr>");
+                for (index, text) in row.iter().enumerate() {
+                    out.push_str(&cell("td", index, text));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</tbody>\n");
+            if !footer.is_empty() {
+                out.push_str("<tfoot>\n");
+                for (label, total) in footer {
+                    let _ = writeln!(
+                        out,
+                        r#"<tr><th colspan="{}">{}</th>{}</tr>"#,
+                        headers.len() - 1,
+                        escape_html(label),
+                        cell("td", 1, &total.format())
+                    );
+                }
+                out.push_str("</tfoot>\n");
+            }
+            out.push_str("</table>\n");
+        }
+    }
+    out
+}
+
+// Helper escaping the characters that would break a Markdown table cell
+fn escape_markdown(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('\n', " ")
+}
+
+// Helper escaping text for an HTML element
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,6 +1,143 @@
-#[derive(Debug, Clone, Copy)]
+use crate::error::OwoError;
+use crate::{Currency, Owo};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "cowry"))]
 pub enum RoundingMode {
     Nearest, // .round() | Rounds to nearest, ties away from zero | 2.625 → 2.63
     Floor,   // .floor() | Always rounds down | 2.625 → 2.62, -2.625 → -2.63
     Ceil,    // .ceil()  | Always rounds up | 2.625 → 2.63, -2.625 → -2.62
 }
+
+// Helper dividing exactly in integers, rounding the quotient with `mode`;
+// `denominator` must be positive
+pub(crate) fn round_div(numerator: i128, denominator: i128, mode: RoundingMode) -> i128 {
+    let quotient = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    if remainder == 0 {
+        return quotient;
+    }
+    match mode {
+        RoundingMode::Floor => quotient,
+        RoundingMode::Ceil => quotient + 1,
+        RoundingMode::Nearest => {
+            let twice = remainder * 2;
+            if twice > denominator || (twice == denominator && numerator > 0) {
+                quotient + 1
+            } else {
+                quotient
+            }
+        }
+    }
+}
+
+/// A penny bucket collecting the sub-unit remainders that rounding discards,
+/// per currency, so batch jobs can account for the total drift and pay it
+/// out later instead of losing it.
+///
+/// Remainders are kept in minor units: `0.4` means four tenths of a cent.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RoundingLedger {
+    remainders: Vec<(Currency, f64)>,
+}
+
+impl RoundingLedger {
+    /// Creates an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the difference between an `exact` amount in minor units and
+    /// the `rounded` amount actually used.
+    pub fn record(&mut self, exact: f64, rounded: &Owo) {
+        let drift = exact - rounded.amount as f64;
+        match self
+            .remainders
+            .iter_mut()
+            .find(|(currency, _)| *currency == rounded.currency)
+        {
+            Some((_, remainder)) => *remainder += drift,
+            None => self.remainders.push((rounded.currency, drift)),
+        }
+    }
+
+    /// Multiplies `owo` by `scalar` like [`Owo::multiply_with_mode`],
+    /// recording what the rounding discarded
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::rounding::RoundingLedger;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut bucket = RoundingLedger::new();
+    ///
+    /// // Three payslips at a third of $1.00 each drop a third of a cent apiece
+    /// for _ in 0..3 {
+    ///     let pay = bucket.multiply(&Owo::new(100,usd.clone()), 1.0 / 3.0, RoundingMode::Floor).unwrap();
+    ///     assert_eq!(pay, Owo::new(33,usd.clone()));
+    /// }
+    /// assert!((bucket.remainder(&usd) - 1.0).abs() < 1e-9);
+    ///
+    /// assert_eq!(bucket.take_whole(&usd), Owo::new(1,usd.clone()));
+    /// assert!(bucket.remainder(&usd).abs() < 1e-9);
+    /// ```
+    pub fn multiply(
+        &mut self,
+        owo: &Owo,
+        scalar: f64,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let rounded = owo.multiply_with_mode(scalar, mode)?;
+        self.record(owo.amount as f64 * scalar, &rounded);
+        Ok(rounded)
+    }
+
+    /// Takes `percent` percent of `owo` like
+    /// [`Owo::percentage_with_mode`], recording what the rounding
+    /// discarded.
+    pub fn percentage(
+        &mut self,
+        owo: &Owo,
+        percent: f64,
+        mode: RoundingMode,
+    ) -> Result<Owo, OwoError> {
+        let rounded = owo.percentage_with_mode(percent, mode)?;
+        self.record(owo.amount as f64 * percent / 100.0, &rounded);
+        Ok(rounded)
+    }
+
+    /// Returns the accumulated remainder for `currency` in minor units.
+    pub fn remainder(&self, currency: &Currency) -> f64 {
+        self.remainders
+            .iter()
+            .find(|(code, _)| code == currency)
+            .map_or(0.0, |(_, remainder)| *remainder)
+    }
+
+    /// Returns the remainders for every currency seen so far.
+    pub fn remainders(&self) -> impl Iterator<Item = (&Currency, f64)> {
+        self.remainders
+            .iter()
+            .map(|(currency, remainder)| (currency, *remainder))
+    }
+
+    /// Removes and returns the whole minor units accumulated for `currency`,
+    /// rounded toward zero, leaving the fraction behind for later.
+    pub fn take_whole(&mut self, currency: &Currency) -> Owo {
+        let whole = self
+            .remainders
+            .iter_mut()
+            .find(|(code, _)| code == currency)
+            .map_or(0.0, |(_, remainder)| {
+                // Absorb float noise so 0.999999… counts as a whole unit
+                let whole = (*remainder + remainder.signum() * 1e-9).trunc();
+                *remainder -= whole;
+                whole
+            });
+        Owo::new(whole as i64, *currency)
+    }
+}
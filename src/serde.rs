@@ -0,0 +1,255 @@
+//! Alternative serde representations for `Owo`, for use with
+//! `#[serde(with = "...")]` on fields of your own types.
+//!
+//! | Module | Wire format |
+//! |---|---|
+//! | [`minor_units`] | `{"amount":599,"currency":{"code":"USD","symbol":"$","precision":2}}` |
+//! | [`code_only`] | `{"amount":599,"currency":"USD"}` |
+//! | [`decimal_string`] | `{"amount":"5.99","currency":"USD"}` |
+//! | [`compact`] | `"USD 5.99"` |
+//! | [`strict`] | the `minor_units` format, checked against the registry |
+//!
+//! Every format except `minor_units` resolves the currency code from the
+//! ISO 4217 registry on deserialization.
+//!
+//! #Example
+//! ```
+//! # use cowry::prelude::*;
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Order {
+//!     #[serde(with = "cowry::serde::code_only")]
+//!     subtotal: Owo,
+//!     #[serde(with = "cowry::serde::decimal_string")]
+//!     total: Owo,
+//! }
+//!
+//! let usd = Currency::new("USD", "$", 2);
+//! let order = Order { subtotal: Owo::new(500, usd.clone()), total: Owo::new(599, usd) };
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&order).unwrap(),
+//!     r#"{"subtotal":{"amount":500,"currency":"USD"},"total":{"amount":"5.99","currency":"USD"}}"#
+//! );
+//! ```
+
+use crate::{Owo, registry};
+use ::serde::de::Error as _;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `Owo` in its default representation: the amount in minor units
+/// and the full currency definition. Useful to be explicit, or to pin the
+/// format of a field.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Entry {
+///     #[serde(with = "cowry::serde::minor_units")]
+///     amount: Owo,
+/// }
+///
+/// let entry = Entry { amount: Owo::new(500, Currency::new("NGN", "₦", 2)) };
+/// let json = serde_json::to_string(&entry).unwrap();
+///
+/// assert_eq!(json, r#"{"amount":{"amount":500,"currency":{"code":"NGN","symbol":"₦","precision":2}}}"#);
+/// ```
+pub mod minor_units {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(owo: &Owo, serializer: S) -> Result<S::Ok, S::Error> {
+        owo.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Owo, D::Error> {
+        Owo::deserialize(deserializer)
+    }
+}
+
+/// Serializes `Owo` as an amount in minor units and a currency code, e.g.
+/// `{"amount":599,"currency":"USD"}`.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Charge {
+///     #[serde(with = "cowry::serde::code_only")]
+///     price: Owo,
+/// }
+///
+/// let charge: Charge = serde_json::from_str(r#"{"price":{"amount":1200,"currency":"JPY"}}"#).unwrap();
+///
+/// assert_eq!(charge.price.format(), "¥1200");
+/// ```
+pub mod code_only {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Repr {
+        amount: i64,
+        currency: String,
+    }
+
+    pub fn serialize<S: Serializer>(owo: &Owo, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            amount: owo.amount,
+            currency: owo.currency.code.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Owo, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        let currency = registry::currency(&repr.currency).map_err(D::Error::custom)?;
+        Ok(Owo::new(repr.amount, currency))
+    }
+}
+
+/// Serializes `Owo` as a decimal string amount and a currency code, e.g.
+/// `{"amount":"5.99","currency":"USD"}`, the shape most payment APIs and
+/// JavaScript clients expect.
+///
+/// Currencies are resolved from the ISO 4217 registry on deserialization.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Payment {
+///     #[serde(with = "cowry::serde::decimal_string")]
+///     total: Owo,
+/// }
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let payment = Payment { total: Owo::new(599, usd.clone()) };
+///
+/// let json = serde_json::to_string(&payment).unwrap();
+/// assert_eq!(json, r#"{"total":{"amount":"5.99","currency":"USD"}}"#);
+///
+/// let back: Payment = serde_json::from_str(&json).unwrap();
+/// assert_eq!(back.total, Owo::new(599, usd));
+/// ```
+pub mod decimal_string {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Repr {
+        amount: String,
+        currency: String,
+    }
+
+    pub fn serialize<S: Serializer>(owo: &Owo, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            amount: owo.to_decimal_string(),
+            currency: owo.currency.code.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Owo, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        let currency = registry::currency(&repr.currency).map_err(D::Error::custom)?;
+        Owo::from_decimal_str(&repr.amount, currency).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes `Owo` as a single compact string of currency code and decimal
+/// amount, e.g. `"NGN 1234.50"`, for config files and URL parameters.
+///
+/// Currencies are resolved from the ISO 4217 registry on deserialization.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "cowry::serde::compact")]
+///     minimum_order: Owo,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"minimum_order":"NGN 1234.50"}"#).unwrap();
+/// assert_eq!(config.minimum_order.get_amount(), 123450);
+///
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"minimum_order":"NGN 1234.50"}"#);
+/// ```
+pub mod compact {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(owo: &Owo, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&owo.to_compact_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Owo, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        Owo::from_compact_str(&input).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes `Owo` like [`minor_units`], but rejects currency definitions
+/// that don't match the ISO 4217 registry on deserialization: unknown codes,
+/// wrong symbols and wrong precisions. Use it at API boundaries that must not
+/// accept caller-chosen currency definitions.
+///
+/// See [`Owo::from_json_strict`] to also accept a set of custom currencies.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Transfer {
+///     #[serde(with = "cowry::serde::strict")]
+///     amount: Owo,
+/// }
+///
+/// let json = r#"{"amount":{"amount":500,"currency":{"code":"USD","symbol":"$","precision":2}}}"#;
+/// let transfer: Transfer = serde_json::from_str(json).unwrap();
+/// assert_eq!(transfer.amount, Owo::new(500, Currency::new("USD", "$", 2)));
+///
+/// let forged = r#"{"amount":{"amount":500,"currency":{"code":"USD","symbol":"$","precision":4}}}"#;
+/// assert!(serde_json::from_str::<Transfer>(forged).is_err());
+/// ```
+pub mod strict {
+    use super::*;
+    use crate::Currency;
+    use crate::error::OwoError;
+
+    #[derive(Deserialize)]
+    struct CurrencyRepr {
+        code: String,
+        symbol: String,
+        precision: u8,
+    }
+
+    // The default `Owo` shape with owned strings, so rejected definitions
+    // are never interned
+    #[derive(Deserialize)]
+    pub(crate) struct Repr {
+        amount: i64,
+        currency: CurrencyRepr,
+    }
+
+    impl Repr {
+        // Helper checking the currency against `custom` and the registry
+        pub(crate) fn resolve(self, custom: &[Currency]) -> Result<Owo, OwoError> {
+            let CurrencyRepr {
+                code,
+                symbol,
+                precision,
+            } = self.currency;
+            let currency = registry::validate(&code, &symbol, precision, custom)?;
+            Ok(Owo::new(self.amount, currency))
+        }
+    }
+
+    pub fn serialize<S: Serializer>(owo: &Owo, serializer: S) -> Result<S::Ok, S::Error> {
+        owo.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Owo, D::Error> {
+        Repr::deserialize(deserializer)?
+            .resolve(&[])
+            .map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,143 @@
+//! Splitting a bill, with tip and tax, among the people who shared it.
+
+use crate::error::OwoError;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// One participant's part of the subtotal.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Claim {
+    /// A weighted share of whatever the fixed claims leave over.
+    Weight(u64),
+    /// A fixed part of the subtotal, e.g. the dish this person ordered.
+    Fixed(Owo),
+}
+
+/// A bill and how it was split.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BillSplit {
+    pub subtotal: Owo,
+    pub tip: Owo,
+    pub tax: Owo,
+    pub total: Owo,
+    /// What each participant pays, in the order of the claims, summing to
+    /// `total` exactly.
+    pub shares: Vec<Owo>,
+}
+
+/// Splits `subtotal` plus a tip and tax, both percentages of the subtotal,
+/// among the participants described by `claims`
+///
+/// Fixed claims are taken off the subtotal first and the remainder is
+/// divided by weight. Each participant then pays the tip and tax in
+/// proportion to their part of the subtotal, and leftover minor units go to
+/// the largest remainders as in [`Owo::allocate`], so the shares always sum
+/// to the total.
+///
+/// Returns an error if the subtotal is negative, the fixed claims exceed
+/// it, or a remainder is left with no weighted participant to take it.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::split::{Claim, split_bill};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let subtotal = Owo::new(10000,usd.clone());
+///
+/// // Ana had the $40 steak; Ben and Cy split the rest evenly
+/// let claims = [Claim::Fixed(Owo::new(4000,usd.clone())), Claim::Weight(1), Claim::Weight(1)];
+/// let bill = split_bill(&subtotal, 15.0, 10.0, &claims, RoundingMode::Nearest).unwrap();
+///
+/// assert_eq!(bill.total, Owo::new(12500,usd.clone()));
+/// assert_eq!(bill.shares, vec![Owo::new(5000,usd.clone()),Owo::new(3750,usd.clone()),Owo::new(3750,usd.clone())]);
+///
+/// // Three ways evenly: one participant picks up the extra cent
+/// let even = split_bill(&Owo::new(1000,usd.clone()), 0.0, 0.0, &vec![Claim::Weight(1); 3], RoundingMode::Nearest).unwrap();
+/// assert_eq!(even.shares, vec![Owo::new(334,usd.clone()),Owo::new(333,usd.clone()),Owo::new(333,usd.clone())]);
+/// ```
+pub fn split_bill(
+    subtotal: &Owo,
+    tip_percent: f64,
+    tax_percent: f64,
+    claims: &[Claim],
+    mode: RoundingMode,
+) -> Result<BillSplit, OwoError> {
+    if claims.is_empty() {
+        return Err(OwoError::EmptyCollection);
+    }
+    if subtotal.amount < 0 {
+        return Err(OwoError::InvalidSplit(format!(
+            "subtotal {} is negative",
+            subtotal.to_compact_string()
+        )));
+    }
+    let tip = subtotal.percentage_with_mode(tip_percent, mode)?;
+    let tax = subtotal.percentage_with_mode(tax_percent, mode)?;
+    let total = subtotal.checked_add(&tip)?.checked_add(&tax)?;
+
+    // Take the fixed claims off the subtotal, then divide what's left by weight
+    let mut fixed = 0i64;
+    for (index, claim) in claims.iter().enumerate() {
+        if let Claim::Fixed(amount) = claim {
+            let at_index = |source| OwoError::AtIndex {
+                index,
+                source: Box::new(source),
+            };
+            subtotal.check_currency(amount).map_err(at_index)?;
+            if amount.amount < 0 {
+                return Err(at_index(OwoError::InvalidSplit(format!(
+                    "claim {} is negative",
+                    amount.to_compact_string()
+                ))));
+            }
+            fixed = fixed.saturating_add(amount.amount);
+        }
+    }
+    let remainder = subtotal.amount - fixed;
+    if remainder < 0 {
+        return Err(OwoError::InvalidSplit(format!(
+            "fixed claims exceed the subtotal by {}",
+            Owo::new(-remainder, subtotal.currency).to_compact_string()
+        )));
+    }
+    let weights: Vec<u64> = claims
+        .iter()
+        .map(|claim| match claim {
+            Claim::Weight(weight) => *weight,
+            Claim::Fixed(_) => 0,
+        })
+        .collect();
+    let weighted = if weights.iter().any(|&weight| weight > 0) {
+        Owo::new(remainder, subtotal.currency).allocate(&weights)?
+    } else if remainder == 0 {
+        vec![Owo::new(0, subtotal.currency); claims.len()]
+    } else {
+        return Err(OwoError::InvalidSplit(format!(
+            "{} is left unclaimed",
+            Owo::new(remainder, subtotal.currency).to_compact_string()
+        )));
+    };
+    let parts: Vec<u64> = claims
+        .iter()
+        .zip(&weighted)
+        .map(|(claim, weighted)| match claim {
+            Claim::Fixed(amount) => amount.amount as u64,
+            Claim::Weight(_) => weighted.amount as u64,
+        })
+        .collect();
+
+    // Spread the total, tip and tax included, over each participant's part
+    let shares = if subtotal.amount == 0 {
+        vec![Owo::new(0, subtotal.currency); claims.len()]
+    } else {
+        total.allocate(&parts)?
+    };
+    Ok(BillSplit {
+        subtotal: *subtotal,
+        tip,
+        tax,
+        total,
+        shares,
+    })
+}
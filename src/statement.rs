@@ -0,0 +1,81 @@
+//! Account statements with a running balance after every transaction.
+
+use crate::Owo;
+use crate::error::OwoError;
+use serde::{Deserialize, Serialize};
+
+/// One transaction on a statement and the balance after it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StatementLine {
+    pub description: String,
+    /// Positive for money in, negative for money out.
+    pub amount: Owo,
+    pub balance: Owo,
+}
+
+/// An opening balance, the transactions applied to it in order, and the
+/// closing balance they leave.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub opening: Owo,
+    pub lines: Vec<StatementLine>,
+    pub closing: Owo,
+}
+
+impl Statement {
+    /// Applies `transactions` to `opening` in order, recording the running
+    /// balance after each one
+    ///
+    /// Returns an error naming the first transaction in a different currency
+    /// from the opening balance, or whose balance would overflow.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::statement::Statement;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let statement = Statement::generate(
+    ///     Owo::new(10000,ngn.clone()),
+    ///     vec![
+    ///         ("Salary", Owo::new(500000,ngn.clone())),
+    ///         ("Rent", Owo::new(-300000,ngn.clone())),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(statement.lines[0].balance, Owo::new(510000,ngn.clone()));
+    /// assert_eq!(statement.lines[1].balance, Owo::new(210000,ngn.clone()));
+    /// assert_eq!(statement.closing, Owo::new(210000,ngn.clone()));
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mixed = Statement::generate(Owo::new(0,ngn.clone()), vec![("Card", Owo::new(500,usd))]);
+    /// assert_eq!(mixed.unwrap_err().to_string(), "Item 0: Currency mismatch: NGN vs USD");
+    /// ```
+    pub fn generate<I, S>(opening: Owo, transactions: I) -> Result<Statement, OwoError>
+    where
+        I: IntoIterator<Item = (S, Owo)>,
+        S: Into<String>,
+    {
+        let mut balance = opening;
+        let mut lines = Vec::new();
+        for (index, (description, amount)) in transactions.into_iter().enumerate() {
+            balance = balance
+                .checked_add(&amount)
+                .map_err(|source| OwoError::AtIndex {
+                    index,
+                    source: Box::new(source),
+                })?;
+            lines.push(StatementLine {
+                description: description.into(),
+                amount,
+                balance,
+            });
+        }
+        Ok(Statement {
+            opening,
+            lines,
+            closing: balance,
+        })
+    }
+}
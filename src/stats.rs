@@ -0,0 +1,386 @@
+//! Descriptive statistics over slices of `Owo` sharing a single currency.
+//!
+//! Results that are themselves amounts of money (median, standard deviation,
+//! percentiles) are returned as a rounded `Owo`. Variance is in squared major
+//! units and is returned as an `f64`.
+
+use crate::error::OwoError;
+use crate::finance::owo_from_minor;
+use crate::owo::common_currency;
+use crate::rounding::round_div;
+use crate::{Currency, Owo, RoundingMode};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+// Helper building an `Owo` in `like`'s currency from an amount in minor units
+fn from_minor(like: &Owo, minor: f64, mode: RoundingMode) -> Owo {
+    let raw = minor / like.currency.scale_factor_f64();
+    Owo {
+        amount: like.round_amount_with_mode(raw, mode),
+        currency: like.currency,
+    }
+}
+
+// Helper returning the amounts of `items` in ascending order
+fn sorted_amounts(items: &[Owo]) -> Vec<i64> {
+    let mut amounts: Vec<i64> = items.iter().map(|c| c.amount).collect();
+    amounts.sort_unstable();
+    amounts
+}
+
+/// Returns the median of `items`, averaging the two middle values for an
+/// even number of items
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::stats;
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// let odd = vec![Owo::new(500,ngn.clone()),Owo::new(100,ngn.clone()),Owo::new(300,ngn.clone())];
+/// assert_eq!(stats::median(&odd, RoundingMode::Nearest).unwrap(), Owo::new(300,ngn.clone()));
+///
+/// let even = vec![Owo::new(100,ngn.clone()),Owo::new(200,ngn.clone()),Owo::new(301,ngn.clone()),Owo::new(400,ngn.clone())];
+/// // (200 + 301) / 2 = 250.5
+/// assert_eq!(stats::median(&even, RoundingMode::Floor).unwrap(), Owo::new(250,ngn.clone()));
+/// ```
+pub fn median(items: &[Owo], mode: RoundingMode) -> Result<Owo, OwoError> {
+    percentile(items, 50.0, mode)
+}
+
+/// Returns the population variance of `items`, in squared major units
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::stats;
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// // $2, $4, $4, $4, $5, $5, $7, $9
+/// let items: Vec<Owo> = [200, 400, 400, 400, 500, 500, 700, 900]
+///     .iter()
+///     .map(|a| Owo::new(*a, usd.clone()))
+///     .collect();
+///
+/// assert!((stats::variance(&items).unwrap() - 4.0).abs() < 1e-9);
+/// ```
+pub fn variance(items: &[Owo]) -> Result<f64, OwoError> {
+    let first = common_currency(items)?;
+    let n = items.len() as f64;
+    let mean = items.iter().map(|c| c.amount as f64).sum::<f64>() / n;
+    let squares = items
+        .iter()
+        .map(|c| (c.amount as f64 - mean).powi(2))
+        .sum::<f64>();
+    Ok(squares / n / first.currency.scale_factor_f64().powi(2))
+}
+
+/// Returns the population standard deviation of `items`, rounded with the
+/// given rounding mode
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::stats;
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// let items: Vec<Owo> = [200, 400, 400, 400, 500, 500, 700, 900]
+///     .iter()
+///     .map(|a| Owo::new(*a, usd.clone()))
+///     .collect();
+///
+/// assert_eq!(stats::std_dev(&items, RoundingMode::Nearest).unwrap(), Owo::new(200,usd.clone()));
+/// ```
+pub fn std_dev(items: &[Owo], mode: RoundingMode) -> Result<Owo, OwoError> {
+    let first = common_currency(items)?;
+    let raw = variance(items)?.sqrt();
+    Ok(Owo {
+        amount: first.round_amount_with_mode(raw, mode),
+        currency: first.currency,
+    })
+}
+
+/// Returns the `p`th percentile (0 to 100) of `items`, interpolating
+/// linearly between the closest ranks
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::stats;
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// let items: Vec<Owo> = [100, 200, 300, 400, 500]
+///     .iter()
+///     .map(|a| Owo::new(*a, ngn.clone()))
+///     .collect();
+///
+/// assert_eq!(stats::percentile(&items, 0.0, RoundingMode::Nearest).unwrap(), Owo::new(100,ngn.clone()));
+/// assert_eq!(stats::percentile(&items, 90.0, RoundingMode::Nearest).unwrap(), Owo::new(460,ngn.clone()));
+/// assert_eq!(stats::percentile(&items, 100.0, RoundingMode::Nearest).unwrap(), Owo::new(500,ngn.clone()));
+/// assert!(stats::percentile(&items, 101.0, RoundingMode::Nearest).is_err());
+/// ```
+pub fn percentile(items: &[Owo], p: f64, mode: RoundingMode) -> Result<Owo, OwoError> {
+    if !(0.0..=100.0).contains(&p) {
+        return Err(OwoError::InvalidPercentile(p));
+    }
+    let first = common_currency(items)?;
+    let amounts = sorted_amounts(items);
+
+    let rank = p / 100.0 * (amounts.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - lower as f64;
+    let minor = amounts[lower] as f64 + (amounts[upper] as f64 - amounts[lower] as f64) * weight;
+
+    Ok(from_minor(first, minor, mode))
+}
+
+/// Returns the average of `items` weighted by each pair's `f64` weight (for
+/// example a quantity), rounded once with the given rounding mode
+///
+/// A negative, NaN or infinite weight is rejected with `InvalidScalar`, and
+/// weights so large that the sums or the average no longer fit report
+/// `Overflow`.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::error::OwoError;
+/// use cowry::stats;
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// // 10 units at ₦5.00 and 30 units at ₦6.00 → ₦5.75 average unit cost
+/// let lots = vec![(Owo::new(500,ngn.clone()), 10.0), (Owo::new(600,ngn.clone()), 30.0)];
+/// assert_eq!(stats::weighted_average(&lots, RoundingMode::Nearest).unwrap(), Owo::new(575,ngn.clone()));
+///
+/// // 1 unit at ₦1.00 and 2 units at ₦2.00 → ₦1.666…
+/// let lots = vec![(Owo::new(100,ngn.clone()), 1.0), (Owo::new(200,ngn.clone()), 2.0)];
+/// assert_eq!(stats::weighted_average(&lots, RoundingMode::Floor).unwrap(), Owo::new(166,ngn.clone()));
+///
+/// let weightless = vec![(Owo::new(100,ngn.clone()), 0.0)];
+/// assert!(stats::weighted_average(&weightless, RoundingMode::Nearest).is_err());
+///
+/// let unweighable = vec![(Owo::new(100,ngn.clone()), 1.0), (Owo::new(200,ngn.clone()), f64::NAN)];
+/// assert!(matches!(
+///     stats::weighted_average(&unweighable, RoundingMode::Nearest),
+///     Err(OwoError::InvalidScalar(_))
+/// ));
+///
+/// let negative = vec![(Owo::new(100,ngn.clone()), 2.0), (Owo::new(200,ngn.clone()), -1.0)];
+/// assert!(matches!(
+///     stats::weighted_average(&negative, RoundingMode::Nearest),
+///     Err(OwoError::InvalidScalar(_))
+/// ));
+///
+/// let heavy = vec![(Owo::new(100,ngn.clone()), 1e308), (Owo::new(200,ngn.clone()), 1e308)];
+/// assert!(matches!(
+///     stats::weighted_average(&heavy, RoundingMode::Nearest),
+///     Err(OwoError::Overflow { .. })
+/// ));
+/// ```
+pub fn weighted_average(items: &[(Owo, f64)], mode: RoundingMode) -> Result<Owo, OwoError> {
+    let (first, _) = items.first().ok_or(OwoError::EmptyCollection)?;
+    let mut weighted_sum = 0.0;
+    let mut total_weight = 0.0;
+    for (c, weight) in items {
+        if c.currency != first.currency {
+            return Err(OwoError::CurrencyMismatch(
+                first.currency.code.to_string(),
+                c.currency.code.to_string(),
+            ));
+        }
+        if !weight.is_finite() || *weight < 0.0 {
+            return Err(OwoError::InvalidScalar(*weight));
+        }
+        weighted_sum += c.amount as f64 * weight;
+        total_weight += weight;
+    }
+    if !weighted_sum.is_finite() || !total_weight.is_finite() {
+        return Err(first.overflow("weighted_average", total_weight));
+    }
+    if total_weight == 0.0 {
+        return Err(OwoError::DivisionByZero);
+    }
+    owo_from_minor(first, weighted_sum / total_weight, mode, "weighted_average")
+}
+
+/// Sums any collection of `Owo`, mixed currencies included, into one total
+/// per currency
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::stats;
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let usd = Currency::new("USD", "$", 2);
+///
+/// let cart = vec![Owo::new(1000,ngn.clone()),Owo::new(250,usd.clone()),Owo::new(500,ngn.clone())];
+/// let totals = stats::totals_by_currency(&cart).unwrap();
+///
+/// assert_eq!(totals[&ngn], Owo::new(1500,ngn.clone()));
+/// assert_eq!(totals[&usd], Owo::new(250,usd.clone()));
+/// ```
+pub fn totals_by_currency<I>(items: I) -> Result<HashMap<Currency, Owo>, OwoError>
+where
+    I: IntoIterator,
+    I::Item: Borrow<Owo>,
+{
+    let mut totals: HashMap<Currency, Owo> = HashMap::new();
+    for item in items {
+        let item = item.borrow();
+        match totals.get_mut(&item.currency) {
+            Some(total) => *total = total.checked_add(item)?,
+            None => {
+                totals.insert(item.currency, *item);
+            }
+        }
+    }
+    Ok(totals)
+}
+
+/// A running sum, count, minimum and maximum over a stream of amounts in one
+/// currency, for datasets too large to hold in memory
+///
+/// The sum is kept in an `i128`, so it can't overflow partway through a
+/// stream; [`OwoAccumulator::sum`] reports an error only if the final total
+/// doesn't fit in an `Owo`. Accumulators over separate chunks can be merged.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::stats::OwoAccumulator;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let mut first = OwoAccumulator::new();
+/// let mut second = OwoAccumulator::new();
+///
+/// first.extend((0..1000).map(|_| Owo::new(i64::MAX / 100,usd.clone()))).unwrap();
+/// first.extend((0..1000).map(|_| Owo::new(-(i64::MAX / 100),usd.clone()))).unwrap();
+/// second.push(&Owo::new(250,usd.clone())).unwrap();
+/// second.push(&Owo::new(-50,usd.clone())).unwrap();
+///
+/// first.merge(&second).unwrap();
+/// assert_eq!(first.count(), 2002);
+/// assert_eq!(first.sum().unwrap(), Owo::new(200,usd.clone()));
+/// assert_eq!(first.max(), Some(Owo::new(i64::MAX / 100,usd.clone())));
+///
+/// assert!(first.push(&Owo::new(1,Currency::new("EUR", "€", 2))).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OwoAccumulator {
+    currency: Option<Currency>,
+    sum: i128,
+    count: u64,
+    min: i64,
+    max: i64,
+}
+
+impl OwoAccumulator {
+    /// Creates an empty accumulator; its currency is fixed by the first
+    /// amount pushed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one amount, erroring if its currency differs from the ones
+    /// before it.
+    pub fn push(&mut self, owo: &Owo) -> Result<(), OwoError> {
+        match &self.currency {
+            Some(currency) if *currency != owo.currency => {
+                return Err(OwoError::CurrencyMismatch(
+                    currency.code.to_string(),
+                    owo.currency.code.to_string(),
+                ));
+            }
+            Some(_) => {
+                self.min = self.min.min(owo.amount);
+                self.max = self.max.max(owo.amount);
+            }
+            None => {
+                self.currency = Some(owo.currency);
+                self.min = owo.amount;
+                self.max = owo.amount;
+            }
+        }
+        self.sum += i128::from(owo.amount);
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Adds every amount from `items`, stopping at the first error.
+    pub fn extend<I>(&mut self, items: I) -> Result<(), OwoError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Owo>,
+    {
+        items
+            .into_iter()
+            .try_for_each(|owo| self.push(owo.borrow()))
+    }
+
+    /// Combines the totals of another accumulator into this one.
+    pub fn merge(&mut self, other: &OwoAccumulator) -> Result<(), OwoError> {
+        let Some(theirs) = &other.currency else {
+            return Ok(());
+        };
+        match &self.currency {
+            Some(ours) if ours != theirs => {
+                return Err(OwoError::CurrencyMismatch(
+                    ours.code.to_string(),
+                    theirs.code.to_string(),
+                ));
+            }
+            Some(_) => {
+                self.min = self.min.min(other.min);
+                self.max = self.max.max(other.max);
+            }
+            None => {
+                self.currency = Some(*theirs);
+                self.min = other.min;
+                self.max = other.max;
+            }
+        }
+        self.sum += other.sum;
+        self.count += other.count;
+        Ok(())
+    }
+
+    /// Returns the number of amounts seen.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the exact total in minor units, or `None` before any amount
+    /// has been pushed.
+    pub fn sum_minor(&self) -> Option<i128> {
+        self.currency.map(|_| self.sum)
+    }
+
+    /// Returns the total, erroring if nothing was pushed or the total
+    /// doesn't fit in an `Owo`.
+    pub fn sum(&self) -> Result<Owo, OwoError> {
+        let currency = self.currency.ok_or(OwoError::EmptyCollection)?;
+        let amount = i64::try_from(self.sum).map_err(|_| OwoError::Overflow {
+            op: "sum",
+            lhs: self.sum.to_string(),
+            rhs: self.count.to_string(),
+        })?;
+        Ok(Owo::new(amount, currency))
+    }
+
+    /// Returns the mean, rounded with `mode`, erroring if nothing was pushed.
+    pub fn mean(&self, mode: RoundingMode) -> Result<Owo, OwoError> {
+        let currency = self.currency.ok_or(OwoError::EmptyCollection)?;
+        // The mean lies between the smallest and largest amount, so it fits
+        let mean = round_div(self.sum, i128::from(self.count), mode);
+        Ok(Owo::new(mean as i64, currency))
+    }
+
+    pub fn min(&self) -> Option<Owo> {
+        self.currency.map(|currency| Owo::new(self.min, currency))
+    }
+
+    pub fn max(&self) -> Option<Owo> {
+        self.currency.map(|currency| Owo::new(self.max, currency))
+    }
+}
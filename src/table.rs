@@ -0,0 +1,116 @@
+//! Plain-text columns of money for terminal reports and emails.
+//!
+//! Amounts are right-justified with their decimal points aligned, so a
+//! column mixing USD, JPY and BTC still lines up. Widths are counted in
+//! `char`s, which suits the usual one-column currency symbols.
+
+use crate::Owo;
+
+/// Formats each amount into a cell of the same width, with the decimal
+/// points aligned
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::table::format_column;
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let jpy = Currency::new("JPY", "¥", 0);
+/// let btc = Currency::new("BTC", "₿", 8);
+///
+/// let column = format_column(&[
+///     Owo::new(123456,usd.clone()),
+///     Owo::new(-5,usd),
+///     Owo::new(500,jpy),
+///     Owo::new(150000000,btc),
+/// ]);
+/// assert_eq!(column, vec![
+///     "$1234.56      ",
+///     "  -$0.05      ",
+///     " ¥500         ",
+///     "   ₿1.50000000",
+/// ]);
+/// ```
+pub fn format_column(items: &[Owo]) -> Vec<String> {
+    let parts: Vec<(String, String)> = items.iter().map(split_cell).collect();
+    let whole_width = parts
+        .iter()
+        .map(|(whole, _)| width(whole))
+        .max()
+        .unwrap_or(0);
+    let fraction_width = parts.iter().map(|(_, frac)| width(frac)).max().unwrap_or(0);
+    parts
+        .iter()
+        .map(|(whole, fraction)| {
+            format!(
+                "{}{}{}{}",
+                " ".repeat(whole_width - width(whole)),
+                whole,
+                fraction,
+                " ".repeat(fraction_width - width(fraction))
+            )
+        })
+        .collect()
+}
+
+/// Formats labelled amounts as lines of a two-column table, labels
+/// left-justified and amounts aligned as in [`format_column`]
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::table::format_rows;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+/// let report = format_rows(&[
+///     ("Rent", Owo::new(25000000,ngn.clone())),
+///     ("Groceries", Owo::new(4550000,ngn.clone())),
+///     ("Refund", Owo::new(-120050,ngn)),
+/// ]);
+///
+/// assert_eq!(report, "\
+/// Rent       ₦250000.00
+/// Groceries   ₦45500.00
+/// Refund      -₦1200.50");
+/// ```
+pub fn format_rows(rows: &[(&str, Owo)]) -> String {
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| width(label))
+        .max()
+        .unwrap_or(0);
+    let amounts: Vec<Owo> = rows.iter().map(|(_, owo)| *owo).collect();
+    rows.iter()
+        .zip(format_column(&amounts))
+        .map(|((label, _), cell)| {
+            format!(
+                "{}{}  {}",
+                label,
+                " ".repeat(label_width - width(label)),
+                cell
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Helper splitting an amount into its signed, symbolled whole part and its
+// fraction including the decimal point
+fn split_cell(owo: &Owo) -> (String, String) {
+    let sign = if owo.amount < 0 { "-" } else { "" };
+    let decimal = owo.to_decimal_string();
+    let unsigned = decimal.trim_start_matches('-');
+    let (whole, fraction) = match unsigned.find('.') {
+        Some(point) => unsigned.split_at(point),
+        None => (unsigned, ""),
+    };
+    (
+        format!("{}{}{}", sign, owo.currency.symbol, whole),
+        fraction.to_string(),
+    )
+}
+
+// Helper for the display width of a cell, in chars
+fn width(text: &str) -> usize {
+    text.chars().count()
+}
@@ -0,0 +1,235 @@
+//! Tax-inclusive and tax-exclusive price math, for a single rate or a
+//! [`TaxTable`] of several.
+//!
+//! Rates are percentages, as with `Owo::percentage`, so `7.5` is 7.5%. Every
+//! breakdown satisfies `net + tax == gross` exactly, whatever the rounding.
+
+use crate::error::OwoError;
+use crate::finance::owo_from_minor;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+
+/// A price split into its net amount and the tax on it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TaxBreakdown {
+    /// The amount before tax.
+    pub net: Owo,
+    /// The tax charged on `net`.
+    pub tax: Owo,
+    /// The amount including tax, always `net + tax`.
+    pub gross: Owo,
+}
+
+/// Adds tax at `rate` percent to a tax-exclusive `net` price, rounding the
+/// tax with `mode`
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::tax::add_tax;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// let price = add_tax(&Owo::new(93023,ngn.clone()), 7.5, RoundingMode::Nearest).unwrap();
+/// assert_eq!(price.tax, Owo::new(6977,ngn.clone()));
+/// assert_eq!(price.gross, Owo::new(100000,ngn.clone()));
+/// ```
+pub fn add_tax(net: &Owo, rate: f64, mode: RoundingMode) -> Result<TaxBreakdown, OwoError> {
+    let tax = net.percentage_with_mode(rate, mode)?;
+    let gross = net.checked_add(&tax)?;
+    Ok(TaxBreakdown {
+        net: *net,
+        tax,
+        gross,
+    })
+}
+
+/// Splits a tax-inclusive `gross` price into net and tax at `rate` percent,
+/// rounding the net amount with `mode` and giving the rest to tax
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::tax::extract_tax;
+///
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// // ₦1,000 incl. 7.5% VAT
+/// let price = extract_tax(&Owo::new(100000,ngn.clone()), 7.5, RoundingMode::Nearest).unwrap();
+/// assert_eq!(price.net, Owo::new(93023,ngn.clone()));
+/// assert_eq!(price.tax, Owo::new(6977,ngn.clone()));
+/// assert_eq!(price.net.clone() + price.tax.clone(), price.gross);
+///
+/// assert!(extract_tax(&Owo::new(100000,ngn.clone()), -100.0, RoundingMode::Nearest).is_err());
+/// ```
+pub fn extract_tax(gross: &Owo, rate: f64, mode: RoundingMode) -> Result<TaxBreakdown, OwoError> {
+    let divisor = 1.0 + rate / 100.0;
+    if !divisor.is_finite() || divisor <= 0.0 {
+        return Err(OwoError::InvalidScalar(rate));
+    }
+    let net = gross.divide_with_mode(divisor, mode)?;
+    let tax = gross.checked_sub(&net)?;
+    Ok(TaxBreakdown {
+        net,
+        tax,
+        gross: *gross,
+    })
+}
+
+/// How the rates in a [`TaxTable`] combine.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxStacking {
+    /// Every rate applies to the net amount, e.g. state + county + city.
+    #[default]
+    Cumulative,
+    /// Each rate applies to the net amount plus the taxes before it, e.g. a
+    /// levy charged on a VAT-inclusive price.
+    Compound,
+}
+
+/// One named tax in an [`ItemizedTax`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TaxLine {
+    pub name: String,
+    pub rate: f64,
+    pub amount: Owo,
+}
+
+/// A net amount with each tax line charged on it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ItemizedTax {
+    /// The amount before tax.
+    pub net: Owo,
+    /// Each tax in table order, summing exactly to `tax`.
+    pub lines: Vec<TaxLine>,
+    /// The total tax charged.
+    pub tax: Owo,
+    /// The amount including tax, always `net + tax`.
+    pub gross: Owo,
+}
+
+/// A set of named tax rates charged together, such as VAT plus a levy.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TaxTable {
+    rates: Vec<(String, f64)>,
+    stacking: TaxStacking,
+}
+
+impl TaxTable {
+    /// Creates an empty table whose rates combine by `stacking`.
+    pub fn new(stacking: TaxStacking) -> Self {
+        Self {
+            rates: Vec::new(),
+            stacking,
+        }
+    }
+
+    /// Appends a named rate, in percent. Compound rates apply in the order
+    /// they are added.
+    pub fn add_rate(&mut self, name: &str, rate: f64) {
+        self.rates.push((name.to_string(), rate));
+    }
+
+    /// Returns the single rate, in percent, equivalent to the whole table
+    ///
+    /// #Example
+    /// ```
+    /// use cowry::tax::{TaxStacking, TaxTable};
+    ///
+    /// let mut table = TaxTable::new(TaxStacking::Compound);
+    /// table.add_rate("VAT", 10.0);
+    /// table.add_rate("Levy", 10.0);
+    ///
+    /// assert!((table.total_rate() - 21.0).abs() < 1e-9);
+    /// ```
+    pub fn total_rate(&self) -> f64 {
+        match self.stacking {
+            TaxStacking::Cumulative => self.rates.iter().map(|(_, rate)| rate).sum(),
+            TaxStacking::Compound => {
+                let growth: f64 = self
+                    .rates
+                    .iter()
+                    .map(|(_, rate)| 1.0 + rate / 100.0)
+                    .product();
+                (growth - 1.0) * 100.0
+            }
+        }
+    }
+
+    /// Charges every rate on `net`, rounding the total tax with `mode` and
+    /// apportioning it across the lines so they sum to it exactly
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::tax::{TaxStacking, TaxTable};
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut table = TaxTable::new(TaxStacking::Cumulative);
+    /// table.add_rate("State", 6.25);
+    /// table.add_rate("County", 1.25);
+    /// table.add_rate("City", 1.25);
+    ///
+    /// let bill = table.apply(&Owo::new(1999,usd.clone()), RoundingMode::Nearest).unwrap();
+    ///
+    /// let amounts: Vec<i64> = bill.lines.iter().map(|line| line.amount.amount).collect();
+    /// assert_eq!(amounts, vec![125, 25, 25]);
+    /// assert_eq!(bill.tax, Owo::new(175,usd.clone()));
+    /// assert_eq!(bill.gross, Owo::new(2174,usd.clone()));
+    /// ```
+    pub fn apply(&self, net: &Owo, mode: RoundingMode) -> Result<ItemizedTax, OwoError> {
+        let mut raws = Vec::with_capacity(self.rates.len());
+        let mut base = net.amount as f64;
+        for (_, rate) in &self.rates {
+            if !rate.is_finite() {
+                return Err(OwoError::InvalidScalar(*rate));
+            }
+            let raw = match self.stacking {
+                TaxStacking::Cumulative => net.amount as f64 * rate / 100.0,
+                TaxStacking::Compound => base * rate / 100.0,
+            };
+            base += raw;
+            raws.push(raw);
+        }
+
+        let tax = owo_from_minor(net, raws.iter().sum(), mode, "tax")?;
+        let lines = self
+            .rates
+            .iter()
+            .zip(apportion(tax.amount, &raws))
+            .map(|((name, rate), amount)| TaxLine {
+                name: name.clone(),
+                rate: *rate,
+                amount: Owo::new(amount, net.currency),
+            })
+            .collect();
+        let gross = net.checked_add(&tax)?;
+        Ok(ItemizedTax {
+            net: *net,
+            lines,
+            tax,
+            gross,
+        })
+    }
+}
+
+// Helper rounding each of `raws` to a whole number so they sum to `total`,
+// adjusting the values with the largest fractional parts first
+pub(crate) fn apportion(total: i64, raws: &[f64]) -> Vec<i64> {
+    let mut amounts: Vec<i64> = raws.iter().map(|raw| raw.floor() as i64).collect();
+    if amounts.is_empty() {
+        return amounts;
+    }
+    let mut order: Vec<usize> = (0..raws.len()).collect();
+    order.sort_by(|&a, &b| (raws[b] - raws[b].floor()).total_cmp(&(raws[a] - raws[a].floor())));
+
+    let leftover = total - amounts.iter().sum::<i64>();
+    let step = leftover.signum();
+    if step < 0 {
+        order.reverse();
+    }
+    for index in order.iter().cycle().take(leftover.unsigned_abs() as usize) {
+        amounts[*index] += step;
+    }
+    amounts
+}
@@ -1,12 +1,90 @@
-use crate::{Owo, RoundingMode};
+use crate::error::OwoError;
+use crate::{Currency, Owo, RateTable, RoundingMode};
 
+/// Operations over a collection of `Owo`.
+///
+/// Implemented for `[Owo]`, so it is available on vectors, arrays and slices
+/// alike. A `VecDeque` can use it through `make_contiguous()` without copying
+/// into a `Vec` first.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use std::collections::VecDeque;
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// let array = [Owo::new(1000,ngn.clone()),Owo::new(500,ngn.clone())];
+/// assert_eq!(array.total().unwrap(), Owo::new(1500,ngn.clone()));
+///
+/// let slice: &[Owo] = &array[1..];
+/// assert_eq!(slice.multiply_all(2.0).unwrap(), vec![Owo::new(1000,ngn.clone())]);
+///
+/// let mut deque: VecDeque<Owo> = array.into_iter().collect();
+/// deque.push_front(Owo::new(100,ngn.clone()));
+/// assert_eq!(deque.make_contiguous().total().unwrap(), Owo::new(1600,ngn.clone()));
+/// ```
+pub trait BatchOperations {
+    fn multiply_all(&self, scalar: f64) -> Result<Vec<Owo>, OwoError>;
+    fn divide_all(&self, scalar: f64) -> Result<Vec<Owo>, OwoError>;
+    fn percentage_all(&self, percent: f64) -> Result<Vec<Owo>, OwoError>;
+    fn multiply_all_with_mode(&self, scalar: f64, mode: RoundingMode)
+    -> Result<Vec<Owo>, OwoError>;
+    fn divide_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Result<Vec<Owo>, OwoError>;
+    fn percentage_all_with_mode(
+        &self,
+        percent: f64,
+        mode: RoundingMode,
+    ) -> Result<Vec<Owo>, OwoError>;
+    fn multiply_all_mut(&mut self, scalar: f64) -> Result<(), OwoError>;
+    fn divide_all_mut(&mut self, scalar: f64) -> Result<(), OwoError>;
+    fn percentage_all_mut(&mut self, percent: f64) -> Result<(), OwoError>;
+    fn multiply_all_with_mode_mut(
+        &mut self,
+        scalar: f64,
+        mode: RoundingMode,
+    ) -> Result<(), OwoError>;
+    fn divide_all_with_mode_mut(&mut self, scalar: f64, mode: RoundingMode)
+    -> Result<(), OwoError>;
+    fn percentage_all_with_mode_mut(
+        &mut self,
+        percent: f64,
+        mode: RoundingMode,
+    ) -> Result<(), OwoError>;
+    fn convert_all(
+        &self,
+        rates: &RateTable,
+        target: &Currency,
+        mode: RoundingMode,
+    ) -> Vec<Result<Owo, OwoError>>;
+    fn total(&self) -> Result<Owo, OwoError>;
+    fn cumulative_sum(&self) -> Result<Vec<Owo>, OwoError>;
+    fn partition_by_sign(&self) -> Result<SignPartition, OwoError>;
+    fn format_all(&self, separator: &str) -> String;
+    fn filter_by_currency(&self, code: &str) -> Vec<Owo>;
+    fn partition_by_currency(&self, code: &str) -> (Vec<Owo>, Vec<Owo>);
+    fn average_with_mode(&self, mode: RoundingMode) -> Result<Owo, OwoError>;
+    fn min(&self) -> Result<&Owo, OwoError>;
+    fn max(&self) -> Result<&Owo, OwoError>;
+}
 
+/// Items split into credits (zero or positive) and debits (negative), as
+/// returned by [`BatchOperations::partition_by_sign`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignPartition {
+    pub credits: Vec<Owo>,
+    pub debits: Vec<Owo>,
+    pub credit_total: Owo,
+    pub debit_total: Owo,
+}
 
-pub trait BatchOperations {
-    fn multiply_all(&self, scalar: f64) -> Vec<Owo>;
-    fn divide_all(&self, scalar: f64) -> Vec<Owo>;
-    fn percentage_all(&self, percent: f64) -> Vec<Owo>;
-    fn multiply_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Vec<Owo>;
-    fn divide_all_with_mode(&self, scalar: f64, mode: RoundingMode) -> Vec<Owo>;
-    fn percentage_all_with_mode(&self, percent: f64, mode: RoundingMode) -> Vec<Owo>;
+/// Iterator adapters for streams of `Owo`, so money pipelines compose with
+/// standard iterator chains without collecting into a `Vec` at each step.
+///
+/// Implemented for every iterator yielding owned `Owo` values; use
+/// `.iter().cloned()` to adapt an iterator over references.
+pub trait OwoIterExt: Iterator<Item = Owo> + Sized {
+    fn try_sum(self) -> Result<Owo, OwoError>;
+    fn map_amounts<F: FnMut(i64) -> i64>(self, f: F) -> impl Iterator<Item = Owo>;
+    fn filter_currency(self, code: &str) -> impl Iterator<Item = Owo>;
+    fn max_amount(self) -> Result<Owo, OwoError>;
 }
@@ -0,0 +1,122 @@
+//! Unit prices converted between measurement units, such as per kilogram to
+//! per pound or per litre to per gallon.
+//!
+//! Unit sizes are exact integers in a small base unit (10⁻¹² kg for mass,
+//! 10⁻¹³ L for volume), using the international pound and the US and
+//! imperial gallons as legally defined, so a conversion rounds once at the
+//! end.
+
+use crate::error::OwoError;
+use crate::rounding::round_div;
+use crate::{Owo, RoundingMode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A unit of mass or volume that goods are priced by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Gram,
+    Kilogram,
+    /// The avoirdupois ounce, 1/16 lb.
+    Ounce,
+    /// The international pound, exactly 0.45359237 kg.
+    Pound,
+    Millilitre,
+    Litre,
+    /// The US fluid ounce, 1/128 US gallon.
+    FluidOunce,
+    /// The US gallon, exactly 3.785411784 L.
+    Gallon,
+    /// The imperial gallon, exactly 4.54609 L.
+    ImperialGallon,
+}
+
+// What a unit measures; only units of the same dimension convert
+#[derive(PartialEq, Eq)]
+enum Dimension {
+    Mass,
+    Volume,
+}
+
+impl Unit {
+    // Helper returning the dimension and the unit's size in base units
+    fn size(self) -> (Dimension, i128) {
+        match self {
+            Unit::Gram => (Dimension::Mass, 1_000_000_000),
+            Unit::Kilogram => (Dimension::Mass, 1_000_000_000_000),
+            Unit::Ounce => (Dimension::Mass, 28_349_523_125),
+            Unit::Pound => (Dimension::Mass, 453_592_370_000),
+            Unit::Millilitre => (Dimension::Volume, 10_000_000_000),
+            Unit::Litre => (Dimension::Volume, 10_000_000_000_000),
+            Unit::FluidOunce => (Dimension::Volume, 295_735_295_625),
+            Unit::Gallon => (Dimension::Volume, 37_854_117_840_000),
+            Unit::ImperialGallon => (Dimension::Volume, 45_460_900_000_000),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Unit::Gram => "g",
+            Unit::Kilogram => "kg",
+            Unit::Ounce => "oz",
+            Unit::Pound => "lb",
+            Unit::Millilitre => "ml",
+            Unit::Litre => "l",
+            Unit::FluidOunce => "fl oz",
+            Unit::Gallon => "gal",
+            Unit::ImperialGallon => "imp gal",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Converts a price per `from` into the price per `to`, rounding once with
+/// `mode`
+///
+/// Returns an error when converting between mass and volume, or when the
+/// result overflows.
+///
+/// #Example
+/// ```
+/// # use cowry::prelude::*;
+/// use cowry::units::{Unit, convert_unit_price};
+///
+/// let usd = Currency::new("USD", "$", 2);
+/// let ngn = Currency::new("NGN", "₦", 2);
+///
+/// // $8.80/kg is $3.99/lb
+/// let per_lb = convert_unit_price(&Owo::new(880,usd.clone()), Unit::Kilogram, Unit::Pound, RoundingMode::Nearest).unwrap();
+/// assert_eq!(per_lb, Owo::new(399,usd.clone()));
+///
+/// // $3.49/gal is $0.92/l
+/// let per_gallon = Owo::new(349,usd.clone());
+/// let per_litre = convert_unit_price(&per_gallon, Unit::Gallon, Unit::Litre, RoundingMode::Nearest).unwrap();
+/// assert_eq!(per_litre, Owo::new(92,usd.clone()));
+///
+/// // ₦617.00/l is ₦0.62/ml
+/// let per_litre = Owo::new(61700,ngn.clone());
+/// let per_ml = convert_unit_price(&per_litre, Unit::Litre, Unit::Millilitre, RoundingMode::Nearest).unwrap();
+/// assert_eq!(per_ml.get_amount(), 62);
+///
+/// assert!(convert_unit_price(&per_litre, Unit::Litre, Unit::Kilogram, RoundingMode::Nearest).is_err());
+/// ```
+pub fn convert_unit_price(
+    price: &Owo,
+    from: Unit,
+    to: Unit,
+    mode: RoundingMode,
+) -> Result<Owo, OwoError> {
+    let (from_dimension, from_size) = from.size();
+    let (to_dimension, to_size) = to.size();
+    if from_dimension != to_dimension {
+        return Err(OwoError::IncompatibleUnits(
+            from.to_string(),
+            to.to_string(),
+        ));
+    }
+    let amount = round_div(i128::from(price.amount) * to_size, from_size, mode);
+    let amount = i64::try_from(amount).map_err(|_| price.overflow("convert_unit_price", to))?;
+    Ok(Owo::new(amount, price.currency))
+}
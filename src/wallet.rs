@@ -0,0 +1,129 @@
+//! A multi-currency wallet holding one balance per currency.
+
+use crate::Owo;
+use crate::error::OwoError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Balances in any number of currencies, at most one per currency code,
+/// iterated in code order.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Wallet {
+    balances: BTreeMap<String, Owo>,
+}
+
+impl Wallet {
+    /// Creates an empty wallet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `amount` to the balance in its currency, opening one if needed
+    ///
+    /// Returns an error for negative amounts, for a currency whose definition
+    /// differs from the balance already held under its code, or on overflow.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::error::OwoError;
+    /// use cowry::wallet::Wallet;
+    ///
+    /// let ngn = Currency::new("NGN", "₦", 2);
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut wallet = Wallet::new();
+    ///
+    /// wallet.deposit(&Owo::new(500000,ngn.clone())).unwrap();
+    /// wallet.deposit(&Owo::new(2000,usd.clone())).unwrap();
+    /// wallet.deposit(&Owo::new(2500,usd.clone())).unwrap();
+    ///
+    /// assert_eq!(wallet.balance("USD"), Some(&Owo::new(4500,usd.clone())));
+    /// assert_eq!(wallet.iter().count(), 2);
+    /// assert!(matches!(
+    ///     wallet.deposit(&Owo::new(-1,usd.clone())),
+    ///     Err(OwoError::NegativeAmount(_))
+    /// ));
+    /// ```
+    pub fn deposit(&mut self, amount: &Owo) -> Result<(), OwoError> {
+        if amount.amount < 0 {
+            return Err(OwoError::NegativeAmount(amount.to_compact_string()));
+        }
+        match self.balances.get_mut(amount.currency.code) {
+            Some(balance) => *balance = balance.checked_add(amount)?,
+            None => {
+                self.balances
+                    .insert(amount.currency.code.to_string(), *amount);
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes `amount` out of the balance in its currency
+    ///
+    /// Returns an error for negative amounts, or if the wallet holds less
+    /// than `amount` in that currency.
+    ///
+    /// #Example
+    /// ```
+    /// # use cowry::prelude::*;
+    /// use cowry::wallet::Wallet;
+    ///
+    /// let usd = Currency::new("USD", "$", 2);
+    /// let mut wallet = Wallet::new();
+    /// wallet.deposit(&Owo::new(1000,usd.clone())).unwrap();
+    ///
+    /// wallet.withdraw(&Owo::new(400,usd.clone())).unwrap();
+    /// assert_eq!(wallet.balance("USD"), Some(&Owo::new(600,usd.clone())));
+    ///
+    /// assert!(wallet.withdraw(&Owo::new(700,usd.clone())).is_err());
+    /// assert!(wallet.withdraw(&Owo::new(1,Currency::new("EUR", "€", 2))).is_err());
+    /// ```
+    pub fn withdraw(&mut self, amount: &Owo) -> Result<(), OwoError> {
+        if amount.amount < 0 {
+            return Err(OwoError::NegativeAmount(amount.to_compact_string()));
+        }
+        let insufficient = |available: String| OwoError::InsufficientFunds {
+            available,
+            requested: amount.to_compact_string(),
+        };
+        let balance = self
+            .balances
+            .get_mut(amount.currency.code)
+            .ok_or_else(|| insufficient(Owo::new(0, amount.currency).to_compact_string()))?;
+        let remaining = balance.checked_sub(amount)?;
+        if remaining.amount < 0 {
+            return Err(insufficient(balance.to_compact_string()));
+        }
+        *balance = remaining;
+        Ok(())
+    }
+
+    /// Returns the balance held in the currency with `code`, if any.
+    pub fn balance(&self, code: &str) -> Option<&Owo> {
+        self.balances.get(code)
+    }
+
+    /// Iterates over the balances in currency code order.
+    pub fn iter(&self) -> impl Iterator<Item = &Owo> {
+        self.balances.values()
+    }
+
+    /// Returns the number of currencies held.
+    pub fn len(&self) -> usize {
+        self.balances.len()
+    }
+
+    /// Returns `true` if the wallet holds no currencies.
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+    }
+}
+
+impl<'a> IntoIterator for &'a Wallet {
+    type Item = &'a Owo;
+    type IntoIter = std::collections::btree_map::Values<'a, String, Owo>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.balances.values()
+    }
+}
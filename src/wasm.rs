@@ -0,0 +1,133 @@
+//! JavaScript bindings through `wasm-bindgen`, so a browser checkout runs the
+//! exact same formatting, parsing and rounding logic as the server.
+//!
+//! The types are exported to JavaScript as `Owo`, `Currency` and
+//! `RoundingMode`. Amounts are minor units as `BigInt`.
+//!
+//! ```js
+//! import { Owo, Currency, RoundingMode } from "cowry";
+//!
+//! const price = new Owo(1050n, Currency.fromCode("USD"));
+//! price.multiply(3, RoundingMode.Nearest).format(); // "$31.50"
+//! Owo.parse("NGN 1234.50").amount; // 123450n
+//! ```
+
+use crate::{Currency, Owo, RoundingMode, registry};
+use wasm_bindgen::prelude::*;
+
+/// A currency definition, exported to JavaScript as `Currency`.
+#[wasm_bindgen(js_name = Currency)]
+#[derive(Clone)]
+pub struct WasmCurrency(Currency);
+
+#[wasm_bindgen(js_class = Currency)]
+impl WasmCurrency {
+    /// Creates a currency definition from the ISO 4217 registry or a
+    /// currency added with `Currency.register`, rejecting unknown codes and
+    /// symbols.
+    #[wasm_bindgen(constructor)]
+    pub fn new(code: &str, symbol: &str, precision: u8) -> Result<WasmCurrency, JsError> {
+        Ok(WasmCurrency(Currency::resolve(code, symbol, precision)?))
+    }
+
+    /// Registers a custom currency definition for the life of the module,
+    /// rejecting unsupported precisions. Only register currencies the
+    /// application defines, never ones taken from user input.
+    pub fn register(code: &str, symbol: &str, precision: u8) -> Result<WasmCurrency, JsError> {
+        Ok(WasmCurrency(Currency::try_new(code, symbol, precision)?))
+    }
+
+    /// Looks up an ISO 4217 currency by code.
+    #[wasm_bindgen(js_name = fromCode)]
+    pub fn from_code(code: &str) -> Result<WasmCurrency, JsError> {
+        Ok(WasmCurrency(registry::currency(code)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> String {
+        self.0.code.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn symbol(&self) -> String {
+        self.0.symbol.to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn precision(&self) -> u8 {
+        self.0.precision
+    }
+}
+
+/// An amount of money, exported to JavaScript as `Owo`.
+#[wasm_bindgen(js_name = Owo)]
+#[derive(Clone)]
+pub struct WasmOwo(Owo);
+
+#[wasm_bindgen(js_class = Owo)]
+impl WasmOwo {
+    /// Creates an amount from minor units.
+    #[wasm_bindgen(constructor)]
+    pub fn new(amount: i64, currency: &WasmCurrency) -> WasmOwo {
+        WasmOwo(Owo::new(amount, currency.0))
+    }
+
+    /// Parses a compact string such as `"USD 5.99"`.
+    pub fn parse(input: &str) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(Owo::from_compact_str(input)?))
+    }
+
+    /// Parses a plain decimal string such as `"5.99"` in the given currency.
+    #[wasm_bindgen(js_name = fromDecimal)]
+    pub fn from_decimal(input: &str, currency: &WasmCurrency) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(Owo::from_decimal_str(input, currency.0)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn amount(&self) -> i64 {
+        self.0.amount
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn currency(&self) -> WasmCurrency {
+        WasmCurrency(self.0.currency)
+    }
+
+    pub fn format(&self) -> String {
+        self.0.format()
+    }
+
+    #[wasm_bindgen(js_name = toDecimalString)]
+    pub fn to_decimal_string(&self) -> String {
+        self.0.to_decimal_string()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_compact_string(&self) -> String {
+        self.0.to_compact_string()
+    }
+
+    pub fn add(&self, other: &WasmOwo) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(self.0.checked_add(&other.0)?))
+    }
+
+    pub fn subtract(&self, other: &WasmOwo) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(self.0.checked_sub(&other.0)?))
+    }
+
+    pub fn multiply(&self, scalar: f64, mode: RoundingMode) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(self.0.multiply_with_mode(scalar, mode)?))
+    }
+
+    pub fn divide(&self, scalar: f64, mode: RoundingMode) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(self.0.divide_with_mode(scalar, mode)?))
+    }
+
+    pub fn percentage(&self, percent: f64, mode: RoundingMode) -> Result<WasmOwo, JsError> {
+        Ok(WasmOwo(self.0.percentage_with_mode(percent, mode)?))
+    }
+
+    pub fn equals(&self, other: &WasmOwo) -> bool {
+        self.0 == other.0
+    }
+}